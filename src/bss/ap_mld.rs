@@ -0,0 +1,123 @@
+use crate::Bss;
+use macaddr::MacAddr6;
+
+/// A Wi-Fi 7 AP that operates several BSSs, one per link, as a single multi-link device (MLD).
+#[derive(Debug, Clone)]
+pub struct ApMld {
+    mld_mac_address: MacAddr6,
+    links: Vec<Bss>,
+}
+
+impl ApMld {
+    /// Groups the BSSs that advertise the same AP MLD MAC address in their Basic Multi-Link
+    /// element. BSSs that aren't affiliated with an AP MLD are left out.
+    pub fn group<'a>(bsses: impl IntoIterator<Item = &'a Bss>) -> Vec<ApMld> {
+        let mut ap_mlds: Vec<ApMld> = Vec::new();
+
+        for bss in bsses {
+            let mld_mac_address = match bss.mld_mac_address() {
+                Some(mld_mac_address) => mld_mac_address,
+                None => continue,
+            };
+
+            match ap_mlds
+                .iter_mut()
+                .find(|ap_mld| ap_mld.mld_mac_address == mld_mac_address)
+            {
+                Some(ap_mld) => {
+                    if !ap_mld.links.contains(bss) {
+                        ap_mld.links.push(bss.clone())
+                    }
+                }
+                None => ap_mlds.push(ApMld {
+                    mld_mac_address,
+                    links: vec![bss.clone()],
+                }),
+            }
+        }
+
+        for ap_mld in ap_mlds.iter_mut() {
+            ap_mld.links.sort_by_key(|bss| bss.mld_link_id());
+        }
+
+        ap_mlds
+    }
+
+    pub fn mld_mac_address(&self) -> MacAddr6 {
+        self.mld_mac_address
+    }
+
+    /// The scanned BSSs of the AP MLD, ordered by link ID.
+    pub fn links(&self) -> &[Bss] {
+        &self.links
+    }
+
+    pub fn link(&self, link_id: u8) -> Option<&Bss> {
+        self.links
+            .iter()
+            .find(|bss| bss.mld_link_id() == Some(link_id))
+    }
+
+    /// The IDs of every link the AP MLD advertises, including links on channels that weren't
+    /// scanned.
+    pub fn link_ids(&self) -> Vec<u8> {
+        let mut link_ids: Vec<u8> = self
+            .links
+            .iter()
+            .filter_map(|bss| bss.basic_multi_link())
+            .flat_map(|multi_link| {
+                multi_link.link_id().into_iter().chain(
+                    multi_link
+                        .per_sta_profiles()
+                        .iter()
+                        .map(|per_sta_profile| per_sta_profile.link_id()),
+                )
+            })
+            .collect();
+
+        link_ids.sort_unstable();
+        link_ids.dedup();
+        link_ids
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A Basic Multi-Link element for the link of the AP MLD, followed by per-STA profiles for the
+    // other links
+    fn basic_multi_link(mld_mac_address: MacAddr6, link_id: u8, other_link_ids: &[u8]) -> Vec<u8> {
+        let mut bytes = vec![0xFF, 0x00, 0x6B, 0x10, 0x00, 0x08];
+        bytes.extend(mld_mac_address.as_bytes());
+        bytes.push(link_id);
+        for other_link_id in other_link_ids {
+            bytes.extend([0x00, 0x03, *other_link_id, 0x00, 0x01]);
+        }
+        bytes[1] = (bytes.len() - 2) as u8;
+        bytes
+    }
+
+    #[test]
+    fn group_by_mld_mac_address() {
+        let mld_mac_address = MacAddr6::new(0x02, 0x11, 0x22, 0x33, 0x44, 0x55);
+        // A Reconfiguration Multi-Link element doesn't add links to the AP MLD
+        let mut five_ghz_ie_bytes = vec![0xFF, 0x04, 0x6B, 0x02, 0x00, 0x01];
+        five_ghz_ie_bytes.extend(basic_multi_link(mld_mac_address, 1, &[]));
+        let bsses = vec![
+            Bss::from_last_octet(1, 5180, &five_ghz_ie_bytes),
+            Bss::from_last_octet(2, 2412, &basic_multi_link(mld_mac_address, 0, &[1, 2])),
+            Bss::from_last_octet(3, 2437, &[0x00, 0x00]),
+        ];
+
+        let ap_mlds = ApMld::group(&bsses);
+        assert_eq!(ap_mlds.len(), 1);
+        assert_eq!(ap_mlds[0].mld_mac_address(), mld_mac_address);
+        assert_eq!(ap_mlds[0].links(), &[bsses[1].clone(), bsses[0].clone()]);
+        assert_eq!(ap_mlds[0].link(1), Some(&bsses[0]));
+        assert_eq!(ap_mlds[0].link(2), None);
+
+        // Link 2 is advertised but wasn't scanned
+        assert_eq!(ap_mlds[0].link_ids(), vec![0, 1, 2]);
+    }
+}
@@ -2,7 +2,7 @@ use crate::Field;
 use bitvec::prelude::*;
 use std::fmt::Display;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CapabilityInfo {
     bits: BitArray<LocalBits, [u8; 2]>,
 }
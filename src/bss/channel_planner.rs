@@ -0,0 +1,686 @@
+use crate::{
+    ies::Ie, Bss, ChannelBand, ChannelNumber, ChannelOccupancy, ChannelRelation, ChannelSurvey,
+    ChannelWidth, OperatingClass, Region,
+};
+use std::{cmp::Ordering, collections::HashMap, fmt::Display};
+
+// Every recommendation starts with this many points before the factors add or take away theirs
+const BASE_SCORE: f64 = 100.0;
+// Points taken away per neighbor at full signal strength
+const CO_CHANNEL_PENALTY: f64 = 15.0;
+const OVERLAPPING_PENALTY: f64 = 10.0;
+const ADJACENT_PENALTY: f64 = 3.0;
+// Points taken away for a fully utilized channel or for a channel that was always busy
+const CHANNEL_UTILIZATION_PENALTY: f64 = 20.0;
+const SURVEY_BUSY_TIME_PENALTY: f64 = 30.0;
+const STATION_PENALTY: f64 = 0.5;
+const MAX_STATION_PENALTY: f64 = 15.0;
+const DFS_PENALTY: f64 = 10.0;
+// Points given each time the width doubles from 20 MHz
+const WIDTH_BONUS: f64 = 5.0;
+// Signals at or below the floor don't count, and signals at or above the ceiling count fully
+const SIGNAL_FLOOR_DBM: i32 = -90;
+const SIGNAL_CEILING_DBM: i32 = -50;
+
+/// What channels the planner may recommend.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChannelPlanPolicy {
+    /// The region whose operating classes list the allowed channels.
+    pub region: Region,
+    /// The bands to plan for. Only the 2.4, 5 and 6 GHz bands are planned.
+    pub bands: Vec<ChannelBand>,
+    /// The primary channels allowed in the 2.4 GHz band.
+    pub two_point_four_ghz_channels: Vec<u8>,
+    /// Whether to leave out channels that need radar detection.
+    pub avoid_dfs: bool,
+    /// The widest channel to recommend in each band, which is 20 MHz for bands that aren't
+    /// listed.
+    pub max_widths: HashMap<ChannelBand, ChannelWidth>,
+}
+
+impl ChannelPlanPolicy {
+    /// The usual policy for the region: the non-overlapping channels 1, 6 and 11 at 20 MHz in the
+    /// 2.4 GHz band, up to 80 MHz in the 5 GHz band and up to 160 MHz in the 6 GHz band.
+    pub fn new(region: Region) -> ChannelPlanPolicy {
+        ChannelPlanPolicy {
+            region,
+            bands: vec![
+                ChannelBand::TwoPointFourGhz,
+                ChannelBand::FiveGhz,
+                ChannelBand::SixGhz,
+            ],
+            two_point_four_ghz_channels: vec![1, 6, 11],
+            avoid_dfs: false,
+            max_widths: vec![
+                (ChannelBand::TwoPointFourGhz, ChannelWidth::TwentyMhz),
+                (ChannelBand::FiveGhz, ChannelWidth::EightyMhz),
+                (ChannelBand::SixGhz, ChannelWidth::OneSixtyMhz),
+            ]
+            .into_iter()
+            .collect(),
+        }
+    }
+}
+
+/// What a score factor measures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScoreFactorKind {
+    CoChannelNeighbors,
+    OverlappingNeighbors,
+    AdjacentNeighbors,
+    ChannelUtilization,
+    StationCount,
+    Dfs,
+    SurveyBusyTime,
+    Width,
+}
+
+impl Display for ScoreFactorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScoreFactorKind::CoChannelNeighbors => write!(f, "Co-Channel Neighbors"),
+            ScoreFactorKind::OverlappingNeighbors => write!(f, "Overlapping Neighbors"),
+            ScoreFactorKind::AdjacentNeighbors => write!(f, "Adjacent Neighbors"),
+            ScoreFactorKind::ChannelUtilization => write!(f, "Channel Utilization"),
+            ScoreFactorKind::StationCount => write!(f, "Station Count"),
+            ScoreFactorKind::Dfs => write!(f, "DFS"),
+            ScoreFactorKind::SurveyBusyTime => write!(f, "Survey Busy Time"),
+            ScoreFactorKind::Width => write!(f, "Width"),
+        }
+    }
+}
+
+/// How much one factor added to or took away from a recommendation's score, and why.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScoreFactor {
+    kind: ScoreFactorKind,
+    points: f64,
+    explanation: String,
+}
+
+impl ScoreFactor {
+    pub fn kind(&self) -> ScoreFactorKind {
+        self.kind
+    }
+
+    /// The points the factor added to the score, which are negative for penalties.
+    pub fn points(&self) -> f64 {
+        self.points
+    }
+
+    pub fn explanation(&self) -> &str {
+        &self.explanation
+    }
+}
+
+impl Display for ScoreFactor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: {:+.1} ({})",
+            self.kind, self.points, self.explanation
+        )
+    }
+}
+
+/// A candidate channel for a new BSS and how it scored.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChannelRecommendation {
+    band: ChannelBand,
+    primary_channel: ChannelNumber,
+    width: ChannelWidth,
+    center_freq_mhz: u32,
+    requires_dfs: bool,
+    score: f64,
+    factors: Vec<ScoreFactor>,
+}
+
+impl ChannelRecommendation {
+    pub fn band(&self) -> ChannelBand {
+        self.band
+    }
+
+    pub fn primary_channel(&self) -> ChannelNumber {
+        self.primary_channel
+    }
+
+    pub fn width(&self) -> ChannelWidth {
+        self.width
+    }
+
+    /// The center frequency of the whole channel.
+    pub fn center_freq_mhz(&self) -> u32 {
+        self.center_freq_mhz
+    }
+
+    /// The number of the channel centered on the whole channel, like the channel center frequency
+    /// segment of the VHT Operation element. It's the primary channel for 20 MHz channels.
+    pub fn center_channel(&self) -> Option<ChannelNumber> {
+        self.band.channel_number(self.center_freq_mhz * 1000)
+    }
+
+    /// Whether any of the channel's 20 MHz subchannels needs radar detection.
+    pub fn requires_dfs(&self) -> bool {
+        self.requires_dfs
+    }
+
+    /// The score, where higher is better.
+    pub fn score(&self) -> f64 {
+        self.score
+    }
+
+    pub fn factors(&self) -> &[ScoreFactor] {
+        &self.factors
+    }
+}
+
+impl Display for ChannelRecommendation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} Channel {} ({}): {:.1}",
+            self.band, self.primary_channel, self.width, self.score
+        )
+    }
+}
+
+/// Scores every channel the policy allows against the BSSs and channel surveys of a scan.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChannelPlanner {
+    policy: ChannelPlanPolicy,
+}
+
+impl ChannelPlanner {
+    pub fn new(policy: ChannelPlanPolicy) -> ChannelPlanner {
+        ChannelPlanner { policy }
+    }
+
+    pub fn policy(&self) -> &ChannelPlanPolicy {
+        &self.policy
+    }
+
+    /// Ranks the candidate channels from best to worst by how much the scanned BSSs and, if
+    /// there are any, the channel surveys say they're in use.
+    pub fn recommend<'a>(
+        &self,
+        bsses: impl IntoIterator<Item = &'a Bss>,
+        surveys: &[ChannelSurvey],
+    ) -> Vec<ChannelRecommendation> {
+        let neighbors: Vec<Neighbor> = bsses
+            .into_iter()
+            .filter_map(|bss| {
+                Some(Neighbor::new(
+                    bss.channel_occupancy()?,
+                    bss.signal_dbm(),
+                    bss.ies(),
+                ))
+            })
+            .collect();
+
+        self.rank(&neighbors, surveys)
+    }
+
+    fn rank(
+        &self,
+        neighbors: &[Neighbor],
+        surveys: &[ChannelSurvey],
+    ) -> Vec<ChannelRecommendation> {
+        let mut recommendations: Vec<ChannelRecommendation> = self
+            .candidates()
+            .into_iter()
+            .map(|candidate| candidate.score(neighbors, surveys))
+            .collect();
+
+        recommendations.sort_by(|recommendation, other_recommendation| {
+            other_recommendation
+                .score
+                .partial_cmp(&recommendation.score)
+                .unwrap_or(Ordering::Equal)
+                .then(other_recommendation.width.cmp(&recommendation.width))
+                .then(recommendation.band.cmp(&other_recommendation.band))
+                .then(
+                    recommendation
+                        .primary_channel
+                        .cmp(&other_recommendation.primary_channel),
+                )
+        });
+
+        recommendations
+    }
+
+    // Every channel of the region's operating classes within the policy, with each of the 20 MHz
+    // subchannels that can be its primary channel
+    fn candidates(&self) -> Vec<Candidate> {
+        let mut candidates: Vec<Candidate> = Vec::new();
+
+        for band in self.policy.bands.iter().copied() {
+            if !matches!(
+                band,
+                ChannelBand::TwoPointFourGhz | ChannelBand::FiveGhz | ChannelBand::SixGhz
+            ) {
+                continue;
+            }
+
+            // Regions whose tables leave out the band fall back to the global table
+            let mut classes: Vec<OperatingClass> = OperatingClass::classes(self.policy.region)
+                .into_iter()
+                .filter(|operating_class| operating_class.band() == band)
+                .collect();
+            if classes.is_empty() {
+                classes = OperatingClass::global_classes()
+                    .into_iter()
+                    .filter(|operating_class| operating_class.band() == band)
+                    .collect();
+            }
+
+            // The allowed 20 MHz channels, and whether they need radar detection
+            let twenty_mhz_channels: HashMap<u32, bool> = classes
+                .iter()
+                .filter(|operating_class| {
+                    operating_class.channel_spacing() == ChannelWidth::TwentyMhz
+                })
+                .flat_map(|operating_class| {
+                    operating_class
+                        .channels()
+                        .iter()
+                        .filter_map(move |channel| {
+                            operating_class
+                                .center_frequency_mhz(*channel)
+                                .map(|freq_mhz| (freq_mhz, operating_class.requires_dfs()))
+                        })
+                })
+                .collect();
+
+            let max_width_mhz = self
+                .policy
+                .max_widths
+                .get(&band)
+                .copied()
+                .and_then(width_mhz)
+                .unwrap_or(20);
+
+            for operating_class in classes.iter() {
+                let width = operating_class.channel_spacing();
+                let class_width_mhz = match width_mhz(width) {
+                    Some(class_width_mhz) if class_width_mhz <= max_width_mhz => class_width_mhz,
+                    _ => continue,
+                };
+
+                for channel in operating_class.channels() {
+                    let center_freq_mhz = match operating_class.center_frequency_mhz(*channel) {
+                        Some(center_freq_mhz) => center_freq_mhz,
+                        None => continue,
+                    };
+                    let subchannel_freqs_mhz: Vec<u32> = (0..class_width_mhz / 20)
+                        .map(|index| center_freq_mhz - class_width_mhz / 2 + index * 20 + 10)
+                        .collect();
+                    if !subchannel_freqs_mhz
+                        .iter()
+                        .all(|freq_mhz| twenty_mhz_channels.contains_key(freq_mhz))
+                    {
+                        continue;
+                    }
+
+                    let requires_dfs = subchannel_freqs_mhz
+                        .iter()
+                        .any(|freq_mhz| twenty_mhz_channels.get(freq_mhz) == Some(&true));
+                    if requires_dfs && self.policy.avoid_dfs {
+                        continue;
+                    }
+
+                    // The channel set of 40 MHz classes with a secondary channel position lists
+                    // primary channels, and any subchannel can be the primary channel otherwise
+                    let primary_freqs_mhz = match operating_class.secondary_channel_position() {
+                        Some(_) => {
+                            vec![operating_class.starting_frequency_mhz() + u32::from(*channel) * 5]
+                        }
+                        None => subchannel_freqs_mhz.clone(),
+                    };
+
+                    for primary_freq_mhz in primary_freqs_mhz {
+                        let primary_channel = match band.channel_number(primary_freq_mhz * 1000) {
+                            Some(primary_channel) => primary_channel,
+                            None => continue,
+                        };
+                        if band == ChannelBand::TwoPointFourGhz
+                            && !self
+                                .policy
+                                .two_point_four_ghz_channels
+                                .contains(&primary_channel)
+                        {
+                            continue;
+                        }
+
+                        let candidate = Candidate {
+                            occupancy: ChannelOccupancy::contiguous(
+                                band,
+                                primary_freq_mhz,
+                                width,
+                                center_freq_mhz,
+                                class_width_mhz,
+                            ),
+                            primary_channel,
+                            width_mhz: class_width_mhz,
+                            center_freq_mhz,
+                            requires_dfs,
+                        };
+                        if !candidates
+                            .iter()
+                            .any(|other_candidate| other_candidate.occupancy == candidate.occupancy)
+                        {
+                            candidates.push(candidate);
+                        }
+                    }
+                }
+            }
+        }
+
+        candidates
+    }
+}
+
+// What the planner needs to know about a scanned BSS
+#[derive(Debug, Clone)]
+struct Neighbor {
+    occupancy: ChannelOccupancy,
+    signal_dbm: i32,
+    channel_utilization: Option<u8>,
+    station_count: Option<u16>,
+}
+
+impl Neighbor {
+    fn new(occupancy: ChannelOccupancy, signal_dbm: i32, ies: &[Ie]) -> Neighbor {
+        let bss_load = ies.iter().find_map(|ie| match ie {
+            Ie::BssLoad(bss_load) => Some(bss_load),
+            _ => None,
+        });
+
+        Neighbor {
+            occupancy,
+            signal_dbm,
+            channel_utilization: bss_load.map(|bss_load| bss_load.channel_utilization()),
+            station_count: bss_load.map(|bss_load| bss_load.station_count()),
+        }
+    }
+
+    // How much the neighbor counts, from 0 for signals at the floor to 1 for strong signals
+    fn strength(&self) -> f64 {
+        f64::from(self.signal_dbm.clamp(SIGNAL_FLOOR_DBM, SIGNAL_CEILING_DBM) - SIGNAL_FLOOR_DBM)
+            / f64::from(SIGNAL_CEILING_DBM - SIGNAL_FLOOR_DBM)
+    }
+}
+
+struct Candidate {
+    occupancy: ChannelOccupancy,
+    primary_channel: ChannelNumber,
+    width_mhz: u32,
+    center_freq_mhz: u32,
+    requires_dfs: bool,
+}
+
+impl Candidate {
+    fn score(self, neighbors: &[Neighbor], surveys: &[ChannelSurvey]) -> ChannelRecommendation {
+        let mut factors = Vec::new();
+
+        let mut co_channel = NeighborTally::default();
+        let mut overlapping = NeighborTally::default();
+        let mut adjacent = NeighborTally::default();
+        let mut max_channel_utilization: Option<u8> = None;
+        let mut station_count = 0u32;
+
+        for neighbor in neighbors
+            .iter()
+            .filter(|neighbor| neighbor.occupancy.band() == self.occupancy.band())
+        {
+            let overlap = self.occupancy.overlap(&neighbor.occupancy);
+            match overlap.relation() {
+                ChannelRelation::CoChannel => co_channel.add(neighbor, 1.0),
+                ChannelRelation::Overlapping => overlapping.add(
+                    neighbor,
+                    f64::from(overlap.overlap_mhz()) / f64::from(self.width_mhz),
+                ),
+                ChannelRelation::Adjacent => adjacent.add(neighbor, 1.0),
+                ChannelRelation::Separate => continue,
+            }
+
+            if overlap.relation() != ChannelRelation::Adjacent {
+                max_channel_utilization = max_channel_utilization.max(neighbor.channel_utilization);
+                station_count += u32::from(neighbor.station_count.unwrap_or_default());
+            }
+        }
+
+        for (kind, tally, penalty, description) in [
+            (
+                ScoreFactorKind::CoChannelNeighbors,
+                &co_channel,
+                CO_CHANNEL_PENALTY,
+                "sharing the primary channel",
+            ),
+            (
+                ScoreFactorKind::OverlappingNeighbors,
+                &overlapping,
+                OVERLAPPING_PENALTY,
+                "overlapping the channel",
+            ),
+            (
+                ScoreFactorKind::AdjacentNeighbors,
+                &adjacent,
+                ADJACENT_PENALTY,
+                "right next to the channel",
+            ),
+        ]
+        .iter()
+        {
+            if let Some(strongest_signal_dbm) = tally.strongest_signal_dbm {
+                factors.push(ScoreFactor {
+                    kind: *kind,
+                    points: -penalty * tally.weight,
+                    explanation: format!(
+                        "{} BSS(s) {}, the strongest at {} dBm",
+                        tally.count, description, strongest_signal_dbm
+                    ),
+                });
+            }
+        }
+
+        if let Some(channel_utilization) = max_channel_utilization {
+            factors.push(ScoreFactor {
+                kind: ScoreFactorKind::ChannelUtilization,
+                points: -CHANNEL_UTILIZATION_PENALTY * f64::from(channel_utilization) / 255.0,
+                explanation: format!(
+                    "The busiest overlapping BSS reports {:.0}% channel utilization",
+                    f64::from(channel_utilization) * 100.0 / 255.0
+                ),
+            });
+        }
+
+        if station_count > 0 {
+            factors.push(ScoreFactor {
+                kind: ScoreFactorKind::StationCount,
+                points: -(STATION_PENALTY * f64::from(station_count)).min(MAX_STATION_PENALTY),
+                explanation: format!(
+                    "{} station(s) are associated with overlapping BSSs",
+                    station_count
+                ),
+            });
+        }
+
+        if self.requires_dfs {
+            factors.push(ScoreFactor {
+                kind: ScoreFactorKind::Dfs,
+                points: -DFS_PENALTY,
+                explanation: String::from(
+                    "Needs radar detection, a channel availability check before use and a channel \
+                     switch if radar shows up",
+                ),
+            });
+        }
+
+        let subchannel_freqs_mhz = self.occupancy.subchannel_freqs_mhz();
+        let busy_ratios: Vec<f64> = surveys
+            .iter()
+            .filter(|survey| subchannel_freqs_mhz.contains(&survey.frequency_mhz()))
+            .filter_map(|survey| survey.busy_ratio())
+            .collect();
+        if !busy_ratios.is_empty() {
+            let busy_ratio = busy_ratios.iter().sum::<f64>() / busy_ratios.len() as f64;
+            factors.push(ScoreFactor {
+                kind: ScoreFactorKind::SurveyBusyTime,
+                points: -SURVEY_BUSY_TIME_PENALTY * busy_ratio,
+                explanation: format!(
+                    "The surveyed subchannels were busy {:.0}% of the time",
+                    busy_ratio * 100.0
+                ),
+            });
+        }
+
+        if self.width_mhz > 20 {
+            factors.push(ScoreFactor {
+                kind: ScoreFactorKind::Width,
+                points: WIDTH_BONUS * f64::from(self.width_mhz / 20).log2(),
+                explanation: format!(
+                    "{} MHz has {} times the capacity of 20 MHz",
+                    self.width_mhz,
+                    self.width_mhz / 20
+                ),
+            });
+        }
+
+        ChannelRecommendation {
+            band: self.occupancy.band(),
+            primary_channel: self.primary_channel,
+            width: self.occupancy.width(),
+            center_freq_mhz: self.center_freq_mhz,
+            requires_dfs: self.requires_dfs,
+            score: BASE_SCORE + factors.iter().map(|factor| factor.points).sum::<f64>(),
+            factors,
+        }
+    }
+}
+
+// The neighbors in one relation to a candidate channel
+#[derive(Default)]
+struct NeighborTally {
+    count: usize,
+    weight: f64,
+    strongest_signal_dbm: Option<i32>,
+}
+
+impl NeighborTally {
+    fn add(&mut self, neighbor: &Neighbor, share: f64) {
+        self.count += 1;
+        self.weight += neighbor.strength() * share;
+        self.strongest_signal_dbm = self.strongest_signal_dbm.max(Some(neighbor.signal_dbm));
+    }
+}
+
+// The width of a contiguous channel, or None for 80+80 MHz and 22 MHz DSSS channels
+fn width_mhz(width: ChannelWidth) -> Option<u32> {
+    match width {
+        ChannelWidth::TwentyMhz => Some(20),
+        ChannelWidth::FortyMhz => Some(40),
+        ChannelWidth::EightyMhz => Some(80),
+        ChannelWidth::OneSixtyMhz => Some(160),
+        ChannelWidth::ThreeTwentyMhz => Some(320),
+        ChannelWidth::TwentyTwoMhz | ChannelWidth::EightyPlusEightyMhz => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Channel;
+
+    #[test]
+    fn recommends_least_used_channel() {
+        let neighbor = |freq_mhz, signal_dbm| Neighbor {
+            occupancy: ChannelOccupancy::new(
+                &Channel::from_frequency_mhz(freq_mhz, &[]).unwrap(),
+                &[],
+            )
+            .unwrap(),
+            signal_dbm,
+            channel_utilization: Some(128),
+            station_count: Some(10),
+        };
+        let neighbors = vec![
+            neighbor(2412, -45),
+            neighbor(2437, -60),
+            neighbor(2427, -80),
+        ];
+        let surveys = vec![
+            ChannelSurvey::new(2412, 100, 60),
+            ChannelSurvey::new(2437, 100, 40),
+            ChannelSurvey::new(2462, 100, 5),
+        ];
+
+        let mut policy = ChannelPlanPolicy::new(Region::Europe);
+        policy.bands = vec![ChannelBand::TwoPointFourGhz];
+        let recommendations = ChannelPlanner::new(policy).rank(&neighbors, &surveys);
+
+        let channels: Vec<u8> = recommendations
+            .iter()
+            .map(|recommendation| *recommendation.primary_channel())
+            .collect();
+        assert_eq!(channels, vec![11, 6, 1]);
+        assert!(recommendations[0].factors().iter().all(|factor| matches!(
+            factor.kind(),
+            ScoreFactorKind::AdjacentNeighbors | ScoreFactorKind::SurveyBusyTime
+        )));
+        // Channel 11 is next to the neighbors on 2427 and 2437 MHz but 30 MHz from channel 1
+        assert!(recommendations[0]
+            .factors()
+            .iter()
+            .any(|factor| factor.kind() == ScoreFactorKind::AdjacentNeighbors
+                && factor.explanation().starts_with("2 BSS(s)")));
+        assert!(recommendations[1]
+            .factors()
+            .iter()
+            .any(|factor| factor.kind() == ScoreFactorKind::OverlappingNeighbors));
+
+        // A neighbor on channel 1 alone doesn't affect channel 11
+        let mut policy = ChannelPlanPolicy::new(Region::Europe);
+        policy.bands = vec![ChannelBand::TwoPointFourGhz];
+        let channel_eleven = ChannelPlanner::new(policy)
+            .rank(&[neighbor(2412, -45)], &[])
+            .into_iter()
+            .find(|recommendation| *recommendation.primary_channel() == 11)
+            .unwrap();
+        assert!(channel_eleven.factors().iter().all(|factor| !matches!(
+            factor.kind(),
+            ScoreFactorKind::CoChannelNeighbors
+                | ScoreFactorKind::OverlappingNeighbors
+                | ScoreFactorKind::AdjacentNeighbors
+        )));
+
+        let mut policy = ChannelPlanPolicy::new(Region::UnitedStates);
+        policy.bands = vec![ChannelBand::FiveGhz];
+        policy.avoid_dfs = true;
+        let recommendations = ChannelPlanner::new(policy).rank(&[], &[]);
+        assert!(recommendations
+            .iter()
+            .all(|recommendation| !recommendation.requires_dfs()));
+        assert_eq!(recommendations[0].width(), ChannelWidth::EightyMhz);
+        assert_eq!(*recommendations[0].primary_channel(), 36);
+        assert_eq!(*recommendations[0].center_channel().unwrap(), 42);
+
+        // A neighbor on channel 36 doesn't change the score of the 80 MHz channel 155
+        let mut policy = ChannelPlanPolicy::new(Region::UnitedStates);
+        policy.bands = vec![ChannelBand::FiveGhz];
+        let channel_155_score = |neighbors: &[Neighbor]| {
+            ChannelPlanner::new(policy.clone())
+                .rank(neighbors, &[])
+                .iter()
+                .find(|recommendation| {
+                    *recommendation.primary_channel() == 149
+                        && recommendation.width() == ChannelWidth::EightyMhz
+                })
+                .map(|recommendation| recommendation.score())
+                .unwrap()
+        };
+        assert_eq!(
+            channel_155_score(&[neighbor(5180, -45)]),
+            channel_155_score(&[])
+        );
+    }
+}
@@ -0,0 +1,134 @@
+use crate::{
+    ies::{Ie, SecondaryChannelOffset},
+    ChannelWidth, OperatingClass,
+};
+
+/// A channel switch that a BSS announces with a Channel Switch Announcement or Extended Channel
+/// Switch Announcement element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChannelSwitch {
+    channel: u8,
+    operating_class: Option<u8>,
+    width: Option<ChannelWidth>,
+    count_tbtts: u8,
+    blocks_transmissions: bool,
+}
+
+impl ChannelSwitch {
+    /// Finds the pending channel switch announced in the elements, preferring the Extended
+    /// Channel Switch Announcement element since it includes the operating class.
+    pub fn from_ies(ies: &[Ie]) -> Option<ChannelSwitch> {
+        let mut channel_switch = ies
+            .iter()
+            .find_map(|ie| match ie {
+                Ie::ExtendedChannelSwitchAnnouncement(extended_channel_switch_announcement) => {
+                    Some(ChannelSwitch {
+                        channel: extended_channel_switch_announcement.new_channel_number(),
+                        operating_class: Some(
+                            extended_channel_switch_announcement.new_operating_class(),
+                        ),
+                        width: None,
+                        count_tbtts: extended_channel_switch_announcement.channel_switch_count(),
+                        blocks_transmissions: extended_channel_switch_announcement
+                            .channel_switch_mode(),
+                    })
+                }
+                _ => None,
+            })
+            .or_else(|| {
+                ies.iter().find_map(|ie| match ie {
+                    Ie::ChannelSwitchAnnouncement(channel_switch_announcement) => {
+                        Some(ChannelSwitch {
+                            channel: channel_switch_announcement.new_channel_number(),
+                            operating_class: None,
+                            width: None,
+                            count_tbtts: channel_switch_announcement.channel_switch_count(),
+                            blocks_transmissions: channel_switch_announcement.channel_switch_mode(),
+                        })
+                    }
+                    _ => None,
+                })
+            })?;
+
+        // The Wide Bandwidth Channel Switch element is either on its own or in the Channel Switch
+        // Wrapper element
+        let wide_bandwidth_channel_switch = ies.iter().find_map(|ie| match ie {
+            Ie::WideBandwidthChannelSwitch(wide_bandwidth_channel_switch) => {
+                Some(wide_bandwidth_channel_switch)
+            }
+            Ie::ChannelSwitchWrapper(channel_switch_wrapper) => {
+                channel_switch_wrapper.wide_bandwidth_channel_switch()
+            }
+            _ => None,
+        });
+
+        channel_switch.width = wide_bandwidth_channel_switch
+            .and_then(|wide_bandwidth_channel_switch| {
+                wide_bandwidth_channel_switch.new_channel_width()
+            })
+            .or_else(|| {
+                channel_switch
+                    .lookup_operating_class(ies)
+                    .map(|operating_class| operating_class.channel_spacing())
+            })
+            .or_else(|| {
+                // A Channel Switch Announcement element has no operating class, so a switch to a
+                // 40 MHz channel comes with a Secondary Channel Offset element
+                ies.iter().find_map(|ie| match ie {
+                    Ie::SecondaryChannelOffset(secondary_channel_offset) => {
+                        match secondary_channel_offset.secondary_channel_offset()? {
+                            SecondaryChannelOffset::NoSecondaryChannel => {
+                                Some(ChannelWidth::TwentyMhz)
+                            }
+                            SecondaryChannelOffset::Above | SecondaryChannelOffset::Below => {
+                                Some(ChannelWidth::FortyMhz)
+                            }
+                        }
+                    }
+                    _ => None,
+                })
+            });
+
+        Some(channel_switch)
+    }
+
+    fn lookup_operating_class(&self, ies: &[Ie]) -> Option<OperatingClass> {
+        let class = self.operating_class?;
+        let country_string = ies.iter().find_map(|ie| match ie {
+            Ie::Country(country) => country.country_string(),
+            _ => None,
+        });
+
+        match country_string {
+            Some(country_string) => OperatingClass::for_country(country_string, class),
+            None => OperatingClass::global(class),
+        }
+    }
+
+    /// The new primary channel.
+    pub fn channel(&self) -> u8 {
+        self.channel
+    }
+
+    /// The operating class of the new channel, which only the Extended Channel Switch
+    /// Announcement element includes.
+    pub fn operating_class(&self) -> Option<u8> {
+        self.operating_class
+    }
+
+    /// The new channel width, from the Wide Bandwidth Channel Switch element, the new operating
+    /// class or the Secondary Channel Offset element, in that order.
+    pub fn width(&self) -> Option<ChannelWidth> {
+        self.width
+    }
+
+    /// The number of TBTTs until the switch, where 0 means it can happen at any time.
+    pub fn count_tbtts(&self) -> u8 {
+        self.count_tbtts
+    }
+
+    /// Whether associated STAs must stop transmitting until the switch.
+    pub fn blocks_transmissions(&self) -> bool {
+        self.blocks_transmissions
+    }
+}
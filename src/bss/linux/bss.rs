@@ -1,8 +1,11 @@
 use super::{BssStatus, Nl80211Bss, ScanWidth};
 use crate::{
-    bss::CapabilityInfo,
-    ies::{self, Ie},
-    Channel, SecurityProtocols, WifiProtocols,
+    bss::{CapabilityInfo, ChannelSwitch, PhyRate},
+    ies::{
+        self, DecodedSsid, Ie, MultiLink, MultiLinkType, MultipleBssid, NontransmittedBssidProfile,
+        P2p, SsidEncoding, Wps,
+    },
+    Channel, ChannelOccupancy, ChannelOverlap, ChannelWidth, SecurityProtocols, WifiProtocols,
 };
 use macaddr::MacAddr6;
 use neli::{attr::Attribute, genl::Nlattr, types::Buffer};
@@ -56,6 +59,29 @@ impl Bss {
         self.beacon_interval_tu as f64 * 1.024
     }
 
+    /// The DTIM period from the TIM element, which only beacons carry. A nontransmitted BSS has
+    /// its own DTIM period in its Multiple BSSID-Index element.
+    pub fn dtim_period(&self) -> Option<u8> {
+        let ies = || self.ies.iter().chain(self.beacon_ies.iter().flatten());
+
+        ies()
+            .find_map(|ie| match ie {
+                Ie::MultipleBssidIndex(multiple_bssid_index) => multiple_bssid_index.dtim_period(),
+                _ => None,
+            })
+            .or_else(|| {
+                ies().find_map(|ie| match ie {
+                    Ie::Tim(tim) => Some(tim.dtim_period()),
+                    _ => None,
+                })
+            })
+    }
+
+    pub fn dtim_interval_ms(&self) -> Option<f64> {
+        self.dtim_period()
+            .map(|dtim_period| dtim_period as f64 * self.beacon_interval_ms())
+    }
+
     pub fn capability_info(&self) -> CapabilityInfo {
         self.capability_info.clone()
     }
@@ -112,6 +138,9 @@ impl Bss {
         self.seen_ms_ago
     }
 
+    /// The SSID if it's valid UTF-8. Hidden SSIDs come back as empty or NUL-filled text, and
+    /// SSIDs that aren't UTF-8 as None, like a BSS without an SSID element; use
+    /// [`decoded_ssid`](Bss::decoded_ssid) to tell them apart and decode legacy encodings.
     pub fn ssid(&self) -> Option<&str> {
         self.ies.iter().find_map(|ie| {
             if let Ie::Ssid(ssid) = ie {
@@ -122,35 +151,197 @@ impl Bss {
         })
     }
 
-    pub fn channel(&self) -> Channel {
-        Channel::from(&self.ies)
+    /// Decodes the SSID, telling hidden SSIDs apart from ones that aren't UTF-8, which are
+    /// decoded with the first of the fallback encodings that can decode them unless the BSS
+    /// declares its SSID as UTF-8.
+    pub fn decoded_ssid(&self, fallback_encodings: &[SsidEncoding]) -> Option<DecodedSsid> {
+        let utf8_ssid = self
+            .ies
+            .iter()
+            .find_map(|ie| match ie {
+                Ie::ExtendedCapabilities(extended_capabilities) => {
+                    extended_capabilities.utf8_ssid()
+                }
+                _ => None,
+            })
+            .unwrap_or_default();
+
+        self.ies.iter().find_map(|ie| match ie {
+            Ie::Ssid(ssid) => Some(ssid.decode(utf8_ssid, fallback_encodings)),
+            _ => None,
+        })
     }
 
-    pub fn security_protocols(&self) -> SecurityProtocols {
-        SecurityProtocols::from(self.ies.as_slice())
+    pub(crate) fn basic_multi_link(&self) -> Option<&MultiLink> {
+        self.ies.iter().find_map(|ie| match ie {
+            Ie::MultiLink(multi_link) if multi_link.multi_link_type() == MultiLinkType::Basic => {
+                Some(multi_link)
+            }
+            _ => None,
+        })
     }
 
-    pub fn wifi_protocols(&self) -> WifiProtocols {
-        WifiProtocols::from(self.ies.as_slice())
+    /// The MAC address of the AP MLD this BSS is a link of.
+    pub fn mld_mac_address(&self) -> Option<MacAddr6> {
+        self.basic_multi_link()
+            .and_then(|multi_link| multi_link.mld_mac_address())
     }
 
-    pub fn max_rate_mbps(&self) -> f64 {
-        let mut max_rate = 0.0;
+    /// The ID of the AP MLD link this BSS is.
+    pub fn mld_link_id(&self) -> Option<u8> {
+        self.basic_multi_link()
+            .and_then(|multi_link| multi_link.link_id())
+    }
 
-        for ie in self.ies.iter() {
-            match ie {
-                Ie::SupportedRates(supported_rates) => {
-                    let data_rates = supported_rates.rates();
-                    if max_rate < data_rates.iter().max().unwrap().value() {
-                        max_rate = data_rates.iter().max().unwrap().value();
+    fn wps(&self) -> Option<Wps> {
+        Wps::join(self.ies.iter().filter_map(|ie| match ie {
+            Ie::Wps(wps) => Some(wps),
+            _ => None,
+        }))
+    }
+
+    /// The Wi-Fi Direct (P2P) element of a P2P group owner, joined from all of its P2P elements.
+    pub fn p2p(&self) -> Option<P2p> {
+        P2p::join(self.ies.iter().filter_map(|ie| match ie {
+            Ie::P2p(p2p) => Some(p2p),
+            _ => None,
+        }))
+    }
+
+    /// The manufacturer of the AP from its WPS element.
+    pub fn manufacturer(&self) -> Option<String> {
+        self.wps().and_then(|wps| wps.manufacturer())
+    }
+
+    /// The model name of the AP from its WPS element.
+    pub fn model_name(&self) -> Option<String> {
+        self.wps().and_then(|wps| wps.model_name())
+    }
+
+    /// The model number of the AP from its WPS element.
+    pub fn model_number(&self) -> Option<String> {
+        self.wps().and_then(|wps| wps.model_number())
+    }
+
+    /// The device name of the AP from its WPS element.
+    pub fn device_name(&self) -> Option<String> {
+        self.wps().and_then(|wps| wps.device_name())
+    }
+
+    /// The mobility domain identifier, which APs that a STA can fast transition (802.11r) between
+    /// share.
+    pub fn mobility_domain_id(&self) -> Option<u16> {
+        self.ies.iter().find_map(|ie| match ie {
+            Ie::MobilityDomain(mobility_domain) => Some(mobility_domain.mdid()),
+            _ => None,
+        })
+    }
+
+    /// Whether the RSN element advertises an FT AKM suite.
+    pub fn advertises_fast_transition(&self) -> bool {
+        self.ies.iter().any(|ie| match ie {
+            Ie::Rsn(rsn) => rsn
+                .akm_suites()
+                .unwrap_or_default()
+                .iter()
+                .any(|akm_suite| akm_suite.is_fast_transition()),
+            _ => false,
+        })
+    }
+
+    /// The channel switch that the BSS announces, if any.
+    pub fn pending_channel_switch(&self) -> Option<ChannelSwitch> {
+        ChannelSwitch::from_ies(&self.ies)
+    }
+
+    /// The BSSs described by the nontransmitted BSSID profiles of the Multiple BSSID elements,
+    /// each with its derived BSSID and the elements it inherits from this BSS.
+    pub fn nontransmitted_bsses(&self) -> Vec<Bss> {
+        let mut profiles: Vec<(&MultipleBssid, NontransmittedBssidProfile)> = Vec::new();
+
+        for ie in &self.ies {
+            let multiple_bssid = match ie {
+                Ie::MultipleBssid(multiple_bssid) => multiple_bssid,
+                _ => continue,
+            };
+
+            for profile in multiple_bssid.nontransmitted_bssid_profiles() {
+                // A profile that doesn't start with a Nontransmitted BSSID Capability element
+                // continues the profile of the previous Multiple BSSID element
+                match profiles.last_mut() {
+                    Some((_, previous_profile)) if profile.capability_info().is_none() => {
+                        previous_profile.extend(profile.clone())
                     }
+                    _ => profiles.push((multiple_bssid, profile.clone())),
                 }
-                Ie::HtOperation(_) => continue,
-                Ie::VhtOperation(_) => continue,
-                _ => continue,
             }
         }
-        0.0
+
+        profiles
+            .into_iter()
+            .filter_map(|(multiple_bssid, profile)| {
+                let bssid_index = profile.bssid_index()?;
+
+                Some(Bss {
+                    bssid: multiple_bssid.nontransmitted_bssid(self.bssid, bssid_index),
+                    capability_info: profile.capability_info()?,
+                    // Being associated to the transmitted BSS isn't being associated to its
+                    // nontransmitted BSSs
+                    status: BssStatus::NotAssociated,
+                    ies: profile.inherited_ies(&self.ies),
+                    beacon_ies: self
+                        .beacon_ies
+                        .as_ref()
+                        .map(|beacon_ies| profile.inherited_ies(beacon_ies)),
+                    ..self.clone()
+                })
+            })
+            .collect()
+    }
+
+    /// The channel at the frequency nl80211 reports, or else the primary channel in the elements.
+    /// Returns None if the frequency isn't on a known channel raster and the elements don't name
+    /// the channel.
+    pub fn channel(&self) -> Option<Channel> {
+        let frequency_khz =
+            self.frequency_mhz * 1000 + self.frequency_offset_khz().unwrap_or_default();
+
+        Channel::from_frequency_khz(frequency_khz, &self.ies)
+            .or_else(|| Channel::from_ies(&self.ies))
+    }
+
+    /// The spectrum the BSS occupies on its channel.
+    pub fn channel_occupancy(&self) -> Option<ChannelOccupancy> {
+        ChannelOccupancy::new(&self.channel()?, &self.ies)
+    }
+
+    /// How the channel of the BSS relates to the channel of the other BSS, like whether they're
+    /// co-channel and how many MHz they share.
+    pub fn channel_overlap(&self, other: &Bss) -> Option<ChannelOverlap> {
+        Some(
+            self.channel_occupancy()?
+                .overlap(&other.channel_occupancy()?),
+        )
+    }
+
+    pub fn security_protocols(&self) -> SecurityProtocols {
+        SecurityProtocols::new(&self.capability_info, &self.ies)
+    }
+
+    pub fn wifi_protocols(&self) -> WifiProtocols {
+        WifiProtocols::from(self.ies.as_slice())
+    }
+
+    /// The theoretical max PHY rate on the operating channel, or 0 if the BSS doesn't advertise
+    /// any rates.
+    pub fn max_rate_mbps(&self) -> f64 {
+        self.max_rate().map_or(0.0, |max_rate| max_rate.rate_mbps())
+    }
+
+    /// The theoretical max PHY rate on the operating channel and the MCS, spatial streams, width
+    /// and guard interval that give it.
+    pub fn max_rate(&self) -> Option<PhyRate> {
+        PhyRate::max_from_ies(&self.ies)
     }
 }
 
@@ -196,7 +387,7 @@ impl TryFrom<&[Nlattr<Nl80211Bss, Buffer>]> for Bss {
             capability_info: bss_attrs
                 .get(&Nl80211Bss::Capability)
                 .and_then(|attr| attr.payload().as_ref().try_into().ok())
-                .map(|payload| CapabilityInfo::new(payload))
+                .map(CapabilityInfo::new)
                 .ok_or(())?,
             status: bss_attrs
                 .get(&Nl80211Bss::Status)
@@ -243,17 +434,70 @@ impl TryFrom<&[Nlattr<Nl80211Bss, Buffer>]> for Bss {
     }
 }
 
+#[cfg(test)]
+impl Bss {
+    /// A BSS built from the nl80211 attributes of a scan result with the given elements.
+    pub(crate) fn from_ie_bytes(bssid: MacAddr6, frequency_mhz: u32, ie_bytes: &[u8]) -> Bss {
+        let bss_attrs = vec![
+            Nlattr::new(
+                None,
+                false,
+                false,
+                Nl80211Bss::Bssid,
+                Buffer::from(bssid.as_bytes()),
+            )
+            .unwrap(),
+            Nlattr::new(None, false, false, Nl80211Bss::Frequency, frequency_mhz).unwrap(),
+            Nlattr::new(None, false, false, Nl80211Bss::SignalMbm, -5000i32).unwrap(),
+            Nlattr::new(None, false, false, Nl80211Bss::BeaconInterval, 100u16).unwrap(),
+            Nlattr::new(
+                None,
+                false,
+                false,
+                Nl80211Bss::Capability,
+                Buffer::from(&[0x11, 0x04][..]),
+            )
+            .unwrap(),
+            Nlattr::new(
+                None,
+                false,
+                false,
+                Nl80211Bss::InformationElements,
+                Buffer::from(ie_bytes),
+            )
+            .unwrap(),
+        ];
+
+        Bss::try_from(bss_attrs.as_slice()).unwrap()
+    }
+
+    /// A BSS like [`from_ie_bytes`](Bss::from_ie_bytes) whose BSSID is 02:00:00:00:00 followed by
+    /// the given last octet.
+    pub(crate) fn from_last_octet(last_octet: u8, frequency_mhz: u32, ie_bytes: &[u8]) -> Bss {
+        Bss::from_ie_bytes(
+            MacAddr6::new(0x02, 0x00, 0x00, 0x00, 0x00, last_octet),
+            frequency_mhz,
+            ie_bytes,
+        )
+    }
+}
+
 impl Display for Bss {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let _r = writeln!(
             f,
-            "BSSID: {}\r\nSSID: {}\r\nRSSI: {} dBm\r\nChannel Number: {}\r\nChannel Width: {}\r\nWi-Fi Protocols: {}",
+            "BSSID: {}\r\nSSID: {}\r\nRSSI: {} dBm\r\nChannel Number: {}\r\nChannel Width: {}\r\nWi-Fi Protocols: {}\r\nSecurity: {}",
             self.bssid,
-            self.ssid().unwrap_or_default(),
+            self.decoded_ssid(&[])
+                .map(|ssid| ssid.escaped())
+                .unwrap_or_default(),
             self.signal_dbm,
-            self.channel().number(),
-            self.channel().width(),
-            self.wifi_protocols()
+            self.channel()
+                .map(|channel| channel.number().to_string())
+                .unwrap_or_default(),
+            ChannelWidth::from(self.ies.as_slice()),
+            self.wifi_protocols(),
+            self.security_protocols()
         );
 
         let _b = writeln!(f, "{}", self.capability_info);
@@ -265,3 +509,111 @@ impl Display for Bss {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ies::SsidKind;
+
+    #[test]
+    fn dtim_period_and_interval() {
+        let bssid = MacAddr6::new(0x02, 0x00, 0x00, 0x00, 0x00, 0x01);
+
+        // A 100 TU beacon interval and a TIM element with a DTIM period of 3
+        let bss = Bss::from_ie_bytes(bssid, 2412, &[5, 4, 0x00, 0x03, 0x00, 0x00]);
+        assert_eq!(bss.beacon_interval_tu(), 100);
+        assert_eq!(bss.dtim_period(), Some(3));
+        assert!((bss.dtim_interval_ms().unwrap() - 307.2).abs() < 1e-9);
+
+        // A TIM element too short to have the DTIM period is kept as an unknown element
+        let bss = Bss::from_ie_bytes(bssid, 2412, &[5, 2, 0x00, 0x03]);
+        assert!(matches!(&bss.ies()[0], Ie::Unknown(_)));
+        assert_eq!(bss.dtim_period(), None);
+        assert_eq!(bss.dtim_interval_ms(), None);
+    }
+
+    #[test]
+    fn nontransmitted_bsses() {
+        let bss = Bss::from_ie_bytes(
+            MacAddr6::new(0x02, 0x11, 0x22, 0x33, 0x44, 0x5E),
+            5180,
+            &[
+                0x00, 0x04, b'm', b'a', b'i', b'n', // SSID
+                0x0B, 0x05, 0x02, 0x00, 0x10, 0x00, 0x00, // BSS Load
+                0x47, 0x25, 0x03, // Multiple BSSID, MaxBSSID Indicator 3
+                0x00, 0x0E, // Nontransmitted BSSID Profile
+                0x53, 0x02, 0x01, 0x04, // Nontransmitted BSSID Capability
+                0x00, 0x05, b'g', b'u', b'e', b's', b't', // SSID
+                0x55, 0x01, 0x01, // Multiple BSSID-Index
+                0x00, 0x12, // Nontransmitted BSSID Profile
+                0x53, 0x02, 0x11, 0x04, // Nontransmitted BSSID Capability with privacy
+                0x00, 0x03, b'i', b'o', b't', // SSID
+                0x55, 0x01, 0x03, // Multiple BSSID-Index
+                0xFF, 0x04, 0x38, 0x01, 0x0B, 0x00, // Non-Inheritance of BSS Load
+            ],
+        );
+
+        let bss = Bss {
+            status: BssStatus::Associated,
+            ..bss
+        };
+
+        let nontransmitted_bsses = bss.nontransmitted_bsses();
+        assert_eq!(nontransmitted_bsses.len(), 2);
+        assert!(nontransmitted_bsses
+            .iter()
+            .all(|bss| bss.status() == BssStatus::NotAssociated));
+
+        // The low 3 bits of the BSSID are incremented by the BSSID index modulo 8
+        let guest = &nontransmitted_bsses[0];
+        assert_eq!(
+            guest.bssid(),
+            MacAddr6::new(0x02, 0x11, 0x22, 0x33, 0x44, 0x5F)
+        );
+        assert_eq!(guest.capability_info(), CapabilityInfo::new([0x01, 0x04]));
+        assert_eq!(guest.ssid(), Some("guest"));
+        assert_eq!(guest.frequency_mhz(), 5180);
+        let names: Vec<&str> = guest.ies().iter().map(|ie| ie.name()).collect();
+        assert_eq!(names, vec!["SSID", "Bss Load", "Multiple BSSID-Index"]);
+
+        let iot = &nontransmitted_bsses[1];
+        assert_eq!(
+            iot.bssid(),
+            MacAddr6::new(0x02, 0x11, 0x22, 0x33, 0x44, 0x59)
+        );
+        assert!(iot.capability_info().privacy());
+        assert_eq!(iot.ssid(), Some("iot"));
+        let names: Vec<&str> = iot.ies().iter().map(|ie| ie.name()).collect();
+        assert_eq!(names, vec!["SSID", "Multiple BSSID-Index"]);
+    }
+
+    #[test]
+    fn decoded_ssid() {
+        let bssid = MacAddr6::new(0x02, 0x00, 0x00, 0x00, 0x00, 0x01);
+        // "中文" in GBK, which isn't valid UTF-8
+        let gbk_ssid = [0x00, 0x04, 0xD6, 0xD0, 0xCE, 0xC4];
+
+        let bss = Bss::from_ie_bytes(bssid, 2412, &gbk_ssid);
+        assert_eq!(bss.ssid(), None);
+        let decoded_ssid = bss.decoded_ssid(&SsidEncoding::SIMPLIFIED_CHINESE).unwrap();
+        assert_eq!(decoded_ssid.text(), Some("中文"));
+        assert_eq!(decoded_ssid.encoding(), Some(SsidEncoding::Gbk));
+        assert!(!decoded_ssid.utf8_declared());
+        assert!(!decoded_ssid.is_hidden());
+
+        // An Extended Capabilities element with the UTF-8 SSID bit stops the fallback encodings
+        let mut ie_bytes = gbk_ssid.to_vec();
+        ie_bytes.extend([0x7F, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01]);
+        let bss = Bss::from_ie_bytes(bssid, 2412, &ie_bytes);
+        let decoded_ssid = bss.decoded_ssid(&SsidEncoding::SIMPLIFIED_CHINESE).unwrap();
+        assert_eq!(decoded_ssid.kind(), &SsidKind::Undecodable);
+        assert!(decoded_ssid.utf8_declared());
+        assert_eq!(decoded_ssid.escaped(), "\\xd6\\xd0\\xce\\xc4");
+
+        // A hidden SSID is told apart from one that isn't UTF-8
+        let bss = Bss::from_ie_bytes(bssid, 2412, &[0x00, 0x04, 0x00, 0x00, 0x00, 0x00]);
+        let decoded_ssid = bss.decoded_ssid(&[]).unwrap();
+        assert_eq!(decoded_ssid.kind(), &SsidKind::Hidden);
+        assert!(decoded_ssid.is_hidden());
+    }
+}
@@ -4,6 +4,7 @@ use num_enum::TryFromPrimitive;
 /// Based on nl80211_bss_scan_width from linux/include/uapi/linux/nl80211.h
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, TryFromPrimitive)]
 #[repr(u32)]
+#[allow(clippy::enum_variant_names)]
 pub enum ScanWidth {
     TwentyMhz,
     TenMhz,
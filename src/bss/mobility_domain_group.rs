@@ -0,0 +1,147 @@
+use crate::Bss;
+
+/// The BSSs that share a mobility domain identifier (MDID) and so allow fast BSS transition
+/// (802.11r) between each other.
+#[derive(Debug, Clone)]
+pub struct MobilityDomainGroup {
+    mdid: u16,
+    bsses: Vec<Bss>,
+}
+
+impl MobilityDomainGroup {
+    /// Groups the BSSs by the MDID in their Mobility Domain element. BSSs without a Mobility
+    /// Domain element are left out.
+    pub fn group<'a>(bsses: impl IntoIterator<Item = &'a Bss>) -> Vec<MobilityDomainGroup> {
+        let mut groups: Vec<MobilityDomainGroup> = Vec::new();
+
+        for bss in bsses {
+            let mdid = match bss.mobility_domain_id() {
+                Some(mdid) => mdid,
+                None => continue,
+            };
+
+            match groups.iter_mut().find(|group| group.mdid == mdid) {
+                Some(group) => {
+                    if !group.bsses.contains(bss) {
+                        group.bsses.push(bss.clone())
+                    }
+                }
+                None => groups.push(MobilityDomainGroup {
+                    mdid,
+                    bsses: vec![bss.clone()],
+                }),
+            }
+        }
+
+        groups
+    }
+
+    pub fn mdid(&self) -> u16 {
+        self.mdid
+    }
+
+    pub fn bsses(&self) -> &[Bss] {
+        &self.bsses
+    }
+
+    /// The SSIDs of the ESSs in the mobility domain, which is normally just one.
+    pub fn ssids(&self) -> Vec<&str> {
+        let mut ssids: Vec<&str> = self.bsses.iter().filter_map(|bss| bss.ssid()).collect();
+        ssids.sort_unstable();
+        ssids.dedup();
+        ssids
+    }
+
+    /// The BSSs that have a Mobility Domain element but don't advertise an FT AKM suite in their
+    /// RSN element, so STAs can't fast transition to them.
+    pub fn bsses_without_fast_transition(&self) -> Vec<&Bss> {
+        self.bsses
+            .iter()
+            .filter(|bss| !bss.advertises_fast_transition())
+            .collect()
+    }
+
+    /// Whether the BSSs of the given SSID all share this mobility domain.
+    pub fn covers_ess<'a>(&self, ssid: &str, bsses: impl IntoIterator<Item = &'a Bss>) -> bool {
+        bsses
+            .into_iter()
+            .filter(|bss| bss.ssid() == Some(ssid))
+            .all(|bss| bss.mobility_domain_id() == Some(self.mdid))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    // An SSID, a Mobility Domain element and an RSN element with the given AKM suite type
+    fn ie_bytes(ssid: &str, mdid: Option<u16>, akm_suite_type: u8) -> Vec<u8> {
+        let mut bytes = vec![0x00, ssid.len() as u8];
+        bytes.extend(ssid.as_bytes());
+        if let Some(mdid) = mdid {
+            bytes.extend([0x36, 0x03]);
+            bytes.extend(mdid.to_le_bytes());
+            bytes.push(0x01);
+        }
+        bytes.extend([
+            0x30,
+            0x14,
+            0x01,
+            0x00,
+            0x00,
+            0x0F,
+            0xAC,
+            0x04,
+            0x01,
+            0x00,
+            0x00,
+            0x0F,
+            0xAC,
+            0x04,
+            0x01,
+            0x00,
+            0x00,
+            0x0F,
+            0xAC,
+            akm_suite_type,
+            0x00,
+            0x00,
+        ]);
+        bytes
+    }
+
+    #[test]
+    fn group_by_mdid() {
+        let bsses = vec![
+            Bss::from_last_octet(1, 5180, &ie_bytes("Office", Some(0x1234), 0x04)),
+            Bss::from_last_octet(2, 5180, &ie_bytes("Office", Some(0x1234), 0x02)),
+            Bss::from_last_octet(3, 5180, &ie_bytes("Office", None, 0x02)),
+            Bss::from_last_octet(4, 5180, &ie_bytes("Lab", Some(0x5678), 0x04)),
+        ];
+
+        assert_eq!(bsses[0].mobility_domain_id(), Some(0x1234));
+        assert!(bsses[0].advertises_fast_transition());
+        assert!(!bsses[1].advertises_fast_transition());
+
+        let groups = MobilityDomainGroup::group(&bsses);
+        assert_eq!(
+            groups.iter().map(|group| group.mdid()).collect::<Vec<_>>(),
+            vec![0x1234, 0x5678]
+        );
+        assert_eq!(groups[0].bsses().len(), 2);
+        assert_eq!(groups[0].ssids(), vec!["Office"]);
+        assert_eq!(groups[0].bsses_without_fast_transition(), vec![&bsses[1]]);
+
+        // The third Office BSS isn't in the mobility domain
+        assert!(!groups[0].covers_ess("Office", &bsses));
+        assert!(groups[1].covers_ess("Lab", &bsses));
+        assert!(!groups[1].covers_ess("Office", &bsses));
+
+        // A scan's set of BSSs works as well, and the Office ESS is covered without its BSS
+        // that's outside the mobility domain
+        let scan: HashSet<Bss> = bsses.iter().take(2).cloned().collect();
+        assert!(groups[0].covers_ess("Office", &scan));
+        assert!(!groups[1].covers_ess("Office", &scan));
+    }
+}
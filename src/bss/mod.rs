@@ -1,5 +1,23 @@
+mod ap_mld;
 mod capability_info;
+mod channel_planner;
+mod channel_switch;
+mod mobility_domain_group;
+mod phy_rate;
+mod six_ghz_neighbor;
+mod twenty_forty_coexistence;
+pub use ap_mld::ApMld;
 pub use capability_info::CapabilityInfo;
+pub use channel_planner::{
+    ChannelPlanPolicy, ChannelPlanner, ChannelRecommendation, ScoreFactor, ScoreFactorKind,
+};
+pub use channel_switch::ChannelSwitch;
+pub use mobility_domain_group::MobilityDomainGroup;
+pub use phy_rate::{GuardInterval, PhyRate};
+pub use six_ghz_neighbor::SixGhzNeighbor;
+pub use twenty_forty_coexistence::{
+    CoexistenceConflict, CoexistenceTrigger, TwentyFortyCoexistence,
+};
 
 cfg_if::cfg_if! {
     if #[cfg(target_os = "linux")] {
@@ -0,0 +1,453 @@
+use crate::{ies::Ie, Channel, ChannelBand, ChannelWidth, WifiProtocol};
+use std::fmt::Display;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum GuardInterval {
+    FourHundredNs,
+    EightHundredNs,
+    SixteenHundredNs,
+    ThirtyTwoHundredNs,
+}
+
+impl GuardInterval {
+    pub fn duration_us(&self) -> f64 {
+        match self {
+            GuardInterval::FourHundredNs => 0.4,
+            GuardInterval::EightHundredNs => 0.8,
+            GuardInterval::SixteenHundredNs => 1.6,
+            GuardInterval::ThirtyTwoHundredNs => 3.2,
+        }
+    }
+}
+
+impl Display for GuardInterval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} μs", self.duration_us())
+    }
+}
+
+/// A theoretical PHY rate and the MCS, number of spatial streams, channel width and guard interval
+/// that give it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PhyRate {
+    rate_mbps: f64,
+    protocol: WifiProtocol,
+    mcs: Option<u8>,
+    nss: u8,
+    width: ChannelWidth,
+    guard_interval: Option<GuardInterval>,
+}
+
+impl PhyRate {
+    /// Finds the highest PHY rate that the BSS supports on its operating channel, from the
+    /// supported rates, the HT Rx MCS bitmask and the VHT and HE Rx MCS maps.
+    pub fn max_from_ies(ies: &[Ie]) -> Option<PhyRate> {
+        let band = Channel::from_ies(ies).map(|channel| channel.band());
+        let width = ChannelWidth::from(ies);
+
+        [
+            Self::max_legacy(ies, band),
+            Self::max_ht(ies, width),
+            Self::max_vht(ies, width),
+            Self::max_he(ies, width),
+        ]
+        .iter()
+        .flatten()
+        .copied()
+        .fold(None, max_phy_rate)
+    }
+
+    fn max_legacy(ies: &[Ie], band: Option<ChannelBand>) -> Option<PhyRate> {
+        let rate_mbps = ies
+            .iter()
+            .flat_map(|ie| match ie {
+                Ie::SupportedRates(supported_rates) => supported_rates.all_rates(),
+                Ie::ExtendedSupportedRates(extended_supported_rates) => {
+                    extended_supported_rates.all_rates()
+                }
+                _ => Vec::new(),
+            })
+            .fold(None, |max_rate_mbps: Option<f64>, rate_mbps| {
+                Some(max_rate_mbps.map_or(rate_mbps, |max_rate_mbps| max_rate_mbps.max(rate_mbps)))
+            })?;
+
+        // The DSSS and CCK rates don't have an OFDM counterpart
+        let dsss = [1.0, 2.0, 5.5, 11.0, 22.0, 33.0].contains(&rate_mbps);
+
+        Some(PhyRate {
+            rate_mbps,
+            protocol: if dsss {
+                WifiProtocol::B
+            } else if band == Some(ChannelBand::TwoPointFourGhz) {
+                WifiProtocol::G
+            } else {
+                WifiProtocol::A
+            },
+            mcs: None,
+            nss: 1,
+            width: if dsss {
+                ChannelWidth::TwentyTwoMhz
+            } else {
+                ChannelWidth::TwentyMhz
+            },
+            guard_interval: if dsss {
+                None
+            } else {
+                Some(GuardInterval::EightHundredNs)
+            },
+        })
+    }
+
+    fn max_ht(ies: &[Ie], width: ChannelWidth) -> Option<PhyRate> {
+        let ht_capabilities = ies.iter().find_map(|ie| match ie {
+            Ie::HtCapabilities(ht_capabilities) => Some(ht_capabilities),
+            _ => None,
+        })?;
+
+        let width = match width {
+            ChannelWidth::TwentyMhz | ChannelWidth::TwentyTwoMhz => ChannelWidth::TwentyMhz,
+            _ => ChannelWidth::FortyMhz,
+        };
+        let short_gi = match width {
+            ChannelWidth::TwentyMhz => ht_capabilities.short_gi_twenty_mhz(),
+            _ => ht_capabilities.short_gi_forty_mhz(),
+        };
+        let guard_interval = if short_gi {
+            GuardInterval::FourHundredNs
+        } else {
+            GuardInterval::EightHundredNs
+        };
+
+        // HT-MCSs 0 to 31 use equal modulation on 1 to 4 spatial streams
+        (0..=31)
+            .filter(|mcs| ht_capabilities.rx_mcs_supported(*mcs))
+            .filter_map(|mcs| {
+                let nss = mcs / 8 + 1;
+                Some(PhyRate {
+                    rate_mbps: vht_rate_mbps(mcs % 8, nss, width, guard_interval)?,
+                    protocol: WifiProtocol::N,
+                    mcs: Some(mcs),
+                    nss,
+                    width,
+                    guard_interval: Some(guard_interval),
+                })
+            })
+            .fold(None, max_phy_rate)
+    }
+
+    fn max_vht(ies: &[Ie], width: ChannelWidth) -> Option<PhyRate> {
+        let vht_capabilities = ies.iter().find_map(|ie| match ie {
+            Ie::VhtCapabilities(vht_capabilities) => Some(vht_capabilities),
+            _ => None,
+        })?;
+        let ht_capabilities = ies.iter().find_map(|ie| match ie {
+            Ie::HtCapabilities(ht_capabilities) => Some(ht_capabilities),
+            _ => None,
+        });
+
+        let width = match width {
+            ChannelWidth::TwentyMhz | ChannelWidth::TwentyTwoMhz => ChannelWidth::TwentyMhz,
+            ChannelWidth::FortyMhz => ChannelWidth::FortyMhz,
+            ChannelWidth::EightyMhz => ChannelWidth::EightyMhz,
+            _ => ChannelWidth::OneSixtyMhz,
+        };
+        let short_gi =
+            match width {
+                ChannelWidth::TwentyMhz => ht_capabilities
+                    .is_some_and(|ht_capabilities| ht_capabilities.short_gi_twenty_mhz()),
+                ChannelWidth::FortyMhz => ht_capabilities
+                    .is_some_and(|ht_capabilities| ht_capabilities.short_gi_forty_mhz()),
+                ChannelWidth::EightyMhz => vht_capabilities.short_gi_eighty_mhz(),
+                _ => vht_capabilities.short_gi_one_sixty_and_eighty_plus_eighty_mhz(),
+            };
+        let guard_interval = if short_gi {
+            GuardInterval::FourHundredNs
+        } else {
+            GuardInterval::EightHundredNs
+        };
+
+        let rx_vht_mcs_map = vht_capabilities.rx_vht_mcs_map();
+
+        (1..=8)
+            .filter_map(|nss| {
+                let max_mcs = rx_vht_mcs_map.mcs_support(nss).max_mcs()?;
+
+                // Some MCS, NSS and width combinations are invalid, so fall back to the next
+                // lower MCS
+                (0..=max_mcs).rev().find_map(|mcs| {
+                    Some(PhyRate {
+                        rate_mbps: vht_rate_mbps(mcs, nss, width, guard_interval)?,
+                        protocol: WifiProtocol::AC,
+                        mcs: Some(mcs),
+                        nss,
+                        width,
+                        guard_interval: Some(guard_interval),
+                    })
+                })
+            })
+            .fold(None, max_phy_rate)
+    }
+
+    fn max_he(ies: &[Ie], width: ChannelWidth) -> Option<PhyRate> {
+        let he_capabilities = ies.iter().find_map(|ie| match ie {
+            Ie::HeCapabilities(he_capabilities) => Some(he_capabilities),
+            _ => None,
+        })?;
+
+        let (width, rx_he_mcs_map) = match width {
+            ChannelWidth::TwentyMhz | ChannelWidth::TwentyTwoMhz => (
+                ChannelWidth::TwentyMhz,
+                he_capabilities.rx_he_mcs_map_less_than_or_equal_eighty_mhz(),
+            ),
+            ChannelWidth::FortyMhz | ChannelWidth::EightyMhz => (
+                width,
+                he_capabilities.rx_he_mcs_map_less_than_or_equal_eighty_mhz(),
+            ),
+            ChannelWidth::EightyPlusEightyMhz => (
+                ChannelWidth::OneSixtyMhz,
+                he_capabilities
+                    .rx_he_mcs_map_eighty_plus_eighty_mhz()
+                    .or_else(|| he_capabilities.rx_he_mcs_map_one_sixty_mhz())?,
+            ),
+            _ => (
+                ChannelWidth::OneSixtyMhz,
+                he_capabilities.rx_he_mcs_map_one_sixty_mhz()?,
+            ),
+        };
+
+        // HE SU PPDUs with a 0.8 μs guard interval are mandatory
+        (1..=8)
+            .filter_map(|nss| {
+                let mcs = rx_he_mcs_map.mcs_support(nss).max_mcs()?;
+                Some(PhyRate {
+                    rate_mbps: he_rate_mbps(mcs, nss, width)?,
+                    protocol: WifiProtocol::AX,
+                    mcs: Some(mcs),
+                    nss,
+                    width,
+                    guard_interval: Some(GuardInterval::EightHundredNs),
+                })
+            })
+            .fold(None, max_phy_rate)
+    }
+
+    pub fn rate_mbps(&self) -> f64 {
+        self.rate_mbps
+    }
+
+    pub fn protocol(&self) -> WifiProtocol {
+        self.protocol
+    }
+
+    /// The MCS index, which is None for the legacy rates.
+    pub fn mcs(&self) -> Option<u8> {
+        self.mcs
+    }
+
+    pub fn nss(&self) -> u8 {
+        self.nss
+    }
+
+    pub fn width(&self) -> ChannelWidth {
+        self.width
+    }
+
+    /// The guard interval, which is None for the DSSS and CCK rates.
+    pub fn guard_interval(&self) -> Option<GuardInterval> {
+        self.guard_interval
+    }
+}
+
+impl Display for PhyRate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:.1} Mb/s (802.11{}", self.rate_mbps, self.protocol)?;
+        if let Some(mcs) = self.mcs {
+            write!(f, ", MCS {}, {} SS", mcs, self.nss)?;
+        }
+        write!(f, ", {}", self.width)?;
+        if let Some(guard_interval) = self.guard_interval {
+            write!(f, ", {} GI", guard_interval)?;
+        }
+        write!(f, ")")
+    }
+}
+
+fn max_phy_rate(max_rate: Option<PhyRate>, rate: PhyRate) -> Option<PhyRate> {
+    match max_rate {
+        Some(max_rate) if max_rate.rate_mbps >= rate.rate_mbps => Some(max_rate),
+        _ => Some(rate),
+    }
+}
+
+// The coded bits per subcarrier per spatial stream and the coding rate of MCSs 0 to 11, which HT
+// (modulo 8), VHT and HE share
+fn modulation_and_coding_rate(mcs: u8) -> Option<(u32, u32, u32)> {
+    match mcs {
+        0 => Some((1, 1, 2)),
+        1 => Some((2, 1, 2)),
+        2 => Some((2, 3, 4)),
+        3 => Some((4, 1, 2)),
+        4 => Some((4, 3, 4)),
+        5 => Some((6, 2, 3)),
+        6 => Some((6, 3, 4)),
+        7 => Some((6, 5, 6)),
+        8 => Some((8, 3, 4)),
+        9 => Some((8, 5, 6)),
+        10 => Some((10, 3, 4)),
+        11 => Some((10, 5, 6)),
+        _ => None,
+    }
+}
+
+// The data bits per OFDM symbol, which HT and VHT require to be a whole number, ruling out
+// combinations like VHT-MCS 9 on one spatial stream at 20 MHz
+fn data_bits_per_symbol(
+    mcs: u8,
+    nss: u8,
+    data_subcarriers: u32,
+    require_whole_bits: bool,
+) -> Option<f64> {
+    let (bits_per_subcarrier, coding_numerator, coding_denominator) =
+        modulation_and_coding_rate(mcs)?;
+    let coded_bits = data_subcarriers * bits_per_subcarrier * u32::from(nss) * coding_numerator;
+
+    if require_whole_bits && coded_bits % coding_denominator != 0 {
+        None
+    } else {
+        Some(f64::from(coded_bits) / f64::from(coding_denominator))
+    }
+}
+
+// HT and VHT use 3.2 μs symbols with 52, 108, 234 or 468 data subcarriers
+fn vht_rate_mbps(
+    mcs: u8,
+    nss: u8,
+    width: ChannelWidth,
+    guard_interval: GuardInterval,
+) -> Option<f64> {
+    // The VHT-MCS tables also leave out these combinations, whose data bits can't be split
+    // evenly across the BCC encoders
+    if matches!(
+        (width, mcs, nss),
+        (ChannelWidth::EightyMhz, 6, 3 | 7)
+            | (ChannelWidth::EightyMhz, 9, 6)
+            | (
+                ChannelWidth::OneSixtyMhz | ChannelWidth::EightyPlusEightyMhz,
+                9,
+                3
+            )
+    ) {
+        return None;
+    }
+
+    let data_subcarriers = match width {
+        ChannelWidth::TwentyMhz => 52,
+        ChannelWidth::FortyMhz => 108,
+        ChannelWidth::EightyMhz => 234,
+        ChannelWidth::OneSixtyMhz | ChannelWidth::EightyPlusEightyMhz => 468,
+        _ => return None,
+    };
+
+    data_bits_per_symbol(mcs, nss, data_subcarriers, true)
+        .map(|bits| bits / (3.2 + guard_interval.duration_us()))
+}
+
+// HE uses 12.8 μs symbols with 234, 468, 980 or 1960 data subcarriers
+fn he_rate_mbps(mcs: u8, nss: u8, width: ChannelWidth) -> Option<f64> {
+    let data_subcarriers = match width {
+        ChannelWidth::TwentyMhz => 234,
+        ChannelWidth::FortyMhz => 468,
+        ChannelWidth::EightyMhz => 980,
+        ChannelWidth::OneSixtyMhz | ChannelWidth::EightyPlusEightyMhz => 1960,
+        _ => return None,
+    };
+
+    data_bits_per_symbol(mcs, nss, data_subcarriers, false)
+        .map(|bits| bits / (12.8 + GuardInterval::EightHundredNs.duration_us()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ies;
+
+    #[test]
+    fn mcs_rates() {
+        // VHT-MCS 9, 1 SS, 80 MHz, short GI
+        let rate_mbps =
+            vht_rate_mbps(9, 1, ChannelWidth::EightyMhz, GuardInterval::FourHundredNs).unwrap();
+        assert!((rate_mbps - 433.3).abs() < 0.1);
+
+        // HT-MCS 15, 40 MHz, short GI
+        let rate_mbps =
+            vht_rate_mbps(7, 2, ChannelWidth::FortyMhz, GuardInterval::FourHundredNs).unwrap();
+        assert!((rate_mbps - 300.0).abs() < 0.1);
+
+        // HE-MCS 11, 2 SS, 160 MHz
+        let rate_mbps = he_rate_mbps(11, 2, ChannelWidth::OneSixtyMhz).unwrap();
+        assert!((rate_mbps - 2401.9).abs() < 0.1);
+
+        assert_eq!(
+            vht_rate_mbps(9, 1, ChannelWidth::TwentyMhz, GuardInterval::EightHundredNs),
+            None
+        );
+        assert_eq!(
+            vht_rate_mbps(6, 3, ChannelWidth::EightyMhz, GuardInterval::EightHundredNs),
+            None
+        );
+        assert_eq!(
+            vht_rate_mbps(9, 6, ChannelWidth::EightyMhz, GuardInterval::EightHundredNs),
+            None
+        );
+        assert_eq!(
+            vht_rate_mbps(
+                9,
+                3,
+                ChannelWidth::OneSixtyMhz,
+                GuardInterval::EightHundredNs
+            ),
+            None
+        );
+    }
+
+    // HT Capabilities with 40 MHz, short GI at 20 and 40 MHz and HT-MCSs 0 to 15, and an HT
+    // Operation element for a 40 MHz BSS on channel 36
+    fn ht_ie_bytes() -> Vec<u8> {
+        let mut bytes = vec![0x2D, 0x1A, 0x62, 0x00, 0x17, 0xFF, 0xFF];
+        bytes.extend([0x00; 21]);
+        bytes.extend([0x3D, 0x16, 0x24, 0x05]);
+        bytes.extend([0x00; 20]);
+        bytes
+    }
+
+    #[test]
+    fn max_ht_forty_mhz() {
+        let ies = ies::from_bytes(&ht_ie_bytes()).unwrap();
+        let phy_rate = PhyRate::max_from_ies(&ies).unwrap();
+
+        assert_eq!(phy_rate.protocol(), WifiProtocol::N);
+        assert_eq!(phy_rate.mcs(), Some(15));
+        assert_eq!(phy_rate.width(), ChannelWidth::FortyMhz);
+        assert!((phy_rate.rate_mbps() - 300.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn max_vht_one_sixty_mhz() {
+        // VHT Capabilities with 160 MHz, short GI at 80 and 160 MHz and VHT-MCSs 0 to 9 on 3
+        // spatial streams, and a VHT Operation element for a 160 MHz BSS centered on channel 50
+        let mut bytes = ht_ie_bytes();
+        bytes.extend([
+            0xBF, 0x0C, 0x64, 0x00, 0x00, 0x00, 0xEA, 0xFF, 0x00, 0x00, 0xEA, 0xFF, 0x00, 0x00,
+        ]);
+        bytes.extend([0xC0, 0x05, 0x01, 0x2A, 0x32, 0x00, 0x00]);
+        let ies = ies::from_bytes(&bytes).unwrap();
+        let phy_rate = PhyRate::max_from_ies(&ies).unwrap();
+
+        // VHT-MCS 9 isn't valid on 3 spatial streams at 160 MHz
+        assert_eq!(phy_rate.protocol(), WifiProtocol::AC);
+        assert_eq!(phy_rate.mcs(), Some(8));
+        assert_eq!(phy_rate.nss(), 3);
+        assert_eq!(phy_rate.width(), ChannelWidth::OneSixtyMhz);
+        assert!((phy_rate.rate_mbps() - 2340.0).abs() < 0.1);
+    }
+}
@@ -0,0 +1,174 @@
+use crate::{ies::Ie, Bss, ChannelBand, OperatingClass};
+use macaddr::MacAddr6;
+
+/// A 6 GHz BSS that a 2.4 or 5 GHz BSS reports as co-located in its Reduced Neighbor Report
+/// element.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SixGhzNeighbor {
+    bssid: Option<MacAddr6>,
+    short_ssid: Option<u32>,
+    ssid: Option<String>,
+    operating_class: u8,
+    channel: u8,
+    reported_by: Vec<MacAddr6>,
+    heard: bool,
+}
+
+impl SixGhzNeighbor {
+    /// Finds the co-located 6 GHz BSSs reported by the scanned BSSs, including the ones that
+    /// weren't scanned. A neighbor reported by several BSSs is only listed once.
+    pub fn from_scan<'a>(bsses: impl IntoIterator<Item = &'a Bss>) -> Vec<SixGhzNeighbor> {
+        let bsses: Vec<&Bss> = bsses.into_iter().collect();
+        let mut neighbors: Vec<SixGhzNeighbor> = Vec::new();
+
+        for bss in bsses.iter() {
+            let reduced_neighbor_reports = bss.ies().iter().filter_map(|ie| match ie {
+                Ie::ReducedNeighborReport(reduced_neighbor_report) => Some(reduced_neighbor_report),
+                _ => None,
+            });
+
+            for neighbor_ap_information in reduced_neighbor_reports
+                .flat_map(|reduced_neighbor_report| {
+                    reduced_neighbor_report.neighbor_ap_informations()
+                })
+                .filter(|neighbor_ap_information| neighbor_ap_information.is_six_ghz())
+            {
+                for tbtt_information in neighbor_ap_information.tbtt_informations() {
+                    let bss_parameters = match tbtt_information.bss_parameters() {
+                        Some(bss_parameters) if bss_parameters.colocated_ap() => bss_parameters,
+                        _ => continue,
+                    };
+
+                    let neighbor = SixGhzNeighbor {
+                        bssid: tbtt_information.bssid(),
+                        short_ssid: tbtt_information.short_ssid(),
+                        ssid: if bss_parameters.same_ssid() {
+                            bss.ssid().map(String::from)
+                        } else {
+                            None
+                        },
+                        operating_class: neighbor_ap_information.operating_class(),
+                        channel: neighbor_ap_information.channel(),
+                        reported_by: vec![bss.bssid()],
+                        heard: tbtt_information
+                            .bssid()
+                            .is_some_and(|bssid| bsses.iter().any(|bss| bss.bssid() == bssid)),
+                    };
+
+                    match neighbors
+                        .iter_mut()
+                        .find(|other_neighbor| other_neighbor.is_same_bss(&neighbor))
+                    {
+                        Some(other_neighbor) => {
+                            if !other_neighbor.reported_by.contains(&bss.bssid()) {
+                                other_neighbor.reported_by.push(bss.bssid());
+                            }
+                            if other_neighbor.ssid.is_none() {
+                                other_neighbor.ssid = neighbor.ssid;
+                            }
+                        }
+                        None => neighbors.push(neighbor),
+                    }
+                }
+            }
+        }
+
+        neighbors
+    }
+
+    fn is_same_bss(&self, other: &SixGhzNeighbor) -> bool {
+        match (self.bssid, other.bssid) {
+            (Some(bssid), Some(other_bssid)) => bssid == other_bssid,
+            _ => {
+                self.channel == other.channel
+                    && self.short_ssid.is_some()
+                    && self.short_ssid == other.short_ssid
+            }
+        }
+    }
+
+    pub fn bssid(&self) -> Option<MacAddr6> {
+        self.bssid
+    }
+
+    /// The CRC-32 of the neighbor's SSID.
+    pub fn short_ssid(&self) -> Option<u32> {
+        self.short_ssid
+    }
+
+    /// The SSID of the neighbor, which is only known when it's the same as a reporting BSS's SSID.
+    pub fn ssid(&self) -> Option<&str> {
+        self.ssid.as_deref()
+    }
+
+    pub fn operating_class(&self) -> u8 {
+        self.operating_class
+    }
+
+    pub fn channel(&self) -> u8 {
+        self.channel
+    }
+
+    /// The frequency of the neighbor's primary 20 MHz channel.
+    pub fn frequency_mhz(&self) -> Option<u32> {
+        OperatingClass::channel_frequency_mhz(ChannelBand::SixGhz, self.channel)
+    }
+
+    /// The BSSIDs of the scanned BSSs that reported the neighbor.
+    pub fn reported_by(&self) -> &[MacAddr6] {
+        &self.reported_by
+    }
+
+    /// Whether the neighbor itself was in the scan.
+    pub fn heard(&self) -> bool {
+        self.heard
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn colocated_six_ghz_neighbor() {
+        let neighbor_bssid = MacAddr6::new(0x02, 0x11, 0x22, 0x33, 0x44, 0x55);
+        let reporting_bss = Bss::from_ie_bytes(
+            MacAddr6::new(0x02, 0x00, 0x00, 0x00, 0x00, 0x01),
+            5180,
+            &[
+                // SSID "Office"
+                0, 6, b'O', b'f', b'f', b'i', b'c', b'e',
+                // Reduced Neighbor Report with 6 GHz channel 37 and one 13-octet TBTT
+                // Information field for a co-located AP with the same SSID
+                201, 17, 0x00, 0x0D, 0x83, 0x25, 0xFF, 0x02, 0x11, 0x22, 0x33, 0x44, 0x55, 0x78,
+                0x56, 0x34, 0x12, 0x46, 0x2E,
+            ],
+        );
+
+        let neighbors = SixGhzNeighbor::from_scan(vec![&reporting_bss]);
+        assert_eq!(neighbors.len(), 1);
+        assert_eq!(neighbors[0].bssid(), Some(neighbor_bssid));
+        assert_eq!(neighbors[0].short_ssid(), Some(0x12345678));
+        assert_eq!(neighbors[0].ssid(), Some("Office"));
+        assert_eq!(neighbors[0].operating_class(), 131);
+        assert_eq!(neighbors[0].frequency_mhz(), Some(6135));
+        assert_eq!(neighbors[0].reported_by(), &[reporting_bss.bssid()]);
+        assert!(!neighbors[0].heard());
+
+        // Once the neighbor itself is in the scan, it's heard
+        let scan: HashSet<Bss> = vec![
+            reporting_bss,
+            Bss::from_ie_bytes(
+                neighbor_bssid,
+                6135,
+                &[0, 6, b'O', b'f', b'f', b'i', b'c', b'e'],
+            ),
+        ]
+        .into_iter()
+        .collect();
+        let neighbors = SixGhzNeighbor::from_scan(&scan);
+        assert_eq!(neighbors.len(), 1);
+        assert!(neighbors[0].heard());
+    }
+}
@@ -0,0 +1,396 @@
+use crate::{ies::Ie, ies::SecondaryChannelOffset, Bss, Channel, ChannelBand, ChannelNumber};
+use macaddr::MacAddr6;
+use std::fmt::Display;
+
+/// Why a neighboring BSS keeps a 2.4 GHz BSS from operating at 40 MHz.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CoexistenceTrigger {
+    /// The HT Capabilities element has the Forty MHz Intolerant bit set.
+    FortyMhzIntolerant,
+    /// The 20/40 BSS Coexistence element has the Forty MHz Intolerant bit set.
+    CoexistenceFortyMhzIntolerant,
+    /// The 20/40 BSS Coexistence element has the 20 MHz BSS Width Request bit set.
+    TwentyMhzBssWidthRequest,
+    /// A 20 MHz BSS in the affected frequency range whose channel isn't the primary channel.
+    TwentyMhzBss,
+    /// A 20/40 MHz BSS whose primary channel is in the affected frequency range but isn't the
+    /// primary channel.
+    PrimaryChannelMismatch,
+    /// A 20/40 MHz BSS in the affected frequency range with another secondary channel.
+    SecondaryChannelMismatch,
+}
+
+impl Display for CoexistenceTrigger {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CoexistenceTrigger::FortyMhzIntolerant => write!(f, "40 MHz Intolerant"),
+            CoexistenceTrigger::CoexistenceFortyMhzIntolerant => {
+                write!(f, "40 MHz Intolerant (20/40 BSS Coexistence)")
+            }
+            CoexistenceTrigger::TwentyMhzBssWidthRequest => write!(f, "20 MHz BSS Width Request"),
+            CoexistenceTrigger::TwentyMhzBss => write!(f, "Overlapping 20 MHz BSS"),
+            CoexistenceTrigger::PrimaryChannelMismatch => write!(f, "Primary Channel Mismatch"),
+            CoexistenceTrigger::SecondaryChannelMismatch => {
+                write!(f, "Secondary Channel Mismatch")
+            }
+        }
+    }
+}
+
+/// A neighboring BSS that keeps the evaluated BSS from operating at 40 MHz.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CoexistenceConflict {
+    bssid: MacAddr6,
+    channel: ChannelNumber,
+    secondary_channel: Option<ChannelNumber>,
+    trigger: CoexistenceTrigger,
+}
+
+impl CoexistenceConflict {
+    pub fn bssid(&self) -> MacAddr6 {
+        self.bssid
+    }
+
+    /// The primary channel of the neighboring BSS.
+    pub fn channel(&self) -> ChannelNumber {
+        self.channel
+    }
+
+    /// The secondary channel of the neighboring BSS if it's a 20/40 MHz BSS.
+    pub fn secondary_channel(&self) -> Option<ChannelNumber> {
+        self.secondary_channel
+    }
+
+    pub fn trigger(&self) -> CoexistenceTrigger {
+        self.trigger
+    }
+}
+
+impl Display for CoexistenceConflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} on Channel {}", self.bssid, self.channel)?;
+        if let Some(secondary_channel) = self.secondary_channel {
+            write!(f, " (Secondary Channel {})", secondary_channel)?;
+        }
+        write!(f, ": {}", self.trigger)
+    }
+}
+
+/// Whether a 2.4 GHz BSS may operate at 40 MHz next to the BSSs around it, following the 20/40
+/// BSS coexistence rules of IEEE Std 802.11-2016 11.16.12.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TwentyFortyCoexistence {
+    bssid: MacAddr6,
+    primary_channel: ChannelNumber,
+    secondary_channel: ChannelNumber,
+    operating_at_forty_mhz: bool,
+    affected_freq_range_mhz: (u32, u32),
+    transition_delay_secs: Option<u32>,
+    conflicts: Vec<CoexistenceConflict>,
+}
+
+impl TwentyFortyCoexistence {
+    /// Evaluates the target BSS's 40 MHz channel against the other scanned BSSs. A 20 MHz target
+    /// is evaluated as if it used the secondary channel above its primary channel, or below it if
+    /// there's no channel above. Returns None if the target isn't a 2.4 GHz BSS or is on
+    /// channel 14, which has no secondary channel.
+    pub fn evaluate<'a>(
+        target: &Bss,
+        bsses: impl IntoIterator<Item = &'a Bss>,
+    ) -> Option<TwentyFortyCoexistence> {
+        TwentyFortyCoexistence::from_channels(
+            target.bssid(),
+            target.channel()?,
+            target.ies(),
+            bsses
+                .into_iter()
+                .filter(|bss| bss.bssid() != target.bssid())
+                .filter_map(|bss| Some((bss.bssid(), bss.channel()?, bss.ies()))),
+        )
+    }
+
+    fn from_channels<'a>(
+        bssid: MacAddr6,
+        channel: Channel,
+        ies: &[Ie],
+        neighbors: impl Iterator<Item = (MacAddr6, Channel, &'a [Ie])>,
+    ) -> Option<TwentyFortyCoexistence> {
+        if channel.band() != ChannelBand::TwoPointFourGhz || *channel.number() == 14 {
+            return None;
+        }
+
+        let primary_freq_mhz = channel.center_freq_mhz();
+        let secondary_channel_offset = ht_secondary_channel_offset(ies);
+        let operating_at_forty_mhz =
+            secondary_channel_offset != SecondaryChannelOffset::NoSecondaryChannel;
+        let secondary_freq_mhz = match secondary_channel_offset {
+            SecondaryChannelOffset::Above => primary_freq_mhz + 20,
+            SecondaryChannelOffset::Below => primary_freq_mhz - 20,
+            SecondaryChannelOffset::NoSecondaryChannel if *channel.number() <= 9 => {
+                primary_freq_mhz + 20
+            }
+            SecondaryChannelOffset::NoSecondaryChannel => primary_freq_mhz - 20,
+        };
+        let secondary_channel =
+            ChannelBand::TwoPointFourGhz.channel_number(secondary_freq_mhz * 1000)?;
+
+        // The affected frequency range is 25 MHz either side of the center of the 40 MHz channel
+        let center_freq_mhz = (primary_freq_mhz + secondary_freq_mhz) / 2;
+        let affected_freq_range_mhz = (center_freq_mhz - 25, center_freq_mhz + 25);
+        let is_affected = |freq_mhz: u32| {
+            affected_freq_range_mhz.0 <= freq_mhz && freq_mhz <= affected_freq_range_mhz.1
+        };
+
+        let mut conflicts = Vec::new();
+        for (neighbor_bssid, neighbor_channel, neighbor_ies) in neighbors {
+            if neighbor_channel.band() != ChannelBand::TwoPointFourGhz {
+                continue;
+            }
+
+            let neighbor_primary_freq_mhz = neighbor_channel.center_freq_mhz();
+            let neighbor_secondary_freq_mhz = match ht_secondary_channel_offset(neighbor_ies) {
+                SecondaryChannelOffset::Above => Some(neighbor_primary_freq_mhz + 20),
+                SecondaryChannelOffset::Below => Some(neighbor_primary_freq_mhz - 20),
+                SecondaryChannelOffset::NoSecondaryChannel => None,
+            };
+            let mut conflict = |trigger| {
+                conflicts.push(CoexistenceConflict {
+                    bssid: neighbor_bssid,
+                    channel: neighbor_channel.number(),
+                    secondary_channel: neighbor_secondary_freq_mhz.and_then(|freq_mhz| {
+                        ChannelBand::TwoPointFourGhz.channel_number(freq_mhz * 1000)
+                    }),
+                    trigger,
+                })
+            };
+
+            // Forty MHz intolerance applies to the whole band rather than the affected range
+            let forty_mhz_intolerant = neighbor_ies.iter().any(|ie| match ie {
+                Ie::HtCapabilities(ht_capabilities) => ht_capabilities.forty_mhz_intolerant(),
+                _ => false,
+            });
+            if forty_mhz_intolerant {
+                conflict(CoexistenceTrigger::FortyMhzIntolerant);
+            }
+            for ie in neighbor_ies {
+                if let Ie::TwentyFortyBssCoexistence(twenty_forty_bss_coexistence) = ie {
+                    if twenty_forty_bss_coexistence.forty_mhz_intolerant() {
+                        conflict(CoexistenceTrigger::CoexistenceFortyMhzIntolerant);
+                    }
+                    if twenty_forty_bss_coexistence.twenty_mhz_bss_width_request() {
+                        conflict(CoexistenceTrigger::TwentyMhzBssWidthRequest);
+                    }
+                }
+            }
+
+            match neighbor_secondary_freq_mhz {
+                // 20/40 MHz BSSs with either channel in the affected range must use the same
+                // secondary channel, and the same primary channel if it's the one in the range
+                Some(neighbor_secondary_freq_mhz)
+                    if is_affected(neighbor_primary_freq_mhz)
+                        || is_affected(neighbor_secondary_freq_mhz) =>
+                {
+                    if is_affected(neighbor_primary_freq_mhz)
+                        && neighbor_primary_freq_mhz != primary_freq_mhz
+                    {
+                        conflict(CoexistenceTrigger::PrimaryChannelMismatch);
+                    } else if neighbor_secondary_freq_mhz != secondary_freq_mhz {
+                        conflict(CoexistenceTrigger::SecondaryChannelMismatch);
+                    }
+                }
+                // 20 MHz BSSs in the affected range must be on the primary channel
+                None if is_affected(neighbor_primary_freq_mhz)
+                    && neighbor_primary_freq_mhz != primary_freq_mhz =>
+                {
+                    conflict(CoexistenceTrigger::TwentyMhzBss);
+                }
+                _ => {}
+            }
+        }
+
+        // An AP that has to fall back waits this long after the last trigger before it may
+        // return to 40 MHz
+        let transition_delay_secs = ies.iter().find_map(|ie| match ie {
+            Ie::OverlappingBssScanParams(overlapping_bss_scan_params) => Some(
+                u32::from(
+                    overlapping_bss_scan_params.bss_channel_width_trigger_scan_interval_secs(),
+                ) * u32::from(
+                    overlapping_bss_scan_params.bss_width_channel_transition_delay_factor(),
+                ),
+            ),
+            _ => None,
+        });
+
+        Some(TwentyFortyCoexistence {
+            bssid,
+            primary_channel: channel.number(),
+            secondary_channel,
+            operating_at_forty_mhz,
+            affected_freq_range_mhz,
+            transition_delay_secs,
+            conflicts,
+        })
+    }
+
+    pub fn bssid(&self) -> MacAddr6 {
+        self.bssid
+    }
+
+    pub fn primary_channel(&self) -> ChannelNumber {
+        self.primary_channel
+    }
+
+    /// The secondary channel the evaluated 40 MHz channel uses.
+    pub fn secondary_channel(&self) -> ChannelNumber {
+        self.secondary_channel
+    }
+
+    /// Whether the HT Operation element of the BSS says it's operating at 40 MHz.
+    pub fn operating_at_forty_mhz(&self) -> bool {
+        self.operating_at_forty_mhz
+    }
+
+    /// The lowest and highest frequencies that 20/40 MHz operation affects, 25 MHz either side
+    /// of the center of the 40 MHz channel.
+    pub fn affected_freq_range_mhz(&self) -> (u32, u32) {
+        self.affected_freq_range_mhz
+    }
+
+    /// How long a BSS that fell back to 20 MHz waits without triggers before going back to
+    /// 40 MHz, from its Overlapping BSS Scan Parameters element.
+    pub fn transition_delay_secs(&self) -> Option<u32> {
+        self.transition_delay_secs
+    }
+
+    /// The neighboring BSSs that require the BSS to operate at 20 MHz.
+    pub fn conflicts(&self) -> &[CoexistenceConflict] {
+        &self.conflicts
+    }
+
+    /// Whether the rules allow the BSS to operate at 40 MHz.
+    pub fn forty_mhz_permitted(&self) -> bool {
+        self.conflicts.is_empty()
+    }
+
+    /// Whether the BSS operates at 40 MHz even though the rules don't allow it.
+    pub fn violates_coexistence_rules(&self) -> bool {
+        self.operating_at_forty_mhz && !self.forty_mhz_permitted()
+    }
+}
+
+fn ht_secondary_channel_offset(ies: &[Ie]) -> SecondaryChannelOffset {
+    ies.iter()
+        .find_map(|ie| match ie {
+            Ie::HtOperation(ht_operation) => Some(ht_operation.secondary_channel_offset()),
+            _ => None,
+        })
+        .unwrap_or(SecondaryChannelOffset::NoSecondaryChannel)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ies::{HtOperation, TwentyFortyBssCoexistence};
+
+    #[test]
+    fn forty_mhz_fallback_triggers() {
+        let ht_operation = |primary_channel, secondary_channel_offset: u8| {
+            let mut bytes = vec![0; 22];
+            bytes[0] = primary_channel;
+            bytes[1] = secondary_channel_offset | 0b100;
+            Ie::HtOperation(HtOperation::new(bytes).unwrap())
+        };
+        let channel = |freq_mhz| Channel::from_frequency_mhz(freq_mhz, &[]).unwrap();
+        let bssid = |last_byte| MacAddr6::new(0, 0, 0, 0, 0, last_byte);
+
+        // Channel 1 with its secondary channel above
+        let target_ies = vec![ht_operation(1, 1)];
+        let same_forty_mhz_ies = vec![ht_operation(1, 1)];
+        let other_forty_mhz_ies = vec![ht_operation(5, 3)];
+        let twenty_mhz_ies = vec![ht_operation(3, 0)];
+        let width_request_ies = vec![Ie::TwentyFortyBssCoexistence(
+            TwentyFortyBssCoexistence::new(vec![0b100]).unwrap(),
+        )];
+
+        let coexistence = TwentyFortyCoexistence::from_channels(
+            bssid(0),
+            channel(2412),
+            &target_ies,
+            vec![
+                (bssid(1), channel(2412), same_forty_mhz_ies.as_slice()),
+                (bssid(2), channel(2412), &[][..]),
+                (bssid(3), channel(2462), &[][..]),
+            ]
+            .into_iter(),
+        )
+        .unwrap();
+        assert_eq!(coexistence.secondary_channel(), ChannelNumber::from(5));
+        assert_eq!(coexistence.affected_freq_range_mhz(), (2397, 2447));
+        assert!(coexistence.forty_mhz_permitted());
+
+        let coexistence = TwentyFortyCoexistence::from_channels(
+            bssid(0),
+            channel(2412),
+            &target_ies,
+            vec![
+                (bssid(1), channel(2432), other_forty_mhz_ies.as_slice()),
+                (bssid(2), channel(2422), twenty_mhz_ies.as_slice()),
+                (bssid(3), channel(2472), width_request_ies.as_slice()),
+            ]
+            .into_iter(),
+        )
+        .unwrap();
+        let triggers: Vec<CoexistenceTrigger> = coexistence
+            .conflicts()
+            .iter()
+            .map(|conflict| conflict.trigger())
+            .collect();
+        assert_eq!(
+            triggers,
+            vec![
+                CoexistenceTrigger::PrimaryChannelMismatch,
+                CoexistenceTrigger::TwentyMhzBss,
+                CoexistenceTrigger::TwentyMhzBssWidthRequest,
+            ]
+        );
+        assert!(coexistence.violates_coexistence_rules());
+
+        // Channel 9 with its secondary channel below only has its secondary channel 5 in the
+        // affected range, so it only has to share the secondary channel
+        let secondary_in_range_ies = vec![ht_operation(9, 3)];
+        let coexistence = TwentyFortyCoexistence::from_channels(
+            bssid(0),
+            channel(2412),
+            &target_ies,
+            vec![(bssid(1), channel(2452), secondary_in_range_ies.as_slice())].into_iter(),
+        )
+        .unwrap();
+        assert!(coexistence.forty_mhz_permitted());
+
+        let secondary_in_range_ies = vec![ht_operation(10, 3)];
+        let coexistence = TwentyFortyCoexistence::from_channels(
+            bssid(0),
+            channel(2412),
+            &target_ies,
+            vec![(bssid(1), channel(2457), secondary_in_range_ies.as_slice())].into_iter(),
+        )
+        .unwrap();
+        let triggers: Vec<CoexistenceTrigger> = coexistence
+            .conflicts()
+            .iter()
+            .map(|conflict| conflict.trigger())
+            .collect();
+        assert_eq!(triggers, vec![CoexistenceTrigger::SecondaryChannelMismatch]);
+
+        // Channel 14 has no secondary channel
+        assert_eq!(
+            TwentyFortyCoexistence::from_channels(
+                bssid(0),
+                channel(2484),
+                &[],
+                Vec::new().into_iter()
+            ),
+            None
+        );
+    }
+}
@@ -1,5 +1,5 @@
-use crate::{ChannelWidth, Ie};
-use num_enum::TryFromPrimitive;
+use crate::{ChannelWidth, Ie, OperatingClass};
+use derive_more::{Deref, From};
 use std::{
     convert::{From, TryFrom},
     fmt::Display,
@@ -7,166 +7,203 @@ use std::{
 
 #[derive(Hash, Eq, PartialEq, Copy, Clone, Debug, Ord, PartialOrd)]
 pub enum ChannelBand {
+    NineHundredMhz,
     TwoPointFourGhz,
+    FourPointNineGhz,
     FiveGhz,
     SixGhz,
+    SixtyGhz,
+}
+
+impl ChannelBand {
+    /// The band with the frequency, like Linux's ieee80211_freq_khz_to_channel().
+    pub fn from_frequency_khz(frequency_khz: u32) -> Option<ChannelBand> {
+        match frequency_khz {
+            902_000..=928_000 => Some(ChannelBand::NineHundredMhz),
+            2_412_000..=2_484_000 => Some(ChannelBand::TwoPointFourGhz),
+            4_910_000..=4_980_000 => Some(ChannelBand::FourPointNineGhz),
+            5_000_001..=5_925_000 => Some(ChannelBand::FiveGhz),
+            5_935_000..=7_115_000 => Some(ChannelBand::SixGhz),
+            58_320_000..=70_200_000 => Some(ChannelBand::SixtyGhz),
+            _ => None,
+        }
+    }
+
+    /// The center frequency of the channel in the band, or None if the band has no such channel.
+    /// 2.4, 5 and 6 GHz channels come from the operating class tables. The other bands aren't in
+    /// them, so their channels are numbered from the band's starting frequency, and S1G channels
+    /// use the US channel starting frequency.
+    pub fn channel_frequency_khz(&self, channel: ChannelNumber) -> Option<u32> {
+        let frequency_khz = match self {
+            ChannelBand::TwoPointFourGhz | ChannelBand::FiveGhz | ChannelBand::SixGhz => {
+                return OperatingClass::channel_frequency_mhz(*self, *channel)
+                    .map(|frequency_mhz| frequency_mhz * 1000)
+            }
+            ChannelBand::NineHundredMhz => 902_000 + u32::from(*channel) * 500,
+            ChannelBand::FourPointNineGhz => 4_000_000 + u32::from(*channel) * 5000,
+            ChannelBand::SixtyGhz => 56_160_000 + u32::from(*channel) * 2_160_000,
+        };
+
+        if ChannelBand::from_frequency_khz(frequency_khz) == Some(*self) {
+            Some(frequency_khz)
+        } else {
+            None
+        }
+    }
+
+    /// The number of the channel centered on the frequency, or None if the band has no channel
+    /// there.
+    pub fn channel_number(&self, frequency_khz: u32) -> Option<ChannelNumber> {
+        let (starting_frequency_khz, channel_spacing_khz) = match self {
+            ChannelBand::TwoPointFourGhz | ChannelBand::FiveGhz | ChannelBand::SixGhz => {
+                let frequency_mhz = frequency_khz / 1000;
+                return OperatingClass::channel_from_frequency_mhz(frequency_mhz)
+                    .filter(|(band, _)| band == self && frequency_mhz * 1000 == frequency_khz)
+                    .map(|(_, number)| ChannelNumber(number));
+            }
+            ChannelBand::NineHundredMhz => (902_000, 500),
+            ChannelBand::FourPointNineGhz => (4_000_000, 5000),
+            ChannelBand::SixtyGhz => (56_160_000, 2_160_000),
+        };
+
+        let offset_khz = frequency_khz.checked_sub(starting_frequency_khz)?;
+        if ChannelBand::from_frequency_khz(frequency_khz) != Some(*self)
+            || offset_khz % channel_spacing_khz != 0
+        {
+            return None;
+        }
+
+        u8::try_from(offset_khz / channel_spacing_khz)
+            .ok()
+            .map(ChannelNumber)
+    }
 }
 
 impl Display for ChannelBand {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            ChannelBand::NineHundredMhz => write!(f, "900 MHz"),
             ChannelBand::TwoPointFourGhz => write!(f, "2.4 GHz"),
+            ChannelBand::FourPointNineGhz => write!(f, "4.9 GHz"),
             ChannelBand::FiveGhz => write!(f, "5 GHz"),
             ChannelBand::SixGhz => write!(f, "6 GHz"),
+            ChannelBand::SixtyGhz => write!(f, "60 GHz"),
         }
     }
 }
 
-#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, TryFromPrimitive)]
-#[repr(u8)]
-pub enum ChannelNumber {
-    One = 1,
-    Two = 2,
-    Three = 3,
-    Four = 4,
-    Five = 5,
-    Six = 6,
-    Seven = 7,
-    Eight = 8,
-    Nine = 9,
-    Ten = 10,
-    Eleven = 11,
-    Twelve = 12,
-    Thirteen = 13,
-    Fourteen = 14,
-    ThirtyTwo = 32,
-    ThirtyFour = 34,
-    ThirtySix = 36,
-    ThirtyEight = 38,
-    Forty = 40,
-    FortyTwo = 42,
-    FortyFour = 44,
-    FortySix = 46,
-    FortyEight = 48,
-    Fifty = 50,
-    FiftyTwo = 52,
-    FiftyFour = 54,
-    FiftySix = 56,
-    FiftyEight = 58,
-    Sixty = 60,
-    SixtyTwo = 62,
-    SixtyFour = 64,
-    SixtyEight = 68,
-    NinetySix = 96,
-    OneHundred = 100,
-    OneHundredTwo = 102,
-    OneHundredFour = 104,
-    OneHundredSix = 106,
-    OneHundredEight = 108,
-    OneHundredTen = 110,
-    OneHundredTwelve = 112,
-    OneHundredFourteen = 114,
-    OneHundredSixteen = 116,
-    OneHundredEighteen = 118,
-    OneHundredTwenty = 120,
-    OneHundredTwentyTwo = 122,
-    OneHundredTwentyFour = 124,
-    OneHundredTwentySix = 126,
-    OneHundredTwentyEight = 128,
-    OneHundredThirtyTwo = 132,
-    OneHundredThirtyFour = 134,
-    OneHundredThirtySix = 136,
-    OneHundredThirtyEight = 138,
-    OneHundredForty = 140,
-    OneHundredFortyTwo = 142,
-    OneHundredFortyFour = 144,
-    OneHundredFortyNine = 149,
-    OneHundredFiftyOne = 151,
-    OneHundredFiftyThree = 153,
-    OneHundredFiftyFive = 155,
-    OneHundredFiftySeven = 157,
-    OneHundredFiftyNine = 159,
-    OneHundredSixtyOne = 161,
-    OneHundredSixtyFive = 165,
-    OneHundredSixtyNine = 169,
-    OneHundredSeventyThree = 173,
-    OneHundredSeventySeven = 177,
-    OneHundredEightyOne = 181,
-}
-
-impl From<&[Ie]> for ChannelNumber {
-    fn from(ies: &[Ie]) -> Self {
-        let channel_number = ies
-            .iter()
-            .find_map(|ie| match ie {
-                Ie::DsParameterSet(ds_parameter_set) => Some(ds_parameter_set.channel_number()),
-                _ => None,
-            })
-            .unwrap_or(1);
-
-        ChannelNumber::try_from(channel_number).unwrap()
-    }
-}
+/// A channel number, which is only unique within a band.
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Hash, Deref, From)]
+pub struct ChannelNumber(u8);
 
 impl Display for ChannelNumber {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", *self as u8)
+        write!(f, "{}", self.0)
     }
 }
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct Channel {
+    band: ChannelBand,
     number: ChannelNumber,
+    frequency_khz: u32,
     width: ChannelWidth,
 }
 
 impl Channel {
+    /// The channel centered on the frequency, like the one nl80211 reports for a BSS, with the
+    /// width from the elements. Returns None if the frequency isn't on a channel raster.
+    pub fn from_frequency_khz(frequency_khz: u32, ies: &[Ie]) -> Option<Channel> {
+        let band = ChannelBand::from_frequency_khz(frequency_khz)?;
+
+        Some(Channel {
+            band,
+            number: band.channel_number(frequency_khz)?,
+            frequency_khz,
+            width: ChannelWidth::from(ies),
+        })
+    }
+
+    pub fn from_frequency_mhz(frequency_mhz: u32, ies: &[Ie]) -> Option<Channel> {
+        Channel::from_frequency_khz(frequency_mhz * 1000, ies)
+    }
+
+    /// The primary channel from the DS Parameter Set element, or else the HT Operation element or
+    /// the HE Operation element's 6 GHz Operation Information for BSSs that don't send the DS
+    /// Parameter Set element. Returns None if none of them are there, or if the channel number
+    /// could be a 4.9 GHz channel, since the elements don't say which band it's in.
+    pub fn from_ies(ies: &[Ie]) -> Option<Channel> {
+        let (band, number) = ies
+            .iter()
+            .find_map(|ie| match ie {
+                Ie::HeOperation(he_operation) => he_operation.six_ghz_operation_information().map(
+                    |six_ghz_operation_information| {
+                        (
+                            ChannelBand::SixGhz,
+                            six_ghz_operation_information.primary_channel(),
+                        )
+                    },
+                ),
+                _ => None,
+            })
+            .or_else(|| {
+                ies.iter()
+                    .find_map(|ie| match ie {
+                        Ie::DsParameterSet(ds_parameter_set) => {
+                            Some(ds_parameter_set.channel_number())
+                        }
+                        _ => None,
+                    })
+                    .or_else(|| {
+                        ies.iter().find_map(|ie| match ie {
+                            Ie::HtOperation(ht_operation) => Some(ht_operation.primary_channel()),
+                            _ => None,
+                        })
+                    })
+                    .and_then(|number| match number {
+                        1..=14 => Some((ChannelBand::TwoPointFourGhz, number)),
+                        // 4.9 GHz channel numbers overlap the top of the 5 GHz band.
+                        182..=196 => None,
+                        _ => Some((ChannelBand::FiveGhz, number)),
+                    })
+            })?;
+        let number = ChannelNumber(number);
+
+        Some(Channel {
+            band,
+            number,
+            frequency_khz: band.channel_frequency_khz(number)?,
+            width: ChannelWidth::from(ies),
+        })
+    }
+
     pub fn number(&self) -> ChannelNumber {
         self.number
     }
 
+    /// The center frequency of the primary 20 MHz channel, rounded down for S1G channels.
     pub fn center_freq_mhz(&self) -> u32 {
-        if self.number == ChannelNumber::Fourteen {
-            return 2484;
-        }
+        self.frequency_khz / 1000
+    }
 
-        match self.band() {
-            ChannelBand::TwoPointFourGhz => 2407 + (self.number as u32 * 5),
-            ChannelBand::FiveGhz => 5000 + (self.number as u32 * 5),
-            ChannelBand::SixGhz => 5935 + (self.number as u32 * 5),
-        }
+    pub fn center_freq_khz(&self) -> u32 {
+        self.frequency_khz
     }
 
     pub fn band(&self) -> ChannelBand {
-        match self.number as u8 {
-            1..=14 => ChannelBand::TwoPointFourGhz,
-            _ => ChannelBand::FiveGhz,
-        }
+        self.band
     }
 
+    /// The width from the HT, VHT, HE and EHT Operation elements, which only describe 2.4, 5 and
+    /// 6 GHz channels.
     pub fn width(&self) -> ChannelWidth {
         self.width
     }
 }
 
-impl From<&[Ie]> for Channel {
-    fn from(ies: &[Ie]) -> Self {
-        Channel {
-            number: ChannelNumber::from(ies),
-            width: ChannelWidth::from(ies),
-        }
-    }
-}
-
-impl From<&Vec<Ie>> for Channel {
-    fn from(ies: &Vec<Ie>) -> Self {
-        Channel::from(ies.as_slice())
-    }
-}
-
 impl Display for Channel {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Channel {}", self.number as u32)
+        write!(f, "Channel {}", self.number)
     }
 }
 
@@ -175,19 +212,83 @@ mod tests {
     use super::*;
 
     #[test]
-    fn channel_from_channel_number() {
-        assert_eq!(ChannelNumber::try_from(1).unwrap(), ChannelNumber::One);
-        assert_eq!(ChannelNumber::try_from(6).unwrap(), ChannelNumber::Six);
-        assert_eq!(ChannelNumber::try_from(11).unwrap(), ChannelNumber::Eleven);
+    fn channel_from_frequency() {
+        let channel = Channel::from_frequency_mhz(2437, &[]).unwrap();
+        assert_eq!(channel.band(), ChannelBand::TwoPointFourGhz);
+        assert_eq!(*channel.number(), 6);
+
+        let channel = Channel::from_frequency_mhz(2484, &[]).unwrap();
+        assert_eq!(*channel.number(), 14);
+
+        let channel = Channel::from_frequency_mhz(4940, &[]).unwrap();
+        assert_eq!(channel.band(), ChannelBand::FourPointNineGhz);
+        assert_eq!(*channel.number(), 188);
+
+        let channel = Channel::from_frequency_mhz(5180, &[]).unwrap();
+        assert_eq!(channel.band(), ChannelBand::FiveGhz);
+        assert_eq!(*channel.number(), 36);
+
+        let channel = Channel::from_frequency_mhz(5935, &[]).unwrap();
+        assert_eq!(channel.band(), ChannelBand::SixGhz);
+        assert_eq!(*channel.number(), 2);
+
+        let channel = Channel::from_frequency_mhz(6115, &[]).unwrap();
+        assert_eq!(channel.band(), ChannelBand::SixGhz);
+        assert_eq!(*channel.number(), 33);
+
+        let channel = Channel::from_frequency_mhz(60_480, &[]).unwrap();
+        assert_eq!(channel.band(), ChannelBand::SixtyGhz);
+        assert_eq!(*channel.number(), 2);
+
+        let channel = Channel::from_frequency_khz(903_500, &[]).unwrap();
+        assert_eq!(channel.band(), ChannelBand::NineHundredMhz);
+        assert_eq!(*channel.number(), 3);
+    }
+
+    #[test]
+    fn channel_from_unknown_frequency() {
+        assert_eq!(Channel::from_frequency_mhz(3660, &[]), None);
+        assert_eq!(Channel::from_frequency_mhz(2413, &[]), None);
+        assert_eq!(Channel::from_ies(&[]), None);
+    }
+
+    #[test]
+    fn channel_band_frequencies() {
+        assert_eq!(
+            ChannelBand::TwoPointFourGhz.channel_frequency_khz(ChannelNumber(14)),
+            Some(2_484_000)
+        );
+        assert_eq!(
+            ChannelBand::SixGhz.channel_frequency_khz(ChannelNumber(2)),
+            Some(5_935_000)
+        );
+        assert_eq!(
+            ChannelBand::SixGhz.channel_number(6_115_000),
+            Some(ChannelNumber(33))
+        );
+        assert_eq!(
+            ChannelBand::FiveGhz.channel_frequency_khz(ChannelNumber(184)),
+            None
+        );
         assert_eq!(
-            ChannelNumber::try_from(14).unwrap(),
-            ChannelNumber::Fourteen
+            ChannelBand::FourPointNineGhz.channel_frequency_khz(ChannelNumber(184)),
+            Some(4_920_000)
         );
     }
 
     #[test]
-    #[should_panic]
-    fn invalid_channel_number() {
-        ChannelNumber::try_from(15).unwrap();
+    fn channel_from_ds_parameter_set() {
+        let channel = Channel::from_ies(&crate::ies::from_bytes(&[3, 1, 6]).unwrap()).unwrap();
+        assert_eq!(channel.band(), ChannelBand::TwoPointFourGhz);
+        assert_eq!(channel.center_freq_mhz(), 2437);
+
+        let channel = Channel::from_ies(&crate::ies::from_bytes(&[3, 1, 165]).unwrap()).unwrap();
+        assert_eq!(channel.band(), ChannelBand::FiveGhz);
+        assert_eq!(channel.center_freq_mhz(), 5825);
+
+        assert_eq!(
+            Channel::from_ies(&crate::ies::from_bytes(&[3, 1, 184]).unwrap()),
+            None
+        );
     }
 }
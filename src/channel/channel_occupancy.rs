@@ -0,0 +1,516 @@
+use crate::{
+    ies::{Ie, SecondaryChannelOffset},
+    Channel, ChannelBand, ChannelNumber, ChannelWidth,
+};
+use std::fmt::Display;
+
+/// A contiguous frequency segment of a channel, which is the whole channel unless it's 80+80 MHz.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChannelSegment {
+    center_freq_mhz: u32,
+    width_mhz: u32,
+}
+
+impl ChannelSegment {
+    pub fn center_freq_mhz(&self) -> u32 {
+        self.center_freq_mhz
+    }
+
+    pub fn width_mhz(&self) -> u32 {
+        self.width_mhz
+    }
+
+    pub fn low_freq_mhz(&self) -> u32 {
+        self.center_freq_mhz - self.width_mhz / 2
+    }
+
+    pub fn high_freq_mhz(&self) -> u32 {
+        self.center_freq_mhz + self.width_mhz / 2
+    }
+
+    fn contains(&self, freq_mhz: u32) -> bool {
+        self.low_freq_mhz() < freq_mhz && freq_mhz < self.high_freq_mhz()
+    }
+
+    // The center of the sibling block of the given width in the segment, like the secondary 40 MHz
+    // channel next to the 40 MHz block with the primary channel
+    fn sibling_center_freq_mhz(&self, freq_mhz: u32, block_width_mhz: u32) -> Option<u32> {
+        if self.width_mhz < block_width_mhz * 2 || !self.contains(freq_mhz) {
+            return None;
+        }
+
+        let block = (freq_mhz - self.low_freq_mhz()) / block_width_mhz;
+        Some(self.low_freq_mhz() + (block ^ 1) * block_width_mhz + block_width_mhz / 2)
+    }
+}
+
+/// How two channels relate to each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChannelRelation {
+    /// The channels share their primary 20 MHz channel, so the BSSs contend with each other.
+    CoChannel,
+    /// The channels overlap but have different primary channels.
+    Overlapping,
+    /// The channels don't overlap but are less than 20 MHz apart.
+    Adjacent,
+    Separate,
+}
+
+impl Display for ChannelRelation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChannelRelation::CoChannel => write!(f, "Co-Channel"),
+            ChannelRelation::Overlapping => write!(f, "Overlapping"),
+            ChannelRelation::Adjacent => write!(f, "Adjacent"),
+            ChannelRelation::Separate => write!(f, "Separate"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChannelOverlap {
+    relation: ChannelRelation,
+    overlap_mhz: u32,
+    separation_mhz: u32,
+}
+
+impl ChannelOverlap {
+    pub fn relation(&self) -> ChannelRelation {
+        self.relation
+    }
+
+    /// How much of the occupied spectrum the channels share.
+    pub fn overlap_mhz(&self) -> u32 {
+        self.overlap_mhz
+    }
+
+    /// The gap between the nearest edges of the channels, which is 0 if they overlap or touch.
+    pub fn separation_mhz(&self) -> u32 {
+        self.separation_mhz
+    }
+}
+
+/// The spectrum a BSS occupies, from its primary channel and the channel center frequency segments
+/// in its operation elements.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChannelOccupancy {
+    band: ChannelBand,
+    primary_freq_mhz: u32,
+    width: ChannelWidth,
+    segments: Vec<ChannelSegment>,
+    subchannel_width_mhz: u32,
+    punctured_freqs_mhz: Vec<u32>,
+}
+
+impl ChannelOccupancy {
+    /// Works out the occupied spectrum of the channel, falling back to the primary 20 MHz channel if
+    /// the elements don't locate the rest of it. Returns None for S1G channels, whose widths the
+    /// elements don't describe.
+    pub fn new(channel: &Channel, ies: &[Ie]) -> Option<ChannelOccupancy> {
+        let band = channel.band();
+        let primary_freq_mhz = channel.center_freq_mhz();
+
+        let (width, segments, subchannel_width_mhz) = match band {
+            ChannelBand::NineHundredMhz => return None,
+            ChannelBand::SixtyGhz => (
+                channel.width(),
+                vec![ChannelSegment {
+                    center_freq_mhz: primary_freq_mhz,
+                    width_mhz: 2160,
+                }],
+                2160,
+            ),
+            _ => match segments(channel, ies) {
+                Some(segments) => (channel.width(), segments, 20),
+                None => (
+                    ChannelWidth::TwentyMhz,
+                    vec![ChannelSegment {
+                        center_freq_mhz: primary_freq_mhz,
+                        width_mhz: 20,
+                    }],
+                    20,
+                ),
+            },
+        };
+
+        let punctured_freqs_mhz = ies
+            .iter()
+            .find_map(|ie| match ie {
+                Ie::EhtOperation(eht_operation) => eht_operation.eht_operation_information(),
+                _ => None,
+            })
+            .map(|eht_operation_information| {
+                eht_operation_information
+                    .disabled_subchannels()
+                    .into_iter()
+                    .filter_map(|subchannel| channel_freq_mhz(band, subchannel))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Some(ChannelOccupancy {
+            band,
+            primary_freq_mhz,
+            width,
+            segments,
+            subchannel_width_mhz,
+            punctured_freqs_mhz,
+        })
+    }
+
+    // A contiguous channel that no BSS announced, like a candidate channel for a new BSS
+    pub(crate) fn contiguous(
+        band: ChannelBand,
+        primary_freq_mhz: u32,
+        width: ChannelWidth,
+        center_freq_mhz: u32,
+        width_mhz: u32,
+    ) -> ChannelOccupancy {
+        ChannelOccupancy {
+            band,
+            primary_freq_mhz,
+            width,
+            segments: vec![ChannelSegment {
+                center_freq_mhz,
+                width_mhz,
+            }],
+            subchannel_width_mhz: 20,
+            punctured_freqs_mhz: Vec::new(),
+        }
+    }
+
+    pub fn band(&self) -> ChannelBand {
+        self.band
+    }
+
+    pub fn primary_freq_mhz(&self) -> u32 {
+        self.primary_freq_mhz
+    }
+
+    /// The channel width, which is 20 MHz if the elements don't locate the rest of the channel.
+    /// It only applies to 2.4, 5 and 6 GHz channels; a 60 GHz channel occupies its whole 2160 MHz
+    /// segment whatever the width says.
+    pub fn width(&self) -> ChannelWidth {
+        self.width
+    }
+
+    pub fn segments(&self) -> &[ChannelSegment] {
+        &self.segments
+    }
+
+    /// The lowest and highest frequencies of the channel, including any gap between 80+80 MHz
+    /// segments.
+    pub fn span_mhz(&self) -> (u32, u32) {
+        let low_freq_mhz = self
+            .segments
+            .iter()
+            .map(|segment| segment.low_freq_mhz())
+            .min()
+            .unwrap_or(self.primary_freq_mhz);
+        let high_freq_mhz = self
+            .segments
+            .iter()
+            .map(|segment| segment.high_freq_mhz())
+            .max()
+            .unwrap_or(self.primary_freq_mhz);
+
+        (low_freq_mhz, high_freq_mhz)
+    }
+
+    fn primary_segment(&self) -> Option<&ChannelSegment> {
+        self.segments
+            .iter()
+            .find(|segment| segment.contains(self.primary_freq_mhz))
+    }
+
+    pub fn secondary_twenty_mhz_freq_mhz(&self) -> Option<u32> {
+        self.primary_segment()?
+            .sibling_center_freq_mhz(self.primary_freq_mhz, 20)
+    }
+
+    pub fn secondary_forty_mhz_center_freq_mhz(&self) -> Option<u32> {
+        self.primary_segment()?
+            .sibling_center_freq_mhz(self.primary_freq_mhz, 40)
+    }
+
+    /// The center of the secondary 80 MHz channel, which is the other segment of an 80+80 MHz
+    /// channel.
+    pub fn secondary_eighty_mhz_center_freq_mhz(&self) -> Option<u32> {
+        if self.width == ChannelWidth::EightyPlusEightyMhz {
+            return self
+                .segments
+                .iter()
+                .find(|segment| !segment.contains(self.primary_freq_mhz))
+                .map(|segment| segment.center_freq_mhz);
+        }
+
+        self.primary_segment()?
+            .sibling_center_freq_mhz(self.primary_freq_mhz, 80)
+    }
+
+    pub fn secondary_one_sixty_mhz_center_freq_mhz(&self) -> Option<u32> {
+        self.primary_segment()?
+            .sibling_center_freq_mhz(self.primary_freq_mhz, 160)
+    }
+
+    /// The center frequencies of the punctured 20 MHz subchannels.
+    pub fn punctured_freqs_mhz(&self) -> &[u32] {
+        &self.punctured_freqs_mhz
+    }
+
+    /// The center frequencies of the 20 MHz subchannels the BSS transmits on, leaving out the
+    /// punctured ones. A 60 GHz channel is one 2160 MHz subchannel.
+    pub fn subchannel_freqs_mhz(&self) -> Vec<u32> {
+        self.segments
+            .iter()
+            .flat_map(|segment| {
+                (0..segment.width_mhz / self.subchannel_width_mhz).map(move |index| {
+                    segment.low_freq_mhz()
+                        + index * self.subchannel_width_mhz
+                        + self.subchannel_width_mhz / 2
+                })
+            })
+            .filter(|freq_mhz| !self.punctured_freqs_mhz.contains(freq_mhz))
+            .collect()
+    }
+
+    pub fn subchannel_numbers(&self) -> Vec<ChannelNumber> {
+        self.subchannel_freqs_mhz()
+            .into_iter()
+            .filter_map(|freq_mhz| self.band.channel_number(freq_mhz * 1000))
+            .collect()
+    }
+
+    // The (low, high) frequencies of the subchannels the BSS transmits on
+    fn occupied_ranges_mhz(&self) -> Vec<(u32, u32)> {
+        self.subchannel_freqs_mhz()
+            .into_iter()
+            .map(|freq_mhz| {
+                (
+                    freq_mhz - self.subchannel_width_mhz / 2,
+                    freq_mhz + self.subchannel_width_mhz / 2,
+                )
+            })
+            .collect()
+    }
+
+    /// Compares the occupied spectrum of the two channels.
+    pub fn overlap(&self, other: &ChannelOccupancy) -> ChannelOverlap {
+        let ranges = self.occupied_ranges_mhz();
+        let other_ranges = other.occupied_ranges_mhz();
+
+        let mut overlap_mhz = 0;
+        let mut separation_mhz = u32::MAX;
+        for (low, high) in ranges.iter() {
+            for (other_low, other_high) in other_ranges.iter() {
+                overlap_mhz += high.min(other_high).saturating_sub(*low.max(other_low));
+                // One of the two gaps is always 0, and both are for overlapping ranges
+                separation_mhz = separation_mhz.min(
+                    other_low
+                        .saturating_sub(*high)
+                        .max(low.saturating_sub(*other_high)),
+                );
+            }
+        }
+
+        let relation = if self.primary_freq_mhz == other.primary_freq_mhz {
+            ChannelRelation::CoChannel
+        } else if overlap_mhz > 0 {
+            ChannelRelation::Overlapping
+        } else if separation_mhz < 20 {
+            ChannelRelation::Adjacent
+        } else {
+            ChannelRelation::Separate
+        };
+
+        ChannelOverlap {
+            relation,
+            overlap_mhz,
+            separation_mhz,
+        }
+    }
+}
+
+fn channel_freq_mhz(band: ChannelBand, channel: u8) -> Option<u32> {
+    band.channel_frequency_khz(ChannelNumber::from(channel))
+        .map(|freq_khz| freq_khz / 1000)
+}
+
+// The segments of the channel, from the channel center frequency segments of the EHT Operation
+// element, the HE Operation element's 6 GHz Operation Information, the VHT Operation element or the
+// HE Operation element's VHT Operation Information, in that order
+fn segments(channel: &Channel, ies: &[Ie]) -> Option<Vec<ChannelSegment>> {
+    let band = channel.band();
+    let primary_freq_mhz = channel.center_freq_mhz();
+
+    let channel_center_freq_segments = ies
+        .iter()
+        .find_map(|ie| match ie {
+            Ie::EhtOperation(eht_operation) => {
+                eht_operation
+                    .eht_operation_information()
+                    .map(|eht_operation_information| {
+                        (
+                            eht_operation_information.channel_center_freq_segment_zero(),
+                            eht_operation_information.channel_center_freq_segment_one(),
+                        )
+                    })
+            }
+            _ => None,
+        })
+        .or_else(|| {
+            ies.iter().find_map(|ie| match ie {
+                Ie::HeOperation(he_operation) => he_operation.six_ghz_operation_information().map(
+                    |six_ghz_operation_information| {
+                        (
+                            six_ghz_operation_information.channel_center_freq_segment_zero(),
+                            six_ghz_operation_information.channel_center_freq_segment_one(),
+                        )
+                    },
+                ),
+                _ => None,
+            })
+        })
+        .or_else(|| {
+            ies.iter().find_map(|ie| match ie {
+                Ie::VhtOperation(vht_operation) => Some((
+                    vht_operation.channel_center_freq_segment_zero(),
+                    vht_operation.channel_center_freq_segment_one(),
+                )),
+                Ie::HeOperation(he_operation) => {
+                    he_operation
+                        .vht_operation_information()
+                        .map(|vht_operation_information| {
+                            (
+                                vht_operation_information.channel_center_freq_segment_zero(),
+                                vht_operation_information.channel_center_freq_segment_one(),
+                            )
+                        })
+                }
+                _ => None,
+            })
+        });
+    let segment_zero = channel_center_freq_segments
+        .and_then(|(segment_zero, _)| channel_freq_mhz(band, segment_zero));
+    let segment_one = channel_center_freq_segments
+        .and_then(|(_, segment_one)| channel_freq_mhz(band, segment_one));
+
+    let segment = |center_freq_mhz, width_mhz| ChannelSegment {
+        center_freq_mhz,
+        width_mhz,
+    };
+
+    let segments = match channel.width() {
+        ChannelWidth::TwentyMhz | ChannelWidth::TwentyTwoMhz => {
+            vec![segment(primary_freq_mhz, 20)]
+        }
+        ChannelWidth::FortyMhz => {
+            let secondary_channel_offset = ies.iter().find_map(|ie| match ie {
+                Ie::HtOperation(ht_operation) => Some(ht_operation.secondary_channel_offset()),
+                _ => None,
+            });
+            let center_freq_mhz = match secondary_channel_offset {
+                Some(SecondaryChannelOffset::Above) => primary_freq_mhz + 10,
+                Some(SecondaryChannelOffset::Below) => primary_freq_mhz - 10,
+                _ => segment_zero?,
+            };
+            vec![segment(center_freq_mhz, 40)]
+        }
+        ChannelWidth::EightyMhz => vec![segment(segment_zero?, 80)],
+        // The deprecated VHT encoding puts the center of the 160 MHz channel in segment 0
+        ChannelWidth::OneSixtyMhz => vec![segment(segment_one.or(segment_zero)?, 160)],
+        ChannelWidth::EightyPlusEightyMhz => {
+            vec![segment(segment_zero?, 80), segment(segment_one?, 80)]
+        }
+        ChannelWidth::ThreeTwentyMhz => vec![segment(segment_one?, 320)],
+    };
+
+    if segments
+        .iter()
+        .any(|segment| segment.contains(primary_freq_mhz))
+    {
+        Some(segments)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ies::{HtOperation, VhtOperation};
+
+    #[test]
+    fn eighty_mhz_occupancy_and_overlap() {
+        // Primary channel 40 in the 80 MHz channel 42
+        let ies = vec![
+            Ie::HtOperation(
+                HtOperation::new(vec![
+                    0x28, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                ])
+                .unwrap(),
+            ),
+            Ie::VhtOperation(VhtOperation::new(vec![0x01, 0x2A, 0x00, 0xFC, 0xFF]).unwrap()),
+        ];
+        let channel = Channel::from_frequency_mhz(5200, &ies).unwrap();
+        let occupancy = ChannelOccupancy::new(&channel, &ies).unwrap();
+
+        assert_eq!(occupancy.width(), ChannelWidth::EightyMhz);
+        assert_eq!(occupancy.span_mhz(), (5170, 5250));
+        assert_eq!(occupancy.secondary_twenty_mhz_freq_mhz(), Some(5180));
+        assert_eq!(occupancy.secondary_forty_mhz_center_freq_mhz(), Some(5230));
+        assert_eq!(occupancy.secondary_eighty_mhz_center_freq_mhz(), None);
+        assert_eq!(
+            occupancy.subchannel_numbers(),
+            vec![
+                ChannelNumber::from(36),
+                ChannelNumber::from(40),
+                ChannelNumber::from(44),
+                ChannelNumber::from(48)
+            ]
+        );
+
+        let twenty_mhz_channel = Channel::from_frequency_mhz(5240, &[]).unwrap();
+        let twenty_mhz_occupancy = ChannelOccupancy::new(&twenty_mhz_channel, &[]).unwrap();
+        let overlap = occupancy.overlap(&twenty_mhz_occupancy);
+        assert_eq!(overlap.relation(), ChannelRelation::Overlapping);
+        assert_eq!(overlap.overlap_mhz(), 20);
+
+        let adjacent_channel = Channel::from_frequency_mhz(5260, &[]).unwrap();
+        let adjacent_occupancy = ChannelOccupancy::new(&adjacent_channel, &[]).unwrap();
+        let overlap = occupancy.overlap(&adjacent_occupancy);
+        assert_eq!(overlap.relation(), ChannelRelation::Adjacent);
+        assert_eq!(overlap.separation_mhz(), 0);
+
+        // The same primary channel with only the HT Operation element is HT40 below
+        let ht_forty_ies = vec![ies[0].clone()];
+        let ht_forty_channel = Channel::from_frequency_mhz(5200, &ht_forty_ies).unwrap();
+        let ht_forty_occupancy = ChannelOccupancy::new(&ht_forty_channel, &ht_forty_ies).unwrap();
+
+        assert_eq!(ht_forty_occupancy.width(), ChannelWidth::FortyMhz);
+        assert_eq!(ht_forty_occupancy.span_mhz(), (5170, 5210));
+        assert_eq!(
+            ht_forty_occupancy.secondary_twenty_mhz_freq_mhz(),
+            Some(5180)
+        );
+        assert_eq!(
+            ht_forty_occupancy.secondary_forty_mhz_center_freq_mhz(),
+            None
+        );
+        assert_eq!(
+            ht_forty_occupancy.subchannel_numbers(),
+            vec![ChannelNumber::from(36), ChannelNumber::from(40)]
+        );
+
+        let overlap = occupancy.overlap(&ht_forty_occupancy);
+        assert_eq!(overlap.relation(), ChannelRelation::CoChannel);
+        assert_eq!(overlap.overlap_mhz(), 40);
+
+        // 5170-5210 MHz and 5230-5250 MHz are 20 MHz apart
+        let overlap = ht_forty_occupancy.overlap(&twenty_mhz_occupancy);
+        assert_eq!(overlap.relation(), ChannelRelation::Separate);
+        assert_eq!(overlap.overlap_mhz(), 0);
+        assert_eq!(overlap.separation_mhz(), 20);
+    }
+}
@@ -0,0 +1,118 @@
+#[cfg(target_os = "linux")]
+use crate::interface::Nl80211SurveyInfo;
+#[cfg(target_os = "linux")]
+use neli::{attr::Attribute, genl::Nlattr, types::Buffer};
+#[cfg(target_os = "linux")]
+use std::{collections::HashMap, convert::TryFrom};
+
+/// How busy a channel was while the radio listened on it, like the survey results nl80211
+/// reports for each channel the radio visited.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChannelSurvey {
+    frequency_mhz: u32,
+    noise_dbm: Option<i8>,
+    in_use: bool,
+    active_time_ms: Option<u64>,
+    busy_time_ms: Option<u64>,
+    extension_channel_busy_time_ms: Option<u64>,
+    rx_time_ms: Option<u64>,
+    tx_time_ms: Option<u64>,
+}
+
+impl ChannelSurvey {
+    /// A survey of the 20 MHz channel at the frequency that found the channel busy for
+    /// `busy_time_ms` of the `active_time_ms` the radio spent on it.
+    pub fn new(frequency_mhz: u32, active_time_ms: u64, busy_time_ms: u64) -> ChannelSurvey {
+        ChannelSurvey {
+            frequency_mhz,
+            noise_dbm: None,
+            in_use: false,
+            active_time_ms: Some(active_time_ms),
+            busy_time_ms: Some(busy_time_ms),
+            extension_channel_busy_time_ms: None,
+            rx_time_ms: None,
+            tx_time_ms: None,
+        }
+    }
+
+    pub fn frequency_mhz(&self) -> u32 {
+        self.frequency_mhz
+    }
+
+    pub fn noise_dbm(&self) -> Option<i8> {
+        self.noise_dbm
+    }
+
+    /// Whether the radio is operating on the channel.
+    pub fn in_use(&self) -> bool {
+        self.in_use
+    }
+
+    /// How long the radio was on the channel.
+    pub fn active_time_ms(&self) -> Option<u64> {
+        self.active_time_ms
+    }
+
+    /// How long the radio sensed the channel as busy.
+    pub fn busy_time_ms(&self) -> Option<u64> {
+        self.busy_time_ms
+    }
+
+    /// How long the radio sensed the secondary channel as busy.
+    pub fn extension_channel_busy_time_ms(&self) -> Option<u64> {
+        self.extension_channel_busy_time_ms
+    }
+
+    pub fn rx_time_ms(&self) -> Option<u64> {
+        self.rx_time_ms
+    }
+
+    pub fn tx_time_ms(&self) -> Option<u64> {
+        self.tx_time_ms
+    }
+
+    /// The fraction of the active time the channel was busy, or None if the radio didn't measure
+    /// either of them.
+    pub fn busy_ratio(&self) -> Option<f64> {
+        match (self.active_time_ms?, self.busy_time_ms?) {
+            (0, _) => None,
+            (active_time_ms, busy_time_ms) => {
+                Some((busy_time_ms as f64 / active_time_ms as f64).min(1.0))
+            }
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl TryFrom<&[Nlattr<Nl80211SurveyInfo, Buffer>]> for ChannelSurvey {
+    type Error = ();
+
+    fn try_from(survey_attrs: &[Nlattr<Nl80211SurveyInfo, Buffer>]) -> Result<Self, Self::Error> {
+        let survey_attrs: HashMap<_, _> = survey_attrs
+            .iter()
+            .map(|attr| (attr.nla_type, attr))
+            .collect();
+        let time_ms = |nla_type| {
+            survey_attrs
+                .get(&nla_type)
+                .and_then(|attr| attr.get_payload_as::<u64>().ok())
+        };
+
+        Ok(ChannelSurvey {
+            frequency_mhz: survey_attrs
+                .get(&Nl80211SurveyInfo::Frequency)
+                .and_then(|attr| attr.get_payload_as().ok())
+                .ok_or(())?,
+            noise_dbm: survey_attrs
+                .get(&Nl80211SurveyInfo::Noise)
+                .and_then(|attr| attr.get_payload_as::<u8>().ok())
+                .map(|noise| noise as i8),
+            in_use: survey_attrs.contains_key(&Nl80211SurveyInfo::InUse),
+            active_time_ms: time_ms(Nl80211SurveyInfo::Time),
+            busy_time_ms: time_ms(Nl80211SurveyInfo::TimeBusy),
+            extension_channel_busy_time_ms: time_ms(Nl80211SurveyInfo::TimeExtBusy),
+            rx_time_ms: time_ms(Nl80211SurveyInfo::TimeRx),
+            tx_time_ms: time_ms(Nl80211SurveyInfo::TimeTx),
+        })
+    }
+}
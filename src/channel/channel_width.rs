@@ -15,11 +15,34 @@ pub enum ChannelWidth {
     EightyMhz = 1 << 3,
     EightyPlusEightyMhz = 1 << 4,
     OneSixtyMhz = 1 << 5,
+    ThreeTwentyMhz = 1 << 6,
 }
 
 impl From<&[Ie]> for ChannelWidth {
     // From Table 11-24 in IEEE Std 802.11-2016
     fn from(ies: &[Ie]) -> Self {
+        // EHT BSSs and 6 GHz HE BSSs state their width outright, and it's the only way to tell a
+        // BSS is 320 MHz wide
+        let eht_channel_width = ies.iter().find_map(|ie| match ie {
+            Ie::EhtOperation(eht_operation) => eht_operation
+                .eht_operation_information()
+                .map(|eht_operation_information| eht_operation_information.channel_width()),
+            _ => None,
+        });
+        if let Some(eht_channel_width) = eht_channel_width {
+            return eht_channel_width;
+        }
+
+        let six_ghz_channel_width = ies.iter().find_map(|ie| match ie {
+            Ie::HeOperation(he_operation) => he_operation
+                .six_ghz_operation_information()
+                .map(|six_ghz_operation_information| six_ghz_operation_information.channel_width()),
+            _ => None,
+        });
+        if let Some(six_ghz_channel_width) = six_ghz_channel_width {
+            return six_ghz_channel_width;
+        }
+
         let ht_channel_widths = ies
             .iter()
             .find_map(|ie| match ie {
@@ -28,22 +51,47 @@ impl From<&[Ie]> for ChannelWidth {
             })
             .unwrap_or(ChannelWidth::TwentyMhz.into());
 
-        let (vht_channel_widths, channel_center_segment_zero, channel_center_segment_one) = ies
-            .iter()
-            .find_map(|ie| match ie {
-                Ie::VhtOperation(vht_operation) => Some((
-                    vht_operation.channel_width(),
-                    vht_operation.channel_center_freq_segment_zero(),
-                    vht_operation.channel_center_freq_segment_one(),
-                )),
-                _ => None,
-            })
-            .unwrap_or((ChannelWidth::TwentyMhz.into(), 0, 0));
+        // An HE AP in the 5 GHz band can carry the VHT Operation Information in its HE Operation
+        // element instead of including a VHT Operation element
+        let vht_operation =
+            ies.iter()
+                .find_map(|ie| match ie {
+                    Ie::VhtOperation(vht_operation) => Some((
+                        vht_operation.channel_width(),
+                        vht_operation.channel_center_freq_segment_zero(),
+                        vht_operation.channel_center_freq_segment_one(),
+                    )),
+                    _ => None,
+                })
+                .or_else(|| {
+                    ies.iter().find_map(|ie| match ie {
+                        Ie::HeOperation(he_operation) => he_operation
+                            .vht_operation_information()
+                            .map(|vht_operation_information| {
+                                (
+                                    vht_operation_information.channel_width(),
+                                    vht_operation_information.channel_center_freq_segment_zero(),
+                                    vht_operation_information.channel_center_freq_segment_one(),
+                                )
+                            }),
+                        _ => None,
+                    })
+                });
 
         if ht_channel_widths == ChannelWidths::from(ChannelWidth::TwentyMhz) {
             return ChannelWidth::TwentyMhz;
         }
 
+        // An HT BSS that allows 40 MHz without any VHT Operation Information is 40 MHz wide
+        let (vht_channel_widths, channel_center_segment_zero, channel_center_segment_one) =
+            match vht_operation {
+                Some(vht_operation) => vht_operation,
+                None => return ChannelWidth::FortyMhz,
+            };
+        let channel_center_segment_distance = channel_center_segment_one
+            .max(channel_center_segment_zero)
+            - channel_center_segment_one.min(channel_center_segment_zero);
+
         if vht_channel_widths == ChannelWidth::TwentyMhz | ChannelWidth::FortyMhz {
             return ChannelWidth::FortyMhz;
         }
@@ -62,7 +110,7 @@ impl From<&[Ie]> for ChannelWidth {
                 | ChannelWidth::EightyPlusEightyMhz
                 | ChannelWidth::OneSixtyMhz
             && channel_center_segment_one > 0
-            && (channel_center_segment_one - channel_center_segment_zero) == 8
+            && channel_center_segment_distance == 8
         {
             return ChannelWidth::OneSixtyMhz;
         }
@@ -72,7 +120,7 @@ impl From<&[Ie]> for ChannelWidth {
                 | ChannelWidth::EightyPlusEightyMhz
                 | ChannelWidth::OneSixtyMhz
             && channel_center_segment_one > 0
-            && (channel_center_segment_one - channel_center_segment_zero) > 16
+            && channel_center_segment_distance > 16
         {
             return ChannelWidth::EightyPlusEightyMhz;
         }
@@ -98,6 +146,7 @@ impl Display for ChannelWidth {
             ChannelWidth::EightyMhz => write!(f, "80 MHz"),
             ChannelWidth::EightyPlusEightyMhz => write!(f, "80+80 MHz"),
             ChannelWidth::OneSixtyMhz => write!(f, "160 MHz"),
+            ChannelWidth::ThreeTwentyMhz => write!(f, "320 MHz"),
         }
     }
 }
@@ -139,6 +188,7 @@ impl Display for ChannelWidths {
             ChannelWidth::EightyMhz,
             ChannelWidth::EightyPlusEightyMhz,
             ChannelWidth::OneSixtyMhz,
+            ChannelWidth::ThreeTwentyMhz,
         ]
         .iter()
         {
@@ -1,5 +1,14 @@
+#[allow(clippy::module_inception)]
 mod channel;
+mod channel_occupancy;
+mod channel_survey;
 mod channel_width;
+mod operating_class;
 
 pub use channel::{Channel, ChannelBand, ChannelNumber};
+pub use channel_occupancy::{ChannelOccupancy, ChannelOverlap, ChannelRelation, ChannelSegment};
+pub use channel_survey::ChannelSurvey;
 pub use channel_width::{ChannelWidth, ChannelWidths};
+pub use operating_class::{
+    BehaviorLimit, BehaviorLimits, OperatingClass, Region, SecondaryChannelPosition,
+};
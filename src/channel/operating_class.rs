@@ -0,0 +1,587 @@
+use crate::{ChannelBand, ChannelWidth};
+use derive_more::{
+    BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Deref, DerefMut, From, Not,
+};
+use enumflags2::{bitflags, BitFlags};
+use std::{convert::TryFrom, fmt::Display};
+
+#[bitflags]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum BehaviorLimit {
+    PrimaryChannelLower = 1 << 0,
+    PrimaryChannelUpper = 1 << 1,
+    Dfs50100 = 1 << 2,
+    Nomadic = 1 << 3,
+    LicenseExempt = 1 << 4,
+    UseEirpForVhtTxPowEnv = 1 << 5,
+    EightyPlus = 1 << 6,
+}
+
+impl Display for BehaviorLimit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BehaviorLimit::PrimaryChannelLower => write!(f, "PrimaryChannelLowerBehavior"),
+            BehaviorLimit::PrimaryChannelUpper => write!(f, "PrimaryChannelUpperBehavior"),
+            BehaviorLimit::Dfs50100 => write!(f, "DFS_50_100_Behavior"),
+            BehaviorLimit::Nomadic => write!(f, "NomadicBehavior"),
+            BehaviorLimit::LicenseExempt => write!(f, "LicenseExemptBehavior"),
+            BehaviorLimit::UseEirpForVhtTxPowEnv => write!(f, "UseEirpForVHTTxPowEnv"),
+            BehaviorLimit::EightyPlus => write!(f, "80+"),
+        }
+    }
+}
+
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    Deref,
+    DerefMut,
+    BitAnd,
+    BitAndAssign,
+    BitOr,
+    BitOrAssign,
+    BitXor,
+    BitXorAssign,
+    From,
+    Not,
+)]
+#[from(forward)]
+pub struct BehaviorLimits(BitFlags<BehaviorLimit>);
+
+impl Display for BehaviorLimits {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let behavior_limits: Vec<String> = self
+            .0
+            .iter()
+            .map(|behavior_limit| behavior_limit.to_string())
+            .collect();
+        write!(f, "{}", behavior_limits.join(", "))
+    }
+}
+
+/// The regulatory domains that have their own operating class tables in IEEE 802.11 Annex E.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Region {
+    Global,
+    UnitedStates,
+    Europe,
+    Japan,
+    China,
+}
+
+impl Region {
+    /// The region whose operating class table applies to the country. Operating classes in a
+    /// Country element whose country string ends in 0x04 are global regardless of the country.
+    pub fn from_country_string(country_string: &str) -> Option<Region> {
+        if country_string.as_bytes().get(2) == Some(&0x04) {
+            return Some(Region::Global);
+        }
+
+        match country_string.get(0..2)? {
+            "US" | "CA" | "PR" | "GU" | "AS" | "VI" | "MP" | "UM" => Some(Region::UnitedStates),
+            "AT" | "BE" | "BG" | "CH" | "CY" | "CZ" | "DE" | "DK" | "EE" | "ES" | "FI" | "FR"
+            | "GB" | "GR" | "HR" | "HU" | "IE" | "IS" | "IT" | "LI" | "LT" | "LU" | "LV" | "MT"
+            | "NL" | "NO" | "PL" | "PT" | "RO" | "SE" | "SI" | "SK" => Some(Region::Europe),
+            "JP" => Some(Region::Japan),
+            "CN" => Some(Region::China),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Region {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Region::Global => write!(f, "Global"),
+            Region::UnitedStates => write!(f, "United States"),
+            Region::Europe => write!(f, "Europe"),
+            Region::Japan => write!(f, "Japan"),
+            Region::China => write!(f, "China"),
+        }
+    }
+}
+
+/// Where the secondary 20 MHz channel of a 40 MHz channel is relative to the primary channel.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SecondaryChannelPosition {
+    Above,
+    Below,
+}
+
+impl Display for SecondaryChannelPosition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SecondaryChannelPosition::Above => write!(f, "Above"),
+            SecondaryChannelPosition::Below => write!(f, "Below"),
+        }
+    }
+}
+
+/// An entry of one of the operating class tables of IEEE 802.11 Annex E: the global table (E-4)
+/// or the United States (E-1), Europe (E-2), Japan (E-3) or China (E-5) table. Regional classes
+/// are described by the global class they correspond to.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct OperatingClass {
+    region: Region,
+    class: u8,
+    global_class: u8,
+    starting_frequency_mhz: u32,
+    channel_spacing: ChannelWidth,
+    channels: &'static [u8],
+    behavior_limits: BehaviorLimits,
+}
+
+impl OperatingClass {
+    /// Looks up a global operating class. The 60 GHz (DMG) classes aren't included.
+    pub fn global(class: u8) -> Option<OperatingClass> {
+        use BehaviorLimit::*;
+        use ChannelWidth::*;
+
+        let (starting_frequency_mhz, channel_spacing, channels, behavior_limits): (
+            u32,
+            ChannelWidth,
+            &'static [u8],
+            BitFlags<BehaviorLimit>,
+        ) = match class {
+            81 => (
+                2407,
+                TwentyMhz,
+                &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13],
+                BitFlags::empty(),
+            ),
+            82 => (2414, TwentyMhz, &[14], BitFlags::empty()),
+            83 => (
+                2407,
+                FortyMhz,
+                &[1, 2, 3, 4, 5, 6, 7, 8, 9],
+                PrimaryChannelLower.into(),
+            ),
+            84 => (
+                2407,
+                FortyMhz,
+                &[5, 6, 7, 8, 9, 10, 11, 12, 13],
+                PrimaryChannelUpper.into(),
+            ),
+            115 => (5000, TwentyMhz, &[36, 40, 44, 48], BitFlags::empty()),
+            116 => (5000, FortyMhz, &[36, 44], PrimaryChannelLower.into()),
+            117 => (5000, FortyMhz, &[40, 48], PrimaryChannelUpper.into()),
+            118 => (5000, TwentyMhz, &[52, 56, 60, 64], Dfs50100.into()),
+            119 => (5000, FortyMhz, &[52, 60], PrimaryChannelLower | Dfs50100),
+            120 => (5000, FortyMhz, &[56, 64], PrimaryChannelUpper | Dfs50100),
+            121 => (
+                5000,
+                TwentyMhz,
+                &[100, 104, 108, 112, 116, 120, 124, 128, 132, 136, 140, 144],
+                Dfs50100.into(),
+            ),
+            122 => (
+                5000,
+                FortyMhz,
+                &[100, 108, 116, 124, 132, 140],
+                PrimaryChannelLower | Dfs50100,
+            ),
+            123 => (
+                5000,
+                FortyMhz,
+                &[104, 112, 120, 128, 136, 144],
+                PrimaryChannelUpper | Dfs50100,
+            ),
+            124 => (5000, TwentyMhz, &[149, 153, 157, 161], Nomadic.into()),
+            125 => (
+                5000,
+                TwentyMhz,
+                &[149, 153, 157, 161, 165, 169, 173, 177],
+                LicenseExempt.into(),
+            ),
+            126 => (
+                5000,
+                FortyMhz,
+                &[149, 157, 165, 173],
+                PrimaryChannelLower.into(),
+            ),
+            127 => (
+                5000,
+                FortyMhz,
+                &[153, 161, 169, 177],
+                PrimaryChannelUpper.into(),
+            ),
+            128 => (
+                5000,
+                EightyMhz,
+                &[42, 58, 106, 122, 138, 155, 171],
+                UseEirpForVhtTxPowEnv.into(),
+            ),
+            129 => (
+                5000,
+                OneSixtyMhz,
+                &[50, 114, 163],
+                UseEirpForVhtTxPowEnv.into(),
+            ),
+            130 => (
+                5000,
+                EightyPlusEightyMhz,
+                &[42, 58, 106, 122, 138, 155, 171],
+                UseEirpForVhtTxPowEnv | EightyPlus,
+            ),
+            131 => (5950, TwentyMhz, SIX_GHZ_20_MHZ_CHANNELS, BitFlags::empty()),
+            132 => (5950, FortyMhz, SIX_GHZ_40_MHZ_CHANNELS, BitFlags::empty()),
+            133 => (5950, EightyMhz, SIX_GHZ_80_MHZ_CHANNELS, BitFlags::empty()),
+            134 => (
+                5950,
+                OneSixtyMhz,
+                SIX_GHZ_160_MHZ_CHANNELS,
+                BitFlags::empty(),
+            ),
+            135 => (
+                5950,
+                EightyPlusEightyMhz,
+                SIX_GHZ_80_MHZ_CHANNELS,
+                EightyPlus.into(),
+            ),
+            136 => (5925, TwentyMhz, &[2], BitFlags::empty()),
+            137 => (
+                5950,
+                ThreeTwentyMhz,
+                SIX_GHZ_320_MHZ_CHANNELS,
+                BitFlags::empty(),
+            ),
+            _ => return None,
+        };
+
+        Some(OperatingClass {
+            region: Region::Global,
+            class,
+            global_class: class,
+            starting_frequency_mhz,
+            channel_spacing,
+            channels,
+            behavior_limits: BehaviorLimits(behavior_limits),
+        })
+    }
+
+    /// Looks up an operating class of a region's table.
+    pub fn regional(region: Region, class: u8) -> Option<OperatingClass> {
+        if region == Region::Global {
+            return OperatingClass::global(class);
+        }
+
+        let (global_class, channels) = regional_class(region, class)?;
+        let global_operating_class = OperatingClass::global(global_class)?;
+
+        Some(OperatingClass {
+            region,
+            class,
+            channels: channels.unwrap_or(global_operating_class.channels),
+            ..global_operating_class
+        })
+    }
+
+    /// Looks up an operating class used in the country, like the ones in the Country and AP
+    /// Channel Report elements.
+    pub fn for_country(country_string: &str, class: u8) -> Option<OperatingClass> {
+        OperatingClass::regional(Region::from_country_string(country_string)?, class)
+    }
+
+    /// Every operating class of the region's table, in ascending order.
+    pub fn classes(region: Region) -> Vec<OperatingClass> {
+        (0..=u8::MAX)
+            .filter_map(|class| OperatingClass::regional(region, class))
+            .collect()
+    }
+
+    /// Every global operating class, in ascending order.
+    pub fn global_classes() -> Vec<OperatingClass> {
+        OperatingClass::classes(Region::Global)
+    }
+
+    /// The center frequency of a channel of the band, from the global operating classes of the
+    /// band. The channel can be a 20 MHz channel or the center channel of a wider channel.
+    pub fn channel_frequency_mhz(band: ChannelBand, channel: u8) -> Option<u32> {
+        OperatingClass::global_classes()
+            .into_iter()
+            .filter(|operating_class| operating_class.band() == band)
+            .flat_map(|operating_class| operating_class.channel_frequencies_mhz())
+            .find(|(number, _)| *number == channel)
+            .map(|(_, frequency_mhz)| frequency_mhz)
+    }
+
+    /// The band and number of the channel centered on the frequency, from the global operating
+    /// classes. The channel can be a 20 MHz channel or the center channel of a wider channel.
+    pub fn channel_from_frequency_mhz(frequency_mhz: u32) -> Option<(ChannelBand, u8)> {
+        OperatingClass::global_classes()
+            .into_iter()
+            .find_map(|operating_class| {
+                operating_class
+                    .channel_frequencies_mhz()
+                    .into_iter()
+                    .find(|(_, channel_frequency_mhz)| *channel_frequency_mhz == frequency_mhz)
+                    .map(|(number, _)| (operating_class.band(), number))
+            })
+    }
+
+    pub fn region(&self) -> Region {
+        self.region
+    }
+
+    pub fn class(&self) -> u8 {
+        self.class
+    }
+
+    /// The global operating class that the class corresponds to.
+    pub fn global_class(&self) -> u8 {
+        self.global_class
+    }
+
+    pub fn starting_frequency_mhz(&self) -> u32 {
+        self.starting_frequency_mhz
+    }
+
+    pub fn band(&self) -> ChannelBand {
+        match self.starting_frequency_mhz {
+            2407 | 2414 => ChannelBand::TwoPointFourGhz,
+            5000 => ChannelBand::FiveGhz,
+            _ => ChannelBand::SixGhz,
+        }
+    }
+
+    /// The width of the channels in the class.
+    pub fn channel_spacing(&self) -> ChannelWidth {
+        self.channel_spacing
+    }
+
+    /// The channel set, which is the primary channels for 20 and 40 MHz classes in the 2.4 and
+    /// 5 GHz bands and the center channels otherwise.
+    pub fn channels(&self) -> &'static [u8] {
+        self.channels
+    }
+
+    pub fn behavior_limits(&self) -> BehaviorLimits {
+        self.behavior_limits
+    }
+
+    /// Whether the channels of the class need radar detection.
+    pub fn requires_dfs(&self) -> bool {
+        self.behavior_limits.contains(BehaviorLimit::Dfs50100)
+    }
+
+    pub fn contains(&self, channel: u8) -> bool {
+        self.channels.contains(&channel)
+    }
+
+    /// Where the secondary channel is relative to the primary channel in a 40 MHz class whose
+    /// channel set is primary channels.
+    pub fn secondary_channel_position(&self) -> Option<SecondaryChannelPosition> {
+        if self
+            .behavior_limits
+            .contains(BehaviorLimit::PrimaryChannelLower)
+        {
+            Some(SecondaryChannelPosition::Above)
+        } else if self
+            .behavior_limits
+            .contains(BehaviorLimit::PrimaryChannelUpper)
+        {
+            Some(SecondaryChannelPosition::Below)
+        } else {
+            None
+        }
+    }
+
+    /// The center frequency of the whole channel of the class's width that the channel of the
+    /// channel set identifies. For 80+80 MHz classes, it's the center of one of the segments.
+    pub fn center_frequency_mhz(&self, channel: u8) -> Option<u32> {
+        if !self.contains(channel) {
+            return None;
+        }
+
+        let frequency_mhz = self.starting_frequency_mhz + u32::from(channel) * 5;
+        Some(match self.secondary_channel_position() {
+            Some(SecondaryChannelPosition::Above) => frequency_mhz + 10,
+            Some(SecondaryChannelPosition::Below) => frequency_mhz - 10,
+            None => frequency_mhz,
+        })
+    }
+
+    // The channels of the channel set with their center frequencies, plus the center channels of
+    // the 40 MHz channels that the channel set identifies by their primary channels
+    fn channel_frequencies_mhz(&self) -> Vec<(u8, u32)> {
+        self.channels
+            .iter()
+            .flat_map(|channel| {
+                let frequency_mhz = self.starting_frequency_mhz + u32::from(*channel) * 5;
+                let center_channel = self
+                    .center_frequency_mhz(*channel)
+                    .filter(|center_frequency_mhz| *center_frequency_mhz != frequency_mhz)
+                    .and_then(|center_frequency_mhz| {
+                        u8::try_from((center_frequency_mhz - self.starting_frequency_mhz) / 5)
+                            .ok()
+                            .map(|center_channel| (center_channel, center_frequency_mhz))
+                    });
+                std::iter::once((*channel, frequency_mhz)).chain(center_channel)
+            })
+            .collect()
+    }
+}
+
+impl Display for OperatingClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} ({}, {})",
+            self.class,
+            self.band(),
+            self.channel_spacing
+        )?;
+        if self.region != Region::Global {
+            write!(f, " [{} class, global {}]", self.region, self.global_class)?;
+        }
+        if !self.behavior_limits.is_empty() {
+            write!(f, " [{}]", self.behavior_limits)?;
+        }
+        Ok(())
+    }
+}
+
+const SIX_GHZ_20_MHZ_CHANNELS: &[u8] = &[
+    1, 5, 9, 13, 17, 21, 25, 29, 33, 37, 41, 45, 49, 53, 57, 61, 65, 69, 73, 77, 81, 85, 89, 93,
+    97, 101, 105, 109, 113, 117, 121, 125, 129, 133, 137, 141, 145, 149, 153, 157, 161, 165, 169,
+    173, 177, 181, 185, 189, 193, 197, 201, 205, 209, 213, 217, 221, 225, 229, 233,
+];
+const SIX_GHZ_40_MHZ_CHANNELS: &[u8] = &[
+    3, 11, 19, 27, 35, 43, 51, 59, 67, 75, 83, 91, 99, 107, 115, 123, 131, 139, 147, 155, 163, 171,
+    179, 187, 195, 203, 211, 219, 227,
+];
+const SIX_GHZ_80_MHZ_CHANNELS: &[u8] = &[
+    7, 23, 39, 55, 71, 87, 103, 119, 135, 151, 167, 183, 199, 215,
+];
+const SIX_GHZ_160_MHZ_CHANNELS: &[u8] = &[15, 47, 79, 111, 143, 175, 207];
+const SIX_GHZ_320_MHZ_CHANNELS: &[u8] = &[31, 63, 95, 127, 159, 191];
+
+// The global operating class that each regional operating class corresponds to, and its channel
+// set where the region only allows some of the global class's channels
+fn regional_class(region: Region, class: u8) -> Option<(u8, Option<&'static [u8]>)> {
+    let regional_class: (u8, Option<&'static [u8]>) = match (region, class) {
+        // Table E-1
+        (Region::UnitedStates, 1) => (115, None),
+        (Region::UnitedStates, 2) => (118, None),
+        (Region::UnitedStates, 3) => (124, None),
+        (Region::UnitedStates, 4) => (121, None),
+        (Region::UnitedStates, 5) => (125, Some(&[149, 153, 157, 161, 165])),
+        (Region::UnitedStates, 12) => (81, Some(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11])),
+        (Region::UnitedStates, 22) => (116, None),
+        (Region::UnitedStates, 23) => (119, None),
+        (Region::UnitedStates, 24) => (122, None),
+        (Region::UnitedStates, 25) => (126, Some(&[149, 157])),
+        (Region::UnitedStates, 26) => (126, Some(&[149, 157])),
+        (Region::UnitedStates, 27) => (117, None),
+        (Region::UnitedStates, 28) => (120, None),
+        (Region::UnitedStates, 29) => (123, None),
+        (Region::UnitedStates, 30) => (127, Some(&[153, 161])),
+        (Region::UnitedStates, 31) => (127, Some(&[153, 161])),
+        (Region::UnitedStates, 32) => (83, Some(&[1, 2, 3, 4, 5, 6, 7])),
+        (Region::UnitedStates, 33) => (84, Some(&[5, 6, 7, 8, 9, 10, 11])),
+        // Table E-2
+        (Region::Europe, 1) => (115, None),
+        (Region::Europe, 2) => (118, None),
+        (Region::Europe, 3) => (
+            121,
+            Some(&[100, 104, 108, 112, 116, 120, 124, 128, 132, 136, 140]),
+        ),
+        (Region::Europe, 4) => (81, None),
+        (Region::Europe, 5) => (116, None),
+        (Region::Europe, 6) => (119, None),
+        (Region::Europe, 7) => (122, Some(&[100, 108, 116, 124, 132])),
+        (Region::Europe, 8) => (117, None),
+        (Region::Europe, 9) => (120, None),
+        (Region::Europe, 10) => (123, Some(&[104, 112, 120, 128, 136])),
+        (Region::Europe, 11) => (83, None),
+        (Region::Europe, 12) => (84, None),
+        (Region::Europe, 17) => (125, Some(&[149, 153, 157, 161, 165, 169])),
+        // Table E-3
+        (Region::Japan, 1) => (115, None),
+        (Region::Japan, 30) => (81, None),
+        (Region::Japan, 31) => (82, None),
+        (Region::Japan, 32) | (Region::Japan, 33) => (118, None),
+        (Region::Japan, 34) | (Region::Japan, 35) => (
+            121,
+            Some(&[100, 104, 108, 112, 116, 120, 124, 128, 132, 136, 140]),
+        ),
+        (Region::Japan, 36) => (116, None),
+        (Region::Japan, 37) | (Region::Japan, 38) => (119, None),
+        (Region::Japan, 39) | (Region::Japan, 40) => (122, Some(&[100, 108, 116, 124, 132])),
+        (Region::Japan, 41) => (117, None),
+        (Region::Japan, 42) | (Region::Japan, 43) => (120, None),
+        (Region::Japan, 44) | (Region::Japan, 45) => (123, Some(&[104, 112, 120, 128, 136])),
+        (Region::Japan, 56) => (83, None),
+        (Region::Japan, 57) => (84, None),
+        // Table E-5
+        (Region::China, 1) => (115, None),
+        (Region::China, 2) => (118, None),
+        (Region::China, 3) => (125, Some(&[149, 153, 157, 161, 165])),
+        (Region::China, 4) => (116, None),
+        (Region::China, 5) => (119, None),
+        (Region::China, 6) => (126, Some(&[149, 157])),
+        (Region::China, 7) => (81, None),
+        (Region::China, 8) => (83, None),
+        (Region::China, 9) => (84, None),
+        // The VHT and 6 GHz classes are the same in every region
+        (_, 128..=137) => (class, None),
+        _ => return None,
+    };
+
+    Some(regional_class)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn operating_class_frequencies() {
+        let operating_class = OperatingClass::regional(Region::UnitedStates, 22).unwrap();
+        assert_eq!(operating_class.global_class(), 116);
+        assert_eq!(
+            operating_class.secondary_channel_position(),
+            Some(SecondaryChannelPosition::Above)
+        );
+        assert_eq!(operating_class.center_frequency_mhz(36), Some(5190));
+
+        let operating_class = OperatingClass::for_country("DE ", 3).unwrap();
+        assert!(operating_class.requires_dfs());
+        assert!(!operating_class.contains(144));
+
+        assert_eq!(
+            OperatingClass::global(134)
+                .unwrap()
+                .center_frequency_mhz(15),
+            Some(6025)
+        );
+        assert_eq!(
+            OperatingClass::channel_frequency_mhz(ChannelBand::TwoPointFourGhz, 14),
+            Some(2484)
+        );
+        assert_eq!(
+            OperatingClass::channel_frequency_mhz(ChannelBand::SixGhz, 2),
+            Some(5935)
+        );
+        assert_eq!(
+            OperatingClass::channel_frequency_mhz(ChannelBand::FiveGhz, 38),
+            Some(5190)
+        );
+        assert_eq!(
+            OperatingClass::channel_frequency_mhz(ChannelBand::FiveGhz, 184),
+            None
+        );
+        assert_eq!(
+            OperatingClass::channel_from_frequency_mhz(6115),
+            Some((ChannelBand::SixGhz, 33))
+        );
+        assert_eq!(
+            OperatingClass::channel_from_frequency_mhz(5210),
+            Some((ChannelBand::FiveGhz, 42))
+        );
+    }
+}
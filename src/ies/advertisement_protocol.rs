@@ -0,0 +1,154 @@
+use super::{Field, IeError, InformationElement};
+use std::fmt::Display;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdvertisementProtocolId {
+    Anqp,
+    MihInformationService,
+    MihCommandAndEventServicesCapabilityDiscovery,
+    Eas,
+    Rlqp,
+    VendorSpecific,
+    Reserved(u8),
+}
+
+impl From<u8> for AdvertisementProtocolId {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => AdvertisementProtocolId::Anqp,
+            1 => AdvertisementProtocolId::MihInformationService,
+            2 => AdvertisementProtocolId::MihCommandAndEventServicesCapabilityDiscovery,
+            3 => AdvertisementProtocolId::Eas,
+            4 => AdvertisementProtocolId::Rlqp,
+            221 => AdvertisementProtocolId::VendorSpecific,
+            value => AdvertisementProtocolId::Reserved(value),
+        }
+    }
+}
+
+impl Display for AdvertisementProtocolId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AdvertisementProtocolId::Anqp => write!(f, "ANQP"),
+            AdvertisementProtocolId::MihInformationService => {
+                write!(f, "MIH Information Service")
+            }
+            AdvertisementProtocolId::MihCommandAndEventServicesCapabilityDiscovery => {
+                write!(f, "MIH Command and Event Services Capability Discovery")
+            }
+            AdvertisementProtocolId::Eas => write!(f, "EAS"),
+            AdvertisementProtocolId::Rlqp => write!(f, "RLQP"),
+            AdvertisementProtocolId::VendorSpecific => write!(f, "Vendor Specific"),
+            AdvertisementProtocolId::Reserved(value) => write!(f, "Reserved ({})", value),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AdvertisementProtocolTuple {
+    pub query_response_length_limit: u8,
+    pub pame_bi: bool,
+    pub advertisement_protocol_id: AdvertisementProtocolId,
+    /// The content of a vendor specific advertisement protocol, starting with its OUI.
+    pub vendor_specific_content: Option<Vec<u8>>,
+}
+
+impl AdvertisementProtocolTuple {
+    pub fn fields(&self) -> Vec<Field> {
+        let mut fields = vec![
+            Field::new(
+                "Query Response Length Limit",
+                self.query_response_length_limit,
+            ),
+            Field::new("PAME-BI", self.pame_bi),
+        ];
+
+        if let Some(vendor_specific_content) = &self.vendor_specific_content {
+            fields.push(Field::new(
+                "Vendor Specific Content",
+                format!("{:02X?}", vendor_specific_content),
+            ));
+        }
+
+        fields
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AdvertisementProtocol {
+    bytes: Vec<u8>,
+}
+
+impl AdvertisementProtocol {
+    pub const MIN_LENGTH: usize = 2;
+
+    pub fn new(bytes: Vec<u8>) -> Result<AdvertisementProtocol, IeError> {
+        if bytes.len() >= Self::MIN_LENGTH {
+            Ok(AdvertisementProtocol { bytes })
+        } else {
+            Err(IeError::InvalidLength {
+                ie_name: Self::NAME,
+                expected_length: Self::MIN_LENGTH,
+                actual_length: bytes.len(),
+            })
+        }
+    }
+
+    pub fn advertisement_protocols(&self) -> Vec<AdvertisementProtocolTuple> {
+        let mut advertisement_protocols = Vec::new();
+        let mut remaining = self.bytes.as_slice();
+
+        while let [query_response_info, advertisement_protocol_id, rest @ ..] = remaining {
+            let advertisement_protocol_id =
+                AdvertisementProtocolId::from(*advertisement_protocol_id);
+            remaining = rest;
+
+            // A vendor specific advertisement protocol is followed by its length and content
+            let vendor_specific_content =
+                if advertisement_protocol_id == AdvertisementProtocolId::VendorSpecific {
+                    match remaining {
+                        [length, rest @ ..] if rest.len() >= *length as usize => {
+                            remaining = &rest[*length as usize..];
+                            Some(rest[..*length as usize].to_vec())
+                        }
+                        _ => break,
+                    }
+                } else {
+                    None
+                };
+
+            advertisement_protocols.push(AdvertisementProtocolTuple {
+                query_response_length_limit: query_response_info & 0b0111_1111,
+                pame_bi: query_response_info & 0b1000_0000 != 0,
+                advertisement_protocol_id,
+                vendor_specific_content,
+            });
+        }
+
+        advertisement_protocols
+    }
+}
+
+impl InformationElement for AdvertisementProtocol {
+    const NAME: &'static str = "Advertisement Protocol";
+    const ID: u8 = 108;
+
+    fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    fn information_fields(&self) -> Vec<Field> {
+        self.advertisement_protocols()
+            .iter()
+            .map(|advertisement_protocol| {
+                Field::with_subfields(
+                    "Advertisement Protocol",
+                    advertisement_protocol.advertisement_protocol_id,
+                    advertisement_protocol.fields(),
+                )
+            })
+            .collect()
+    }
+}
+
+impl_display_for_ie!(AdvertisementProtocol);
@@ -1,4 +1,4 @@
-use super::{Field, IeError, InformationElement};
+use super::{operating_class_field, Field, IeError, InformationElement};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ApChannelReport {
@@ -43,7 +43,7 @@ impl InformationElement for ApChannelReport {
 
     fn information_fields(&self) -> Vec<Field> {
         vec![
-            Field::new("Operating Class", self.operating_class()),
+            operating_class_field("Operating Class", self.operating_class()),
             Field::new("Channel List", format!("{:?}", self.channel_list())),
         ]
     }
@@ -0,0 +1,55 @@
+use super::{Field, IeError, InformationElement};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChannelSwitchAnnouncement {
+    bytes: Vec<u8>,
+}
+
+impl ChannelSwitchAnnouncement {
+    pub const LENGTH: usize = 3;
+
+    pub fn new(bytes: Vec<u8>) -> Result<ChannelSwitchAnnouncement, IeError> {
+        if bytes.len() == Self::LENGTH {
+            Ok(ChannelSwitchAnnouncement { bytes })
+        } else {
+            Err(IeError::InvalidLength {
+                ie_name: Self::NAME,
+                expected_length: Self::LENGTH,
+                actual_length: bytes.len(),
+            })
+        }
+    }
+
+    /// Whether STAs must stop transmitting until the switch.
+    pub fn channel_switch_mode(&self) -> bool {
+        self.bytes[0] == 1
+    }
+
+    pub fn new_channel_number(&self) -> u8 {
+        self.bytes[1]
+    }
+
+    /// The number of TBTTs until the switch, where 0 means it can happen at any time.
+    pub fn channel_switch_count(&self) -> u8 {
+        self.bytes[2]
+    }
+}
+
+impl InformationElement for ChannelSwitchAnnouncement {
+    const NAME: &'static str = "Channel Switch Announcement";
+    const ID: u8 = 37;
+
+    fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    fn information_fields(&self) -> Vec<Field> {
+        vec![
+            Field::new("Channel Switch Mode", self.channel_switch_mode()),
+            Field::new("New Channel Number", self.new_channel_number()),
+            Field::new("Channel Switch Count", self.channel_switch_count()),
+        ]
+    }
+}
+
+impl_display_for_ie!(ChannelSwitchAnnouncement);
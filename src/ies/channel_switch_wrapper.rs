@@ -0,0 +1,122 @@
+use super::{Field, Ie, IeError, InformationElement, WideBandwidthChannelSwitch};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChannelSwitchWrapper {
+    bytes: Vec<u8>,
+    subelements: Vec<Ie>,
+}
+
+impl ChannelSwitchWrapper {
+    pub fn new(bytes: Vec<u8>) -> Result<ChannelSwitchWrapper, IeError> {
+        // The New Country, Wide Bandwidth Channel Switch and New Transmit Power Envelope
+        // subelements are formatted like the elements
+        let subelements = super::from_bytes(&bytes)?;
+        Ok(ChannelSwitchWrapper { bytes, subelements })
+    }
+
+    pub fn subelements(&self) -> &[Ie] {
+        &self.subelements
+    }
+
+    pub fn wide_bandwidth_channel_switch(&self) -> Option<&WideBandwidthChannelSwitch> {
+        self.subelements.iter().find_map(|ie| match ie {
+            Ie::WideBandwidthChannelSwitch(wide_bandwidth_channel_switch) => {
+                Some(wide_bandwidth_channel_switch)
+            }
+            _ => None,
+        })
+    }
+}
+
+impl InformationElement for ChannelSwitchWrapper {
+    const NAME: &'static str = "Channel Switch Wrapper";
+    const ID: u8 = 196;
+
+    fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    fn information_fields(&self) -> Vec<Field> {
+        self.subelements
+            .iter()
+            .map(|subelement| {
+                let title = match subelement {
+                    Ie::Country(_) => "New Country",
+                    Ie::TransmitPowerEnvelope(_) => "New Transmit Power Envelope",
+                    subelement => subelement.name(),
+                };
+                Field::with_subfields(
+                    title,
+                    format!("{:02?}", subelement.bytes()),
+                    subelement.information_fields(),
+                )
+            })
+            .collect()
+    }
+}
+
+impl_display_for_ie!(ChannelSwitchWrapper);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ies::{
+            ChannelSwitchAnnouncement, ExtendedChannelSwitchAnnouncement,
+            SecondaryChannelOffsetElement,
+        },
+        ChannelSwitch, ChannelWidth,
+    };
+
+    #[test]
+    fn channel_switch_with_wrapped_wide_bandwidth_channel_switch() {
+        let ies = vec![
+            Ie::ExtendedChannelSwitchAnnouncement(
+                ExtendedChannelSwitchAnnouncement::new(vec![0x01, 0x80, 0x24, 0x05]).unwrap(),
+            ),
+            Ie::ChannelSwitchWrapper(
+                ChannelSwitchWrapper::new(vec![0xC2, 0x03, 0x01, 0x2A, 0x32]).unwrap(),
+            ),
+        ];
+        let channel_switch = ChannelSwitch::from_ies(&ies).unwrap();
+
+        assert_eq!(channel_switch.channel(), 36);
+        assert_eq!(channel_switch.operating_class(), Some(128));
+        assert_eq!(channel_switch.width(), Some(ChannelWidth::OneSixtyMhz));
+        assert_eq!(channel_switch.count_tbtts(), 5);
+        assert!(channel_switch.blocks_transmissions());
+    }
+
+    #[test]
+    fn channel_switch_with_undecodable_new_country() {
+        let channel_switch_wrapper =
+            ChannelSwitchWrapper::new(vec![0x07, 0x06, 0xFF, 0xFE, 0x20, 0x24, 0x04, 0x17])
+                .unwrap();
+        assert!(channel_switch_wrapper.to_string().contains("New Country"));
+
+        // The operating class is looked up in the global table instead
+        let ies = vec![
+            Ie::ExtendedChannelSwitchAnnouncement(
+                ExtendedChannelSwitchAnnouncement::new(vec![0x00, 0x80, 0x24, 0x05]).unwrap(),
+            ),
+            Ie::ChannelSwitchWrapper(channel_switch_wrapper),
+        ];
+        let channel_switch = ChannelSwitch::from_ies(&ies).unwrap();
+        assert_eq!(channel_switch.width(), Some(ChannelWidth::EightyMhz));
+    }
+
+    #[test]
+    fn channel_switch_with_secondary_channel_offset() {
+        let ies = vec![
+            Ie::ChannelSwitchAnnouncement(
+                ChannelSwitchAnnouncement::new(vec![0x00, 0x24, 0x03]).unwrap(),
+            ),
+            Ie::SecondaryChannelOffset(SecondaryChannelOffsetElement::new(vec![0x01]).unwrap()),
+        ];
+        let channel_switch = ChannelSwitch::from_ies(&ies).unwrap();
+
+        assert_eq!(channel_switch.channel(), 36);
+        assert_eq!(channel_switch.operating_class(), None);
+        assert_eq!(channel_switch.width(), Some(ChannelWidth::FortyMhz));
+    }
+}
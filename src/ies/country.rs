@@ -1,6 +1,5 @@
 use super::{Field, IeError, InformationElement};
-use crate::ChannelNumber;
-use std::convert::TryFrom;
+use crate::{ChannelNumber, OperatingClass};
 use std::{fmt::Display, str};
 
 pub enum Environment {
@@ -24,6 +23,7 @@ pub struct OperatingInfo {
     operating_extension_id: u8,
     operating_class: u8,
     coverage_class: u8,
+    operating_class_entry: Option<OperatingClass>,
 }
 
 impl OperatingInfo {
@@ -49,7 +49,16 @@ impl OperatingInfo {
 
 impl Display for OperatingInfo {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Operating Info:\r\n\tOperating Extension ID: {}\r\n\tOperating Class: {}\r\n\tCoverage Class: {}\r\n\t", self.operating_extension_id, self.operating_class,self.coverage_class)
+        write!(
+            f,
+            "Operating Info:\r\n\tOperating Extension ID: {}\r\n\tOperating Class: ",
+            self.operating_extension_id
+        )?;
+        match self.operating_class_entry {
+            Some(operating_class) => write!(f, "{}", operating_class)?,
+            None => write!(f, "{}", self.operating_class)?,
+        }
+        write!(f, "\r\n\tCoverage Class: {}\r\n\t", self.coverage_class)
     }
 }
 
@@ -106,12 +115,20 @@ impl Country {
 
     // Country String
 
-    pub fn country_string(&self) -> &str {
-        str::from_utf8(&self.bytes[0..=2]).unwrap()
+    /// The country string, or None if it isn't valid UTF-8.
+    pub fn country_string(&self) -> Option<&str> {
+        str::from_utf8(&self.bytes[0..=2]).ok()
+    }
+
+    /// The country code, or None if it isn't valid UTF-8.
+    pub fn country_code(&self) -> Option<&str> {
+        str::from_utf8(&self.bytes[0..=1]).ok()
     }
 
-    pub fn country_code(&self) -> &str {
-        str::from_utf8(&self.bytes[0..=1]).unwrap()
+    /// Looks up an operating class used in this country, like the ones in the triplets and in AP
+    /// Channel Report elements.
+    pub fn operating_class(&self, class: u8) -> Option<OperatingClass> {
+        OperatingClass::for_country(self.country_string()?, class)
     }
 
     pub fn environment(&self) -> Option<Environment> {
@@ -130,18 +147,22 @@ impl Country {
         let mut last_operating_info = None;
 
         for triplet in self.bytes[3..].chunks_exact(3) {
-            if let Ok(channel_number) = ChannelNumber::try_from(triplet[0]) {
+            // First channel numbers go up to 200 and operating extension identifiers start at 201
+            if triplet[0] <= 200 {
                 subbands.push(SubbandInfo {
-                    first_channel_number: channel_number,
+                    first_channel_number: ChannelNumber::from(triplet[0]),
                     number_of_channels: triplet[1],
                     max_transmit_power_level_dbm: triplet[2] as i8,
                     operating_info: last_operating_info,
                 });
-            } else if triplet[0] > 200 {
+            } else {
                 last_operating_info = Some(OperatingInfo {
                     operating_extension_id: triplet[0],
                     operating_class: triplet[1],
                     coverage_class: triplet[2],
+                    operating_class_entry: self
+                        .operating_class(triplet[1])
+                        .or_else(|| OperatingClass::global(triplet[1])),
                 });
             }
         }
@@ -160,7 +181,7 @@ impl InformationElement for Country {
 
     fn information_fields(&self) -> Vec<Field> {
         vec![
-            Field::new("Country Code", self.country_code()),
+            Field::new("Country Code", String::from_utf8_lossy(&self.bytes[0..=1])),
             Field::new(
                 "Environment",
                 self.environment().unwrap_or(Environment::Any),
@@ -170,3 +191,30 @@ impl InformationElement for Country {
 }
 
 impl_display_for_ie!(Country);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn country_operating_classes() {
+        let country =
+            Country::new(vec![b'D', b'E', b' ', 0xC9, 0x01, 0x00, 0x24, 0x04, 0x17]).unwrap();
+        assert_eq!(country.country_string(), Some("DE "));
+        assert_eq!(
+            country.operating_class(1).map(|class| class.global_class()),
+            Some(115)
+        );
+
+        let subbands = country.subband_info();
+        let operating_info = subbands[0].operating_class().unwrap();
+        assert_eq!(operating_info.operating_class(), 1);
+        assert!(operating_info.to_string().contains("global 115"));
+
+        // A country string that isn't UTF-8 has no regional operating classes
+        let country = Country::new(vec![0xFF, 0xFE, b' ', 0x24, 0x04, 0x17]).unwrap();
+        assert_eq!(country.country_string(), None);
+        assert_eq!(country.operating_class(1), None);
+        assert!(country.to_string().contains("Country Code"));
+    }
+}
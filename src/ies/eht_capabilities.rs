@@ -0,0 +1,734 @@
+use super::{
+    he_capabilities::read_ppe_thresholds, Field, HeCapabilities, IeError, InformationElement,
+    PpeThreshold,
+};
+use bitvec::prelude::*;
+use num_enum::TryFromPrimitive;
+use std::{convert::TryFrom, fmt::Display};
+
+/// The max number of spatial streams supported for each range of EHT-MCSs, in either the 4-octet
+/// 20 MHz-only format or the 3-octet format used for wider bandwidths.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EhtMcsMap {
+    bytes: [u8; 4],
+    twenty_mhz_only: bool,
+}
+
+impl EhtMcsMap {
+    pub fn new(bytes: &[u8]) -> EhtMcsMap {
+        let mut map = [0; 4];
+        let length = bytes.len().min(4);
+        map[..length].copy_from_slice(&bytes[..length]);
+
+        EhtMcsMap {
+            bytes: map,
+            twenty_mhz_only: length == 4,
+        }
+    }
+
+    fn mcs_ranges(&self) -> &'static [(u8, u8)] {
+        if self.twenty_mhz_only {
+            &[(0, 7), (8, 9), (10, 11), (12, 13)]
+        } else {
+            &[(0, 9), (10, 11), (12, 13)]
+        }
+    }
+
+    fn nibble(&self, mcs: u8, shift: u8) -> u8 {
+        self.mcs_ranges()
+            .iter()
+            .position(|(low, high)| (*low..=*high).contains(&mcs))
+            .map(|index| (self.bytes[index] >> shift) & 0x0F)
+            .unwrap_or(0)
+    }
+
+    /// The max number of spatial streams that can receive the EHT-MCS.
+    pub fn rx_max_nss(&self, mcs: u8) -> u8 {
+        self.nibble(mcs, 0)
+    }
+
+    /// The max number of spatial streams that can transmit the EHT-MCS.
+    pub fn tx_max_nss(&self, mcs: u8) -> u8 {
+        self.nibble(mcs, 4)
+    }
+
+    pub fn fields(&self) -> Vec<Field> {
+        let mut fields = Vec::new();
+        for (low, high) in self.mcs_ranges() {
+            fields.push(Field::new(
+                format!("Rx Max NSS That Supports EHT-MCS {}-{}", low, high),
+                self.rx_max_nss(*low),
+            ));
+            fields.push(Field::new(
+                format!("Tx Max NSS That Supports EHT-MCS {}-{}", low, high),
+                self.tx_max_nss(*low),
+            ));
+        }
+
+        fields
+    }
+}
+
+impl Display for EhtMcsMap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:02?}", &self.bytes[..self.mcs_ranges().len()])
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
+#[repr(u8)]
+pub enum EhtMaxMpduLength {
+    Octets3895,
+    Octets7991,
+    Octets11454,
+}
+
+impl Display for EhtMaxMpduLength {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EhtMaxMpduLength::Octets3895 => write!(f, "3895 octets"),
+            EhtMaxMpduLength::Octets7991 => write!(f, "7991 octets"),
+            EhtMaxMpduLength::Octets11454 => write!(f, "11454 octets"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EhtCapabilities {
+    bits: BitVec<Lsb0, u8>,
+    he_one_sixty_mhz: bool,
+}
+
+impl EhtCapabilities {
+    pub const MIN_LENGTH: usize = 14;
+
+    const PHY_OFFSET: usize = 16;
+    const MCS_NSS_OFFSET: usize = 11;
+    const MCS_MAP_LENGTH: usize = 3;
+
+    /// Parses the element with the HE Capabilities element of the same frame, whose 160 MHz support
+    /// decides which EHT-MCS maps are present.
+    pub fn new(
+        bytes: Vec<u8>,
+        he_capabilities: Option<&HeCapabilities>,
+    ) -> Result<EhtCapabilities, IeError> {
+        if bytes.len() >= Self::MIN_LENGTH {
+            Ok(EhtCapabilities {
+                bits: BitVec::from_vec(bytes),
+                he_one_sixty_mhz: he_capabilities
+                    .map(HeCapabilities::one_sixty_mhz_in_five_ghz)
+                    .unwrap_or_default(),
+            })
+        } else {
+            Err(IeError::InvalidLength {
+                ie_name: Self::NAME,
+                expected_length: Self::MIN_LENGTH,
+                actual_length: bytes.len(),
+            })
+        }
+    }
+
+    fn phy_bit(&self, index: usize) -> bool {
+        self.bits[Self::PHY_OFFSET + index]
+    }
+
+    fn phy_bits(&self, start: usize, end: usize) -> u8 {
+        self.bits[Self::PHY_OFFSET + start..=Self::PHY_OFFSET + end].load()
+    }
+
+    // EHT MAC Capabilities Information
+
+    pub fn epcs_priority_access_support(&self) -> bool {
+        self.bits[0]
+    }
+
+    pub fn eht_om_control_support(&self) -> bool {
+        self.bits[1]
+    }
+
+    pub fn triggered_txop_sharing_mode_one_support(&self) -> bool {
+        self.bits[2]
+    }
+
+    pub fn triggered_txop_sharing_mode_two_support(&self) -> bool {
+        self.bits[3]
+    }
+
+    pub fn restricted_twt_support(&self) -> bool {
+        self.bits[4]
+    }
+
+    pub fn scs_traffic_description_support(&self) -> bool {
+        self.bits[5]
+    }
+
+    pub fn max_mpdu_length(&self) -> EhtMaxMpduLength {
+        EhtMaxMpduLength::try_from(self.bits[6..=7].load::<u8>())
+            .unwrap_or(EhtMaxMpduLength::Octets3895)
+    }
+
+    pub fn max_ampdu_length_exponent_extension(&self) -> u8 {
+        self.bits[8] as u8
+    }
+
+    pub fn eht_trs_support(&self) -> bool {
+        self.bits[9]
+    }
+
+    pub fn txop_return_support_in_txop_sharing_mode_two(&self) -> bool {
+        self.bits[10]
+    }
+
+    pub fn two_bqrs_support(&self) -> bool {
+        self.bits[11]
+    }
+
+    pub fn eht_link_adaptation_support(&self) -> u8 {
+        self.bits[12..=13].load()
+    }
+
+    pub fn unsolicited_epcs_priority_access_parameter_update(&self) -> bool {
+        self.bits[14]
+    }
+
+    // EHT PHY Capabilities Information
+
+    pub fn three_twenty_mhz_in_six_ghz(&self) -> bool {
+        self.phy_bit(1)
+    }
+
+    pub fn two_forty_two_tone_ru_in_bw_wider_than_twenty_mhz(&self) -> bool {
+        self.phy_bit(2)
+    }
+
+    pub fn ndp_with_4x_eht_ltf_and_3_2_us_gi(&self) -> bool {
+        self.phy_bit(3)
+    }
+
+    pub fn partial_bandwidth_ul_mu_mimo(&self) -> bool {
+        self.phy_bit(4)
+    }
+
+    pub fn su_beamformer(&self) -> bool {
+        self.phy_bit(5)
+    }
+
+    pub fn su_beamformee(&self) -> bool {
+        self.phy_bit(6)
+    }
+
+    pub fn beamformee_ss_less_than_or_equal_eighty_mhz(&self) -> u8 {
+        self.phy_bits(7, 9) + 1
+    }
+
+    pub fn beamformee_ss_one_sixty_mhz(&self) -> u8 {
+        self.phy_bits(10, 12) + 1
+    }
+
+    pub fn beamformee_ss_three_twenty_mhz(&self) -> u8 {
+        self.phy_bits(13, 15) + 1
+    }
+
+    pub fn number_of_sounding_dimensions_less_than_or_equal_eighty_mhz(&self) -> u8 {
+        self.phy_bits(16, 18) + 1
+    }
+
+    pub fn number_of_sounding_dimensions_one_sixty_mhz(&self) -> u8 {
+        self.phy_bits(19, 21) + 1
+    }
+
+    pub fn number_of_sounding_dimensions_three_twenty_mhz(&self) -> u8 {
+        self.phy_bits(22, 24) + 1
+    }
+
+    pub fn ng_sixteen_su_feedback(&self) -> bool {
+        self.phy_bit(25)
+    }
+
+    pub fn ng_sixteen_mu_feedback(&self) -> bool {
+        self.phy_bit(26)
+    }
+
+    pub fn codebook_size_su_feedback(&self) -> bool {
+        self.phy_bit(27)
+    }
+
+    pub fn codebook_size_mu_feedback(&self) -> bool {
+        self.phy_bit(28)
+    }
+
+    pub fn triggered_su_beamforming_feedback(&self) -> bool {
+        self.phy_bit(29)
+    }
+
+    pub fn triggered_mu_beamforming_partial_bw_feedback(&self) -> bool {
+        self.phy_bit(30)
+    }
+
+    pub fn triggered_cqi_feedback(&self) -> bool {
+        self.phy_bit(31)
+    }
+
+    pub fn partial_bandwidth_dl_mu_mimo(&self) -> bool {
+        self.phy_bit(32)
+    }
+
+    pub fn eht_psr_based_sr_support(&self) -> bool {
+        self.phy_bit(33)
+    }
+
+    pub fn power_boost_factor_support(&self) -> bool {
+        self.phy_bit(34)
+    }
+
+    pub fn eht_mu_ppdu_with_4x_eht_ltf_and_0_8_us_gi(&self) -> bool {
+        self.phy_bit(35)
+    }
+
+    pub fn max_nc(&self) -> u8 {
+        self.phy_bits(36, 39) + 1
+    }
+
+    pub fn non_triggered_cqi_feedback(&self) -> bool {
+        self.phy_bit(40)
+    }
+
+    pub fn tx_1024_and_4096_qam_less_than_242_tone_ru_support(&self) -> bool {
+        self.phy_bit(41)
+    }
+
+    pub fn rx_1024_and_4096_qam_less_than_242_tone_ru_support(&self) -> bool {
+        self.phy_bit(42)
+    }
+
+    pub fn ppe_thresholds_present(&self) -> bool {
+        self.phy_bit(43)
+    }
+
+    pub fn common_nominal_packet_padding_us(&self) -> u8 {
+        match self.phy_bits(44, 45) {
+            0 => 0,
+            1 => 8,
+            2 => 16,
+            _ => 20,
+        }
+    }
+
+    pub fn max_number_of_supported_eht_ltfs(&self) -> u8 {
+        self.phy_bits(46, 50)
+    }
+
+    pub fn support_of_mcs_fifteen(&self) -> u8 {
+        self.phy_bits(51, 54)
+    }
+
+    pub fn support_of_eht_dup_in_six_ghz(&self) -> bool {
+        self.phy_bit(55)
+    }
+
+    pub fn twenty_mhz_sta_receiving_ndp_with_wider_bandwidth(&self) -> bool {
+        self.phy_bit(56)
+    }
+
+    pub fn non_ofdma_ul_mu_mimo_less_than_or_equal_eighty_mhz(&self) -> bool {
+        self.phy_bit(57)
+    }
+
+    pub fn non_ofdma_ul_mu_mimo_one_sixty_mhz(&self) -> bool {
+        self.phy_bit(58)
+    }
+
+    pub fn non_ofdma_ul_mu_mimo_three_twenty_mhz(&self) -> bool {
+        self.phy_bit(59)
+    }
+
+    pub fn mu_beamformer_less_than_or_equal_eighty_mhz(&self) -> bool {
+        self.phy_bit(60)
+    }
+
+    pub fn mu_beamformer_one_sixty_mhz(&self) -> bool {
+        self.phy_bit(61)
+    }
+
+    pub fn mu_beamformer_three_twenty_mhz(&self) -> bool {
+        self.phy_bit(62)
+    }
+
+    pub fn tb_sounding_feedback_rate_limit(&self) -> bool {
+        self.phy_bit(63)
+    }
+
+    pub fn rx_1024_qam_in_wider_bandwidth_dl_ofdma_support(&self) -> bool {
+        self.phy_bit(64)
+    }
+
+    pub fn rx_4096_qam_in_wider_bandwidth_dl_ofdma_support(&self) -> bool {
+        self.phy_bit(65)
+    }
+
+    // Supported EHT-MCS And NSS Set
+
+    // The 160 MHz map is present if the HE Capabilities element supports 160 MHz, and the 320 MHz
+    // map if this element supports 320 MHz. An AP is never a 20 MHz-only STA, so every map uses
+    // the 3-octet format.
+    fn mcs_map_count(&self) -> usize {
+        1 + usize::from(self.he_one_sixty_mhz) + usize::from(self.three_twenty_mhz_in_six_ghz())
+    }
+
+    fn mcs_map(&self, index: usize) -> Option<EhtMcsMap> {
+        let offset = Self::MCS_NSS_OFFSET + index * Self::MCS_MAP_LENGTH;
+        self.bits
+            .as_raw_slice()
+            .get(offset..offset + Self::MCS_MAP_LENGTH)
+            .map(EhtMcsMap::new)
+    }
+
+    pub fn eht_mcs_map_less_than_or_equal_eighty_mhz(&self) -> EhtMcsMap {
+        self.mcs_map(0).unwrap_or_else(|| EhtMcsMap::new(&[0; 3]))
+    }
+
+    pub fn eht_mcs_map_one_sixty_mhz(&self) -> Option<EhtMcsMap> {
+        if !self.he_one_sixty_mhz {
+            return None;
+        }
+
+        self.mcs_map(1)
+    }
+
+    pub fn eht_mcs_map_three_twenty_mhz(&self) -> Option<EhtMcsMap> {
+        if !self.three_twenty_mhz_in_six_ghz() {
+            return None;
+        }
+
+        self.mcs_map(1 + usize::from(self.he_one_sixty_mhz))
+    }
+
+    // EHT PPE Thresholds
+
+    pub fn ppe_thresholds(&self) -> Vec<PpeThreshold> {
+        if !self.ppe_thresholds_present() {
+            return Vec::new();
+        }
+
+        let offset = (Self::MCS_NSS_OFFSET + self.mcs_map_count() * Self::MCS_MAP_LENGTH) * 8;
+        match self.bits.get(offset..) {
+            Some(ppe_bits) => read_ppe_thresholds(ppe_bits, 4, 5),
+            None => Vec::new(),
+        }
+    }
+}
+
+impl InformationElement for EhtCapabilities {
+    const NAME: &'static str = "EHT Capabilities";
+    const ID: u8 = 255;
+    const ID_EXT: Option<u8> = Some(108);
+
+    fn bytes(&self) -> &[u8] {
+        self.bits.as_raw_slice()
+    }
+
+    fn information_fields(&self) -> Vec<Field> {
+        let mut information_fields = vec![
+            Field::with_subfields(
+                "EHT MAC Capabilities Information",
+                format!("{:02?}", &self.bits.as_raw_slice()[0..=1]),
+                vec![
+                    Field::new(
+                        "EPCS Priority Access Support",
+                        self.epcs_priority_access_support(),
+                    ),
+                    Field::new("EHT OM Control Support", self.eht_om_control_support()),
+                    Field::new(
+                        "Triggered TXOP Sharing Mode 1 Support",
+                        self.triggered_txop_sharing_mode_one_support(),
+                    ),
+                    Field::new(
+                        "Triggered TXOP Sharing Mode 2 Support",
+                        self.triggered_txop_sharing_mode_two_support(),
+                    ),
+                    Field::new("Restricted TWT Support", self.restricted_twt_support()),
+                    Field::new(
+                        "SCS Traffic Description Support",
+                        self.scs_traffic_description_support(),
+                    ),
+                    Field::new("Maximum MPDU Length", self.max_mpdu_length()),
+                    Field::new(
+                        "Maximum A-MPDU Length Exponent Extension",
+                        self.max_ampdu_length_exponent_extension(),
+                    ),
+                    Field::new("EHT TRS Support", self.eht_trs_support()),
+                    Field::new(
+                        "TXOP Return Support in TXOP Sharing Mode 2",
+                        self.txop_return_support_in_txop_sharing_mode_two(),
+                    ),
+                    Field::new("Two BQRs Support", self.two_bqrs_support()),
+                    Field::new(
+                        "EHT Link Adaptation Support",
+                        self.eht_link_adaptation_support(),
+                    ),
+                    Field::new(
+                        "Unsolicited EPCS Priority Access Parameter Update",
+                        self.unsolicited_epcs_priority_access_parameter_update(),
+                    ),
+                ],
+            ),
+            Field::with_subfields(
+                "EHT PHY Capabilities Information",
+                format!("{:02?}", &self.bits.as_raw_slice()[2..=10]),
+                vec![
+                    Field::new("320 MHz in 6 GHz", self.three_twenty_mhz_in_six_ghz()),
+                    Field::new(
+                        "242-tone RU in BW Wider Than 20 MHz",
+                        self.two_forty_two_tone_ru_in_bw_wider_than_twenty_mhz(),
+                    ),
+                    Field::new(
+                        "NDP with 4x EHT-LTF and 3.2 μs GI",
+                        self.ndp_with_4x_eht_ltf_and_3_2_us_gi(),
+                    ),
+                    Field::new(
+                        "Partial Bandwidth UL MU-MIMO",
+                        self.partial_bandwidth_ul_mu_mimo(),
+                    ),
+                    Field::new("SU Beamformer", self.su_beamformer()),
+                    Field::new("SU Beamformee", self.su_beamformee()),
+                    Field::new(
+                        "Beamformee SS ≤ 80 MHz",
+                        self.beamformee_ss_less_than_or_equal_eighty_mhz(),
+                    ),
+                    Field::new(
+                        "Beamformee SS = 160 MHz",
+                        self.beamformee_ss_one_sixty_mhz(),
+                    ),
+                    Field::new(
+                        "Beamformee SS = 320 MHz",
+                        self.beamformee_ss_three_twenty_mhz(),
+                    ),
+                    Field::new(
+                        "Number of Sounding Dimensions ≤ 80 MHz",
+                        self.number_of_sounding_dimensions_less_than_or_equal_eighty_mhz(),
+                    ),
+                    Field::new(
+                        "Number of Sounding Dimensions = 160 MHz",
+                        self.number_of_sounding_dimensions_one_sixty_mhz(),
+                    ),
+                    Field::new(
+                        "Number of Sounding Dimensions = 320 MHz",
+                        self.number_of_sounding_dimensions_three_twenty_mhz(),
+                    ),
+                    Field::new("Ng = 16 SU Feedback", self.ng_sixteen_su_feedback()),
+                    Field::new("Ng = 16 MU Feedback", self.ng_sixteen_mu_feedback()),
+                    Field::new(
+                        "Codebook Size (φ, ψ) = {4, 2} SU Feedback",
+                        self.codebook_size_su_feedback(),
+                    ),
+                    Field::new(
+                        "Codebook Size (φ, ψ) = {7, 5} MU Feedback",
+                        self.codebook_size_mu_feedback(),
+                    ),
+                    Field::new(
+                        "Triggered SU Beamforming Feedback",
+                        self.triggered_su_beamforming_feedback(),
+                    ),
+                    Field::new(
+                        "Triggered MU Beamforming Partial BW Feedback",
+                        self.triggered_mu_beamforming_partial_bw_feedback(),
+                    ),
+                    Field::new("Triggered CQI Feedback", self.triggered_cqi_feedback()),
+                    Field::new(
+                        "Partial Bandwidth DL MU-MIMO",
+                        self.partial_bandwidth_dl_mu_mimo(),
+                    ),
+                    Field::new("EHT PSR-Based SR Support", self.eht_psr_based_sr_support()),
+                    Field::new(
+                        "Power Boost Factor Support",
+                        self.power_boost_factor_support(),
+                    ),
+                    Field::new(
+                        "EHT MU PPDU with 4x EHT-LTF and 0.8 μs GI",
+                        self.eht_mu_ppdu_with_4x_eht_ltf_and_0_8_us_gi(),
+                    ),
+                    Field::new("Max Nc", self.max_nc()),
+                    Field::new(
+                        "Non-Triggered CQI Feedback",
+                        self.non_triggered_cqi_feedback(),
+                    ),
+                    Field::new(
+                        "Tx 1024-QAM and 4096-QAM < 242-tone RU Support",
+                        self.tx_1024_and_4096_qam_less_than_242_tone_ru_support(),
+                    ),
+                    Field::new(
+                        "Rx 1024-QAM and 4096-QAM < 242-tone RU Support",
+                        self.rx_1024_and_4096_qam_less_than_242_tone_ru_support(),
+                    ),
+                    Field::new("PPE Thresholds Present", self.ppe_thresholds_present()),
+                    Field::new(
+                        "Common Nominal Packet Padding",
+                        format!("{} μs", self.common_nominal_packet_padding_us()),
+                    ),
+                    Field::new(
+                        "Maximum Number of Supported EHT-LTFs",
+                        self.max_number_of_supported_eht_ltfs(),
+                    ),
+                    Field::new(
+                        "Support of MCS 15",
+                        format!("{:#06b}", self.support_of_mcs_fifteen()),
+                    ),
+                    Field::new(
+                        "Support of EHT DUP (MCS 14) in 6 GHz",
+                        self.support_of_eht_dup_in_six_ghz(),
+                    ),
+                    Field::new(
+                        "20 MHz Operating STA Receiving NDP with Wider Bandwidth",
+                        self.twenty_mhz_sta_receiving_ndp_with_wider_bandwidth(),
+                    ),
+                    Field::new(
+                        "Non-OFDMA UL MU-MIMO ≤ 80 MHz",
+                        self.non_ofdma_ul_mu_mimo_less_than_or_equal_eighty_mhz(),
+                    ),
+                    Field::new(
+                        "Non-OFDMA UL MU-MIMO = 160 MHz",
+                        self.non_ofdma_ul_mu_mimo_one_sixty_mhz(),
+                    ),
+                    Field::new(
+                        "Non-OFDMA UL MU-MIMO = 320 MHz",
+                        self.non_ofdma_ul_mu_mimo_three_twenty_mhz(),
+                    ),
+                    Field::new(
+                        "MU Beamformer ≤ 80 MHz",
+                        self.mu_beamformer_less_than_or_equal_eighty_mhz(),
+                    ),
+                    Field::new(
+                        "MU Beamformer = 160 MHz",
+                        self.mu_beamformer_one_sixty_mhz(),
+                    ),
+                    Field::new(
+                        "MU Beamformer = 320 MHz",
+                        self.mu_beamformer_three_twenty_mhz(),
+                    ),
+                    Field::new(
+                        "TB Sounding Feedback Rate Limit",
+                        self.tb_sounding_feedback_rate_limit(),
+                    ),
+                    Field::new(
+                        "Rx 1024-QAM in Wider Bandwidth DL OFDMA Support",
+                        self.rx_1024_qam_in_wider_bandwidth_dl_ofdma_support(),
+                    ),
+                    Field::new(
+                        "Rx 4096-QAM in Wider Bandwidth DL OFDMA Support",
+                        self.rx_4096_qam_in_wider_bandwidth_dl_ofdma_support(),
+                    ),
+                ],
+            ),
+        ];
+
+        let eht_mcs_map = self.eht_mcs_map_less_than_or_equal_eighty_mhz();
+        information_fields.push(Field::with_subfields(
+            "EHT-MCS Map ≤ 80 MHz",
+            eht_mcs_map,
+            eht_mcs_map.fields(),
+        ));
+        if let Some(eht_mcs_map) = self.eht_mcs_map_one_sixty_mhz() {
+            information_fields.push(Field::with_subfields(
+                "EHT-MCS Map 160 MHz",
+                eht_mcs_map,
+                eht_mcs_map.fields(),
+            ));
+        }
+        if let Some(eht_mcs_map) = self.eht_mcs_map_three_twenty_mhz() {
+            information_fields.push(Field::with_subfields(
+                "EHT-MCS Map 320 MHz",
+                eht_mcs_map,
+                eht_mcs_map.fields(),
+            ));
+        }
+
+        let ppe_thresholds = self.ppe_thresholds();
+        if !ppe_thresholds.is_empty() {
+            information_fields.push(Field::with_subfields(
+                "EHT PPE Thresholds",
+                ppe_thresholds.len(),
+                ppe_thresholds
+                    .iter()
+                    .map(|threshold| {
+                        Field::new(
+                            format!(
+                                "NSS {} {}-tone RU",
+                                threshold.nss(),
+                                threshold.ru_size_tones()
+                            ),
+                            format!(
+                                "PPETmax: {}, PPET8: {}",
+                                threshold.ppet16(),
+                                threshold.ppet8()
+                            ),
+                        )
+                    })
+                    .collect(),
+            ));
+        }
+
+        information_fields
+    }
+}
+
+impl_display_for_ie!(EhtCapabilities);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ies::{self, Ie};
+
+    #[test]
+    fn eht_mcs_maps_follow_he_capabilities() {
+        let eht_bytes = [
+            0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x44, 0x44, 0x44,
+            0x33, 0x33, 0x33, 0x22, 0x22, 0x22,
+        ];
+
+        // An HE Capabilities element that supports 160 MHz, so the 160 MHz map comes before the
+        // 320 MHz one
+        let mut bytes = vec![0xFF, 0x1A, 0x23];
+        bytes.extend_from_slice(&[0x00; 6]);
+        bytes.extend_from_slice(&[0x0C, 0x00, 0x00, 0x00, 0x00, 0x00]);
+        bytes.extend_from_slice(&[0x00; 5]);
+        bytes.extend_from_slice(&[0xFA, 0xFF, 0xFA, 0xFF, 0xFA, 0xFF, 0xFA, 0xFF]);
+        bytes.extend_from_slice(&[0xFF, 0x15, 0x6C]);
+        bytes.extend_from_slice(&eht_bytes);
+        let ies = ies::from_bytes(&bytes).unwrap();
+
+        let eht_capabilities = match &ies[1] {
+            Ie::EhtCapabilities(eht_capabilities) => eht_capabilities,
+            ie => panic!("Expected EHT Capabilities, got {}", ie.name()),
+        };
+        assert!(eht_capabilities.three_twenty_mhz_in_six_ghz());
+        assert_eq!(
+            eht_capabilities
+                .eht_mcs_map_less_than_or_equal_eighty_mhz()
+                .rx_max_nss(9),
+            4
+        );
+        assert_eq!(
+            eht_capabilities
+                .eht_mcs_map_one_sixty_mhz()
+                .map(|map| map.rx_max_nss(9)),
+            Some(3)
+        );
+        assert_eq!(
+            eht_capabilities
+                .eht_mcs_map_three_twenty_mhz()
+                .map(|map| map.rx_max_nss(9)),
+            Some(2)
+        );
+
+        // Without an HE Capabilities element that supports 160 MHz, the second map is the 320 MHz
+        // one
+        let eht_capabilities = EhtCapabilities::new(eht_bytes[..17].to_vec(), None).unwrap();
+        assert_eq!(eht_capabilities.eht_mcs_map_one_sixty_mhz(), None);
+        assert_eq!(
+            eht_capabilities
+                .eht_mcs_map_three_twenty_mhz()
+                .map(|map| map.rx_max_nss(9)),
+            Some(3)
+        );
+    }
+}
@@ -0,0 +1,298 @@
+use super::{EhtMcsMap, Field, IeError, InformationElement};
+use crate::ChannelWidth;
+use bitvec::prelude::*;
+use std::fmt::Display;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThreeTwentyMhzChannelization {
+    ThreeTwentyOne,
+    ThreeTwentyTwo,
+}
+
+impl Display for ThreeTwentyMhzChannelization {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ThreeTwentyMhzChannelization::ThreeTwentyOne => write!(f, "320-1"),
+            ThreeTwentyMhzChannelization::ThreeTwentyTwo => write!(f, "320-2"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EhtOperationInformation {
+    bytes: Vec<u8>,
+}
+
+impl EhtOperationInformation {
+    pub fn channel_width(&self) -> ChannelWidth {
+        match self.bytes[0] & 0b111 {
+            1 => ChannelWidth::FortyMhz,
+            2 => ChannelWidth::EightyMhz,
+            3 => ChannelWidth::OneSixtyMhz,
+            4 => ChannelWidth::ThreeTwentyMhz,
+            _ => ChannelWidth::TwentyMhz,
+        }
+    }
+
+    pub fn channel_center_freq_segment_zero(&self) -> u8 {
+        self.bytes[1]
+    }
+
+    pub fn channel_center_freq_segment_one(&self) -> u8 {
+        self.bytes[2]
+    }
+
+    /// The center channel of the whole BSS bandwidth, which is in CCFS1 for 160 MHz and 320 MHz
+    /// BSSs and in CCFS0 otherwise.
+    pub fn center_channel(&self) -> u8 {
+        match self.channel_width() {
+            ChannelWidth::OneSixtyMhz | ChannelWidth::ThreeTwentyMhz => {
+                self.channel_center_freq_segment_one()
+            }
+            _ => self.channel_center_freq_segment_zero(),
+        }
+    }
+
+    /// Which of the two overlapping sets of 6 GHz 320 MHz channels the BSS uses, where 320-1
+    /// channels are centered on channels 31, 95 and 159 and 320-2 channels on 63, 127 and 191.
+    pub fn three_twenty_mhz_channelization(&self) -> Option<ThreeTwentyMhzChannelization> {
+        if self.channel_width() != ChannelWidth::ThreeTwentyMhz {
+            return None;
+        }
+
+        match self.channel_center_freq_segment_one() {
+            31 | 95 | 159 => Some(ThreeTwentyMhzChannelization::ThreeTwentyOne),
+            63 | 127 | 191 => Some(ThreeTwentyMhzChannelization::ThreeTwentyTwo),
+            _ => None,
+        }
+    }
+
+    /// The bitmap of punctured 20 MHz subchannels, where bit 0 is the lowest frequency subchannel.
+    pub fn disabled_subchannel_bitmap(&self) -> Option<u16> {
+        self.bytes
+            .get(3..5)
+            .map(|bitmap| u16::from_le_bytes([bitmap[0], bitmap[1]]))
+    }
+
+    /// The channel numbers of the punctured 20 MHz subchannels.
+    pub fn disabled_subchannels(&self) -> Vec<u8> {
+        let bitmap = match self.disabled_subchannel_bitmap() {
+            Some(bitmap) => bitmap,
+            None => return Vec::new(),
+        };
+
+        let subchannel_count = match self.channel_width() {
+            ChannelWidth::FortyMhz => 2,
+            ChannelWidth::EightyMhz => 4,
+            ChannelWidth::OneSixtyMhz => 8,
+            ChannelWidth::ThreeTwentyMhz => 16,
+            _ => 1,
+        };
+
+        // 20 MHz channel numbers are 4 apart and the center channel is halfway between the two
+        // middle subchannels
+        let lowest_subchannel = i16::from(self.center_channel()) - (subchannel_count - 1) * 2;
+
+        (0..subchannel_count)
+            .filter(|index| bitmap & (1 << index) != 0)
+            .map(|index| lowest_subchannel + index * 4)
+            .filter(|channel| (1..=i16::from(u8::MAX)).contains(channel))
+            .map(|channel| channel as u8)
+            .collect()
+    }
+
+    pub fn fields(&self) -> Vec<Field> {
+        let mut fields = vec![
+            Field::new("Channel Width", self.channel_width()),
+            Field::new(
+                "Channel Center Frequency Segment 0",
+                self.channel_center_freq_segment_zero(),
+            ),
+            Field::new(
+                "Channel Center Frequency Segment 1",
+                self.channel_center_freq_segment_one(),
+            ),
+        ];
+
+        if let Some(channelization) = self.three_twenty_mhz_channelization() {
+            fields.push(Field::new("320 MHz Channelization", channelization));
+        }
+
+        if let Some(bitmap) = self.disabled_subchannel_bitmap() {
+            fields.push(Field::with_subfields(
+                "Disabled Subchannel Bitmap",
+                format!("{:#018b}", bitmap),
+                self.disabled_subchannels()
+                    .iter()
+                    .map(|channel| Field::new("Disabled Subchannel", channel))
+                    .collect(),
+            ));
+        }
+
+        fields
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EhtOperation {
+    bits: BitVec<Lsb0, u8>,
+}
+
+impl EhtOperation {
+    pub const MIN_LENGTH: usize = 5;
+
+    const EHT_OPERATION_INFORMATION_OFFSET: usize = 5;
+
+    pub fn new(bytes: Vec<u8>) -> Result<EhtOperation, IeError> {
+        if bytes.len() >= Self::MIN_LENGTH {
+            Ok(EhtOperation {
+                bits: BitVec::from_vec(bytes),
+            })
+        } else {
+            Err(IeError::InvalidLength {
+                ie_name: Self::NAME,
+                expected_length: Self::MIN_LENGTH,
+                actual_length: bytes.len(),
+            })
+        }
+    }
+
+    // EHT Operation Parameters
+
+    pub fn eht_operation_information_present(&self) -> bool {
+        self.bits[0]
+    }
+
+    pub fn disabled_subchannel_bitmap_present(&self) -> bool {
+        self.bits[1]
+    }
+
+    pub fn eht_default_pe_duration(&self) -> bool {
+        self.bits[2]
+    }
+
+    pub fn group_addressed_bu_indication_limit(&self) -> bool {
+        self.bits[3]
+    }
+
+    pub fn group_addressed_bu_indication_exponent(&self) -> u8 {
+        self.bits[4..=5].load()
+    }
+
+    // Basic EHT-MCS And NSS Set
+
+    pub fn basic_eht_mcs_and_nss_set(&self) -> EhtMcsMap {
+        EhtMcsMap::new(&self.bits.as_raw_slice()[1..=4])
+    }
+
+    // EHT Operation Information
+
+    pub fn eht_operation_information(&self) -> Option<EhtOperationInformation> {
+        if !self.eht_operation_information_present() {
+            return None;
+        }
+
+        let length = if self.disabled_subchannel_bitmap_present() {
+            5
+        } else {
+            3
+        };
+
+        let offset = Self::EHT_OPERATION_INFORMATION_OFFSET;
+        self.bits
+            .as_raw_slice()
+            .get(offset..offset + length)
+            .map(|bytes| EhtOperationInformation {
+                bytes: bytes.to_vec(),
+            })
+    }
+}
+
+impl InformationElement for EhtOperation {
+    const NAME: &'static str = "EHT Operation";
+    const ID: u8 = 255;
+    const ID_EXT: Option<u8> = Some(106);
+
+    fn bytes(&self) -> &[u8] {
+        self.bits.as_raw_slice()
+    }
+
+    fn information_fields(&self) -> Vec<Field> {
+        let basic_eht_mcs_and_nss_set = self.basic_eht_mcs_and_nss_set();
+
+        let mut information_fields = vec![
+            Field::with_subfields(
+                "EHT Operation Parameters",
+                format!("{:02?}", self.bits.as_raw_slice()[0]),
+                vec![
+                    Field::new(
+                        "EHT Operation Information Present",
+                        self.eht_operation_information_present(),
+                    ),
+                    Field::new(
+                        "Disabled Subchannel Bitmap Present",
+                        self.disabled_subchannel_bitmap_present(),
+                    ),
+                    Field::new("EHT Default PE Duration", self.eht_default_pe_duration()),
+                    Field::new(
+                        "Group Addressed BU Indication Limit",
+                        self.group_addressed_bu_indication_limit(),
+                    ),
+                    Field::new(
+                        "Group Addressed BU Indication Exponent",
+                        self.group_addressed_bu_indication_exponent(),
+                    ),
+                ],
+            ),
+            Field::with_subfields(
+                "Basic EHT-MCS and NSS Set",
+                basic_eht_mcs_and_nss_set,
+                basic_eht_mcs_and_nss_set.fields(),
+            ),
+        ];
+
+        if let Some(eht_operation_information) = self.eht_operation_information() {
+            information_fields.push(Field::with_subfields(
+                "EHT Operation Information",
+                format!("{:02?}", eht_operation_information.bytes),
+                eht_operation_information.fields(),
+            ));
+        }
+
+        information_fields
+    }
+}
+
+impl_display_for_ie!(EhtOperation);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ies::{self, Ie};
+
+    #[test]
+    fn eht_operation_punctured_three_twenty_mhz() {
+        let ies = ies::from_bytes(&[
+            0xFF, 0x0B, 0x6A, 0x03, 0x44, 0x44, 0x44, 0x44, 0x04, 0x2F, 0x3F, 0x04, 0x00,
+        ])
+        .unwrap();
+
+        let eht_operation_information = match &ies[0] {
+            Ie::EhtOperation(eht_operation) => eht_operation.eht_operation_information().unwrap(),
+            ie => panic!("Expected EHT Operation, got {}", ie.name()),
+        };
+        assert_eq!(
+            eht_operation_information.channel_width(),
+            ChannelWidth::ThreeTwentyMhz
+        );
+        assert_eq!(
+            eht_operation_information.three_twenty_mhz_channelization(),
+            Some(ThreeTwentyMhzChannelization::ThreeTwentyTwo)
+        );
+        assert_eq!(eht_operation_information.disabled_subchannels(), vec![41]);
+        assert_eq!(
+            ChannelWidth::from(ies.as_slice()),
+            ChannelWidth::ThreeTwentyMhz
+        );
+    }
+}
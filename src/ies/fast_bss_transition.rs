@@ -0,0 +1,190 @@
+use super::{Field, IeError, InformationElement};
+use macaddr::MacAddr6;
+
+// Subelement IDs
+const R1KH_ID: u8 = 1;
+const GTK: u8 = 2;
+const R0KH_ID: u8 = 3;
+const IGTK: u8 = 4;
+const OCI: u8 = 5;
+const BIGTK: u8 = 6;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FastBssTransition {
+    bytes: Vec<u8>,
+}
+
+impl FastBssTransition {
+    /// The length with a 16-octet MIC, which is the shortest MIC.
+    pub const MIN_LENGTH: usize = 82;
+
+    const NONCE_LENGTH: usize = 32;
+
+    pub fn new(bytes: Vec<u8>) -> Result<FastBssTransition, IeError> {
+        let fast_bss_transition = FastBssTransition { bytes };
+        let expected_length = fast_bss_transition.subelements_offset();
+
+        if fast_bss_transition.bytes.len() >= Self::MIN_LENGTH
+            && fast_bss_transition.bytes.len() >= expected_length
+        {
+            Ok(fast_bss_transition)
+        } else {
+            Err(IeError::InvalidLength {
+                ie_name: Self::NAME,
+                expected_length,
+                actual_length: fast_bss_transition.bytes.len(),
+            })
+        }
+    }
+
+    // MIC Control
+
+    pub fn rsnxe_used(&self) -> bool {
+        self.bytes[0] & 0b1 != 0
+    }
+
+    /// The length of the MIC in octets, which is 24 or 32 for AKMs that use SHA-384 and 16
+    /// otherwise.
+    pub fn mic_length(&self) -> usize {
+        match self.bytes.first().map(|byte| (byte >> 1) & 0b111) {
+            Some(1) => 24,
+            Some(2) => 32,
+            _ => 16,
+        }
+    }
+
+    /// The number of elements protected by the MIC.
+    pub fn element_count(&self) -> u8 {
+        self.bytes[1]
+    }
+
+    fn anonce_offset(&self) -> usize {
+        2 + self.mic_length()
+    }
+
+    fn subelements_offset(&self) -> usize {
+        self.anonce_offset() + Self::NONCE_LENGTH * 2
+    }
+
+    pub fn mic(&self) -> &[u8] {
+        &self.bytes[2..self.anonce_offset()]
+    }
+
+    pub fn anonce(&self) -> &[u8] {
+        let offset = self.anonce_offset();
+        &self.bytes[offset..offset + Self::NONCE_LENGTH]
+    }
+
+    pub fn snonce(&self) -> &[u8] {
+        let offset = self.anonce_offset() + Self::NONCE_LENGTH;
+        &self.bytes[offset..offset + Self::NONCE_LENGTH]
+    }
+
+    /// The optional parameters as subelement ID and data pairs.
+    pub fn subelements(&self) -> Vec<(u8, &[u8])> {
+        let mut subelements = Vec::new();
+        let mut remaining = &self.bytes[self.subelements_offset()..];
+
+        while let [id, length, rest @ ..] = remaining {
+            let data = match rest.get(..*length as usize) {
+                Some(data) => data,
+                None => break,
+            };
+            subelements.push((*id, data));
+            remaining = &rest[data.len()..];
+        }
+
+        subelements
+    }
+
+    fn subelement(&self, subelement_id: u8) -> Option<&[u8]> {
+        self.subelements()
+            .into_iter()
+            .find(|(id, _)| *id == subelement_id)
+            .map(|(_, data)| data)
+    }
+
+    /// The MAC address of the R1 key holder, which is the AP itself.
+    pub fn r1kh_id(&self) -> Option<MacAddr6> {
+        self.subelement(R1KH_ID)
+            .filter(|data| data.len() == 6)
+            .map(|data| MacAddr6::new(data[0], data[1], data[2], data[3], data[4], data[5]))
+    }
+
+    /// The identifier of the R0 key holder, which is often the NAS identifier of the controller.
+    pub fn r0kh_id(&self) -> Option<&[u8]> {
+        self.subelement(R0KH_ID)
+    }
+}
+
+impl InformationElement for FastBssTransition {
+    const NAME: &'static str = "Fast BSS Transition";
+    const ID: u8 = 55;
+
+    fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    fn information_fields(&self) -> Vec<Field> {
+        let mut information_fields = vec![
+            Field::with_subfields(
+                "MIC Control",
+                format!("{:02?}", &self.bytes[0..2]),
+                vec![
+                    Field::new("RSNXE Used", self.rsnxe_used()),
+                    Field::new("MIC Length", format!("{} octets", self.mic_length())),
+                    Field::new("Element Count", self.element_count()),
+                ],
+            ),
+            Field::new("MIC", format!("{:02X?}", self.mic())),
+            Field::new("ANonce", format!("{:02X?}", self.anonce())),
+            Field::new("SNonce", format!("{:02X?}", self.snonce())),
+        ];
+
+        for (id, data) in self.subelements() {
+            let field = match id {
+                R1KH_ID => match self.r1kh_id() {
+                    Some(r1kh_id) => Field::new("R1KH-ID", r1kh_id),
+                    None => Field::new("R1KH-ID", format!("{:02X?}", data)),
+                },
+                R0KH_ID => Field::new("R0KH-ID", String::from_utf8_lossy(data)),
+                GTK => Field::new("GTK", format!("{:02X?}", data)),
+                IGTK => Field::new("IGTK", format!("{:02X?}", data)),
+                OCI => Field::new("OCI", format!("{:02X?}", data)),
+                BIGTK => Field::new("BIGTK", format!("{:02X?}", data)),
+                id => Field::new(format!("Subelement {}", id), format!("{:02X?}", data)),
+            };
+            information_fields.push(field);
+        }
+
+        information_fields
+    }
+}
+
+impl_display_for_ie!(FastBssTransition);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fast_bss_transition_key_holder_ids() {
+        let mut bytes = vec![0x00, 0x03];
+        bytes.extend([0xAA; 16]);
+        bytes.extend([0x11; 32]);
+        bytes.extend([0x22; 32]);
+        bytes.extend([0x01, 0x06, 0x02, 0x11, 0x22, 0x33, 0x44, 0x55]);
+        bytes.extend([0x03, 0x04]);
+        bytes.extend(b"nas1");
+
+        let fast_bss_transition = FastBssTransition::new(bytes).unwrap();
+        assert_eq!(fast_bss_transition.element_count(), 3);
+        assert_eq!(fast_bss_transition.mic(), &[0xAA; 16]);
+        assert_eq!(fast_bss_transition.snonce(), &[0x22; 32]);
+        assert_eq!(
+            fast_bss_transition.r1kh_id(),
+            Some(MacAddr6::new(0x02, 0x11, 0x22, 0x33, 0x44, 0x55))
+        );
+        assert_eq!(fast_bss_transition.r0kh_id(), Some(&b"nas1"[..]));
+    }
+}
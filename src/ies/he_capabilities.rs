@@ -0,0 +1,1148 @@
+use super::{ht_capabilities::McsFeedback, Field, IeError, InformationElement};
+use bitvec::prelude::*;
+use num_enum::TryFromPrimitive;
+use std::{convert::TryFrom, fmt::Display};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
+#[repr(u8)]
+pub enum HeMcsSupport {
+    ZeroToSeven,
+    ZeroToNine,
+    ZeroToEleven,
+    NotSupported,
+}
+
+impl HeMcsSupport {
+    pub fn max_mcs(&self) -> Option<u8> {
+        match self {
+            HeMcsSupport::ZeroToSeven => Some(7),
+            HeMcsSupport::ZeroToNine => Some(9),
+            HeMcsSupport::ZeroToEleven => Some(11),
+            HeMcsSupport::NotSupported => None,
+        }
+    }
+}
+
+impl Display for HeMcsSupport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HeMcsSupport::ZeroToSeven => write!(f, "HE-MCS 0-7"),
+            HeMcsSupport::ZeroToNine => write!(f, "HE-MCS 0-9"),
+            HeMcsSupport::ZeroToEleven => write!(f, "HE-MCS 0-11"),
+            HeMcsSupport::NotSupported => write!(f, "Not Supported"),
+        }
+    }
+}
+
+/// A 16-bit HE-MCS map with the max HE-MCS for 1 to 8 spatial streams.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HeMcsMap {
+    map: u16,
+}
+
+impl HeMcsMap {
+    pub fn new(map: [u8; 2]) -> HeMcsMap {
+        HeMcsMap {
+            map: u16::from_le_bytes(map),
+        }
+    }
+
+    pub fn mcs_support(&self, nss: u8) -> HeMcsSupport {
+        match nss {
+            1..=8 => HeMcsSupport::try_from(((self.map >> ((nss - 1) * 2)) & 0b11) as u8)
+                .unwrap_or(HeMcsSupport::NotSupported),
+            _ => HeMcsSupport::NotSupported,
+        }
+    }
+
+    pub fn max_nss(&self) -> u8 {
+        (1..=8)
+            .rev()
+            .find(|nss| self.mcs_support(*nss) != HeMcsSupport::NotSupported)
+            .unwrap_or(0)
+    }
+
+    pub fn fields(&self) -> Vec<Field> {
+        (1..=8)
+            .map(|nss| Field::new(format!("{} SS", nss), self.mcs_support(nss)))
+            .collect()
+    }
+}
+
+impl Display for HeMcsMap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:#06x}", self.map)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
+#[repr(u8)]
+pub enum DcmConstellation {
+    NotSupported,
+    Bpsk,
+    Qpsk,
+    SixteenQam,
+}
+
+impl Display for DcmConstellation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DcmConstellation::NotSupported => write!(f, "Not Supported"),
+            DcmConstellation::Bpsk => write!(f, "BPSK"),
+            DcmConstellation::Qpsk => write!(f, "QPSK"),
+            DcmConstellation::SixteenQam => write!(f, "16-QAM"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
+#[repr(u8)]
+pub enum DcmMaxRu {
+    TwoFortyTwoTone,
+    FourEightyFourTone,
+    NineNinetySixTone,
+    TwoByNineNinetySixTone,
+}
+
+impl Display for DcmMaxRu {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DcmMaxRu::TwoFortyTwoTone => write!(f, "242-tone RU"),
+            DcmMaxRu::FourEightyFourTone => write!(f, "484-tone RU"),
+            DcmMaxRu::NineNinetySixTone => write!(f, "996-tone RU"),
+            DcmMaxRu::TwoByNineNinetySixTone => write!(f, "2x996-tone RU"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
+#[repr(u8)]
+pub enum PpeConstellation {
+    Bpsk,
+    Qpsk,
+    SixteenQam,
+    SixtyFourQam,
+    TwoFiftySixQam,
+    TenTwentyFourQam,
+    None = 7,
+}
+
+impl Display for PpeConstellation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PpeConstellation::Bpsk => write!(f, "BPSK"),
+            PpeConstellation::Qpsk => write!(f, "QPSK"),
+            PpeConstellation::SixteenQam => write!(f, "16-QAM"),
+            PpeConstellation::SixtyFourQam => write!(f, "64-QAM"),
+            PpeConstellation::TwoFiftySixQam => write!(f, "256-QAM"),
+            PpeConstellation::TenTwentyFourQam => write!(f, "1024-QAM"),
+            PpeConstellation::None => write!(f, "None"),
+        }
+    }
+}
+
+/// The PPET16 and PPET8 thresholds for one RU allocation size of one spatial stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PpeThreshold {
+    nss: u8,
+    ru_index: u8,
+    ppet16: PpeConstellation,
+    ppet8: PpeConstellation,
+}
+
+impl PpeThreshold {
+    pub fn nss(&self) -> u8 {
+        self.nss
+    }
+
+    pub fn ru_size_tones(&self) -> u16 {
+        match self.ru_index {
+            0 => 242,
+            1 => 484,
+            2 => 996,
+            3 => 1992,
+            _ => 3984,
+        }
+    }
+
+    /// The PPET16 threshold, which the EHT Capabilities element calls PPETmax.
+    pub fn ppet16(&self) -> PpeConstellation {
+        self.ppet16
+    }
+
+    pub fn ppet8(&self) -> PpeConstellation {
+        self.ppet8
+    }
+}
+
+impl Display for PpeThreshold {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "PPET16: {}, PPET8: {}", self.ppet16, self.ppet8)
+    }
+}
+
+// Reads the PPE Thresholds field of the HE and EHT Capabilities elements, which differ only in the
+// widths of their NSS and RU Index Bitmask subfields
+pub(super) fn read_ppe_thresholds(
+    ppe_bits: &BitSlice<Lsb0, u8>,
+    nss_width: usize,
+    ru_index_bitmask_width: usize,
+) -> Vec<PpeThreshold> {
+    let header_width = nss_width + ru_index_bitmask_width;
+    if ppe_bits.len() < header_width {
+        return Vec::new();
+    }
+
+    let nss_count = ppe_bits[0..nss_width].load::<u8>() + 1;
+    let ru_index_bitmask = ppe_bits[nss_width..header_width].load::<u8>();
+
+    let mut thresholds = Vec::new();
+    let mut position = header_width;
+    for nss in 1..=nss_count {
+        for ru_index in 0..ru_index_bitmask_width as u8 {
+            if ru_index_bitmask & (1 << ru_index) == 0 {
+                continue;
+            }
+
+            let (ppet16, ppet8) = match ppe_bits.get(position..position + 6) {
+                Some(ppet_bits) => (ppet_bits[0..=2].load::<u8>(), ppet_bits[3..=5].load::<u8>()),
+                None => return thresholds,
+            };
+            position += 6;
+
+            thresholds.push(PpeThreshold {
+                nss,
+                ru_index,
+                ppet16: PpeConstellation::try_from(ppet16).unwrap_or(PpeConstellation::None),
+                ppet8: PpeConstellation::try_from(ppet8).unwrap_or(PpeConstellation::None),
+            });
+        }
+    }
+
+    thresholds
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeCapabilities {
+    bits: BitVec<Lsb0, u8>,
+}
+
+impl HeCapabilities {
+    pub const MIN_LENGTH: usize = 21;
+
+    const PHY_OFFSET: usize = 48;
+    const MCS_NSS_OFFSET: usize = 17;
+
+    pub fn new(bytes: Vec<u8>) -> Result<HeCapabilities, IeError> {
+        if bytes.len() >= Self::MIN_LENGTH {
+            Ok(HeCapabilities {
+                bits: BitVec::from_vec(bytes),
+            })
+        } else {
+            Err(IeError::InvalidLength {
+                ie_name: Self::NAME,
+                expected_length: Self::MIN_LENGTH,
+                actual_length: bytes.len(),
+            })
+        }
+    }
+
+    fn phy_bit(&self, index: usize) -> bool {
+        self.bits[Self::PHY_OFFSET + index]
+    }
+
+    fn phy_bits(&self, start: usize, end: usize) -> u8 {
+        self.bits[Self::PHY_OFFSET + start..=Self::PHY_OFFSET + end].load()
+    }
+
+    // HE MAC Capabilities Information
+
+    pub fn htc_he_support(&self) -> bool {
+        self.bits[0]
+    }
+
+    pub fn twt_requester_support(&self) -> bool {
+        self.bits[1]
+    }
+
+    pub fn twt_responder_support(&self) -> bool {
+        self.bits[2]
+    }
+
+    pub fn dynamic_fragmentation_support(&self) -> u8 {
+        self.bits[3..=4].load()
+    }
+
+    pub fn max_fragmented_msdus_exponent(&self) -> u8 {
+        self.bits[5..=7].load()
+    }
+
+    /// The minimum fragment size in octets, or None if there's no restriction.
+    pub fn min_fragment_size(&self) -> Option<u16> {
+        match self.bits[8..=9].load::<u8>() {
+            0 => None,
+            value => Some(64 << value),
+        }
+    }
+
+    pub fn trigger_frame_mac_padding_duration_us(&self) -> u8 {
+        self.bits[10..=11].load::<u8>() * 8
+    }
+
+    pub fn multi_tid_aggregation_rx_support(&self) -> u8 {
+        self.bits[12..=14].load::<u8>() + 1
+    }
+
+    pub fn he_link_adaptation_support(&self) -> McsFeedback {
+        McsFeedback::try_from(self.bits[15..=16].load::<u8>()).unwrap_or(McsFeedback::NoMfb)
+    }
+
+    pub fn all_ack_support(&self) -> bool {
+        self.bits[17]
+    }
+
+    pub fn trs_support(&self) -> bool {
+        self.bits[18]
+    }
+
+    pub fn bsr_support(&self) -> bool {
+        self.bits[19]
+    }
+
+    pub fn broadcast_twt_support(&self) -> bool {
+        self.bits[20]
+    }
+
+    pub fn thirty_two_bit_ba_bitmap_support(&self) -> bool {
+        self.bits[21]
+    }
+
+    pub fn mu_cascading_support(&self) -> bool {
+        self.bits[22]
+    }
+
+    pub fn ack_enabled_aggregation_support(&self) -> bool {
+        self.bits[23]
+    }
+
+    pub fn om_control_support(&self) -> bool {
+        self.bits[25]
+    }
+
+    pub fn ofdma_ra_support(&self) -> bool {
+        self.bits[26]
+    }
+
+    pub fn max_ampdu_length_exponent_extension(&self) -> u8 {
+        self.bits[27..=28].load()
+    }
+
+    pub fn amsdu_fragmentation_support(&self) -> bool {
+        self.bits[29]
+    }
+
+    pub fn flexible_twt_schedule_support(&self) -> bool {
+        self.bits[30]
+    }
+
+    pub fn rx_control_frame_to_multibss(&self) -> bool {
+        self.bits[31]
+    }
+
+    pub fn bsrp_bqrp_ampdu_aggregation(&self) -> bool {
+        self.bits[32]
+    }
+
+    pub fn qtp_support(&self) -> bool {
+        self.bits[33]
+    }
+
+    pub fn bqr_support(&self) -> bool {
+        self.bits[34]
+    }
+
+    pub fn psr_responder(&self) -> bool {
+        self.bits[35]
+    }
+
+    pub fn ndp_feedback_report_support(&self) -> bool {
+        self.bits[36]
+    }
+
+    pub fn ops_support(&self) -> bool {
+        self.bits[37]
+    }
+
+    pub fn amsdu_not_under_ba_in_ack_enabled_ampdu_support(&self) -> bool {
+        self.bits[38]
+    }
+
+    pub fn multi_tid_aggregation_tx_support(&self) -> u8 {
+        self.bits[39..=41].load::<u8>() + 1
+    }
+
+    pub fn he_subchannel_selective_transmission_support(&self) -> bool {
+        self.bits[42]
+    }
+
+    pub fn ul_two_by_nine_ninety_six_tone_ru_support(&self) -> bool {
+        self.bits[43]
+    }
+
+    pub fn om_control_ul_mu_data_disable_rx_support(&self) -> bool {
+        self.bits[44]
+    }
+
+    pub fn he_dynamic_sm_power_save(&self) -> bool {
+        self.bits[45]
+    }
+
+    pub fn punctured_sounding_support(&self) -> bool {
+        self.bits[46]
+    }
+
+    pub fn ht_and_vht_trigger_frame_rx_support(&self) -> bool {
+        self.bits[47]
+    }
+
+    // HE PHY Capabilities Information
+
+    pub fn forty_mhz_in_two_point_four_ghz(&self) -> bool {
+        self.phy_bit(1)
+    }
+
+    pub fn forty_and_eighty_mhz_in_five_ghz(&self) -> bool {
+        self.phy_bit(2)
+    }
+
+    pub fn one_sixty_mhz_in_five_ghz(&self) -> bool {
+        self.phy_bit(3)
+    }
+
+    pub fn eighty_plus_eighty_mhz_in_five_ghz(&self) -> bool {
+        self.phy_bit(4)
+    }
+
+    pub fn two_forty_two_tone_rus_in_two_point_four_ghz(&self) -> bool {
+        self.phy_bit(5)
+    }
+
+    pub fn two_forty_two_tone_rus_in_five_ghz(&self) -> bool {
+        self.phy_bit(6)
+    }
+
+    pub fn punctured_preamble_rx(&self) -> u8 {
+        self.phy_bits(8, 11)
+    }
+
+    pub fn device_class_a(&self) -> bool {
+        self.phy_bit(12)
+    }
+
+    pub fn ldpc_coding_in_payload(&self) -> bool {
+        self.phy_bit(13)
+    }
+
+    pub fn he_su_ppdu_with_1x_he_ltf_and_0_8_us_gi(&self) -> bool {
+        self.phy_bit(14)
+    }
+
+    pub fn midamble_tx_rx_max_nsts(&self) -> u8 {
+        self.phy_bits(15, 16) + 1
+    }
+
+    pub fn ndp_with_4x_he_ltf_and_3_2_us_gi(&self) -> bool {
+        self.phy_bit(17)
+    }
+
+    pub fn stbc_tx_less_than_or_equal_eighty_mhz(&self) -> bool {
+        self.phy_bit(18)
+    }
+
+    pub fn stbc_rx_less_than_or_equal_eighty_mhz(&self) -> bool {
+        self.phy_bit(19)
+    }
+
+    pub fn doppler_tx(&self) -> bool {
+        self.phy_bit(20)
+    }
+
+    pub fn doppler_rx(&self) -> bool {
+        self.phy_bit(21)
+    }
+
+    pub fn full_bandwidth_ul_mu_mimo(&self) -> bool {
+        self.phy_bit(22)
+    }
+
+    pub fn partial_bandwidth_ul_mu_mimo(&self) -> bool {
+        self.phy_bit(23)
+    }
+
+    pub fn dcm_max_constellation_tx(&self) -> DcmConstellation {
+        DcmConstellation::try_from(self.phy_bits(24, 25)).unwrap_or(DcmConstellation::NotSupported)
+    }
+
+    pub fn dcm_max_nss_tx(&self) -> u8 {
+        self.phy_bits(26, 26) + 1
+    }
+
+    pub fn dcm_max_constellation_rx(&self) -> DcmConstellation {
+        DcmConstellation::try_from(self.phy_bits(27, 28)).unwrap_or(DcmConstellation::NotSupported)
+    }
+
+    pub fn dcm_max_nss_rx(&self) -> u8 {
+        self.phy_bits(29, 29) + 1
+    }
+
+    pub fn rx_partial_bw_su_in_twenty_mhz_he_mu_ppdu(&self) -> bool {
+        self.phy_bit(30)
+    }
+
+    pub fn su_beamformer(&self) -> bool {
+        self.phy_bit(31)
+    }
+
+    pub fn su_beamformee(&self) -> bool {
+        self.phy_bit(32)
+    }
+
+    pub fn mu_beamformer(&self) -> bool {
+        self.phy_bit(33)
+    }
+
+    pub fn beamformee_sts_less_than_or_equal_eighty_mhz(&self) -> u8 {
+        self.phy_bits(34, 36) + 1
+    }
+
+    pub fn beamformee_sts_greater_than_eighty_mhz(&self) -> u8 {
+        self.phy_bits(37, 39) + 1
+    }
+
+    pub fn number_of_sounding_dimensions_less_than_or_equal_eighty_mhz(&self) -> u8 {
+        self.phy_bits(40, 42) + 1
+    }
+
+    pub fn number_of_sounding_dimensions_greater_than_eighty_mhz(&self) -> u8 {
+        self.phy_bits(43, 45) + 1
+    }
+
+    pub fn ng_sixteen_su_feedback(&self) -> bool {
+        self.phy_bit(46)
+    }
+
+    pub fn ng_sixteen_mu_feedback(&self) -> bool {
+        self.phy_bit(47)
+    }
+
+    pub fn codebook_size_su_feedback(&self) -> bool {
+        self.phy_bit(48)
+    }
+
+    pub fn codebook_size_mu_feedback(&self) -> bool {
+        self.phy_bit(49)
+    }
+
+    pub fn triggered_su_beamforming_feedback(&self) -> bool {
+        self.phy_bit(50)
+    }
+
+    pub fn triggered_mu_beamforming_partial_bw_feedback(&self) -> bool {
+        self.phy_bit(51)
+    }
+
+    pub fn triggered_cqi_feedback(&self) -> bool {
+        self.phy_bit(52)
+    }
+
+    pub fn partial_bandwidth_extended_range(&self) -> bool {
+        self.phy_bit(53)
+    }
+
+    pub fn partial_bandwidth_dl_mu_mimo(&self) -> bool {
+        self.phy_bit(54)
+    }
+
+    pub fn ppe_thresholds_present(&self) -> bool {
+        self.phy_bit(55)
+    }
+
+    pub fn psr_based_sr_support(&self) -> bool {
+        self.phy_bit(56)
+    }
+
+    pub fn power_boost_factor_support(&self) -> bool {
+        self.phy_bit(57)
+    }
+
+    pub fn he_su_and_mu_ppdu_with_4x_he_ltf_and_0_8_us_gi(&self) -> bool {
+        self.phy_bit(58)
+    }
+
+    pub fn max_nc(&self) -> u8 {
+        self.phy_bits(59, 61) + 1
+    }
+
+    pub fn stbc_tx_greater_than_eighty_mhz(&self) -> bool {
+        self.phy_bit(62)
+    }
+
+    pub fn stbc_rx_greater_than_eighty_mhz(&self) -> bool {
+        self.phy_bit(63)
+    }
+
+    pub fn he_er_su_ppdu_with_4x_he_ltf_and_0_8_us_gi(&self) -> bool {
+        self.phy_bit(64)
+    }
+
+    pub fn twenty_mhz_in_forty_mhz_he_ppdu_in_two_point_four_ghz(&self) -> bool {
+        self.phy_bit(65)
+    }
+
+    pub fn twenty_mhz_in_one_sixty_mhz_he_ppdu(&self) -> bool {
+        self.phy_bit(66)
+    }
+
+    pub fn eighty_mhz_in_one_sixty_mhz_he_ppdu(&self) -> bool {
+        self.phy_bit(67)
+    }
+
+    pub fn he_er_su_ppdu_with_1x_he_ltf_and_0_8_us_gi(&self) -> bool {
+        self.phy_bit(68)
+    }
+
+    pub fn midamble_tx_rx_2x_and_1x_he_ltf(&self) -> bool {
+        self.phy_bit(69)
+    }
+
+    pub fn dcm_max_ru(&self) -> DcmMaxRu {
+        DcmMaxRu::try_from(self.phy_bits(70, 71)).unwrap_or(DcmMaxRu::TwoFortyTwoTone)
+    }
+
+    pub fn longer_than_sixteen_he_sig_b_ofdm_symbols_support(&self) -> bool {
+        self.phy_bit(72)
+    }
+
+    pub fn non_triggered_cqi_feedback(&self) -> bool {
+        self.phy_bit(73)
+    }
+
+    pub fn tx_1024_qam_support_less_than_242_tone_ru(&self) -> bool {
+        self.phy_bit(74)
+    }
+
+    pub fn rx_1024_qam_support_less_than_242_tone_ru(&self) -> bool {
+        self.phy_bit(75)
+    }
+
+    pub fn rx_full_bw_su_using_he_mu_ppdu_with_compressed_he_sig_b(&self) -> bool {
+        self.phy_bit(76)
+    }
+
+    pub fn rx_full_bw_su_using_he_mu_ppdu_with_non_compressed_he_sig_b(&self) -> bool {
+        self.phy_bit(77)
+    }
+
+    pub fn nominal_packet_padding_us(&self) -> u8 {
+        match self.phy_bits(78, 79) {
+            0 => 0,
+            1 => 8,
+            2 => 16,
+            _ => 20,
+        }
+    }
+
+    pub fn he_mu_ppdu_with_more_than_one_ru_rx_max_n_he_ltf(&self) -> bool {
+        self.phy_bit(80)
+    }
+
+    // Supported HE-MCS And NSS Set
+
+    fn mcs_map(&self, index: usize) -> Option<HeMcsMap> {
+        let offset = Self::MCS_NSS_OFFSET + index * 2;
+        self.bits
+            .as_raw_slice()
+            .get(offset..offset + 2)
+            .map(|map| HeMcsMap::new([map[0], map[1]]))
+    }
+
+    fn mcs_nss_set_length(&self) -> usize {
+        let mut length = 4;
+        if self.one_sixty_mhz_in_five_ghz() {
+            length += 4;
+        }
+        if self.eighty_plus_eighty_mhz_in_five_ghz() {
+            length += 4;
+        }
+        length
+    }
+
+    pub fn rx_he_mcs_map_less_than_or_equal_eighty_mhz(&self) -> HeMcsMap {
+        self.mcs_map(0).unwrap_or_else(|| HeMcsMap::new([0xFF; 2]))
+    }
+
+    pub fn tx_he_mcs_map_less_than_or_equal_eighty_mhz(&self) -> HeMcsMap {
+        self.mcs_map(1).unwrap_or_else(|| HeMcsMap::new([0xFF; 2]))
+    }
+
+    pub fn rx_he_mcs_map_one_sixty_mhz(&self) -> Option<HeMcsMap> {
+        if self.one_sixty_mhz_in_five_ghz() {
+            self.mcs_map(2)
+        } else {
+            None
+        }
+    }
+
+    pub fn tx_he_mcs_map_one_sixty_mhz(&self) -> Option<HeMcsMap> {
+        if self.one_sixty_mhz_in_five_ghz() {
+            self.mcs_map(3)
+        } else {
+            None
+        }
+    }
+
+    pub fn rx_he_mcs_map_eighty_plus_eighty_mhz(&self) -> Option<HeMcsMap> {
+        if !self.eighty_plus_eighty_mhz_in_five_ghz() {
+            return None;
+        }
+
+        if self.one_sixty_mhz_in_five_ghz() {
+            self.mcs_map(4)
+        } else {
+            self.mcs_map(2)
+        }
+    }
+
+    pub fn tx_he_mcs_map_eighty_plus_eighty_mhz(&self) -> Option<HeMcsMap> {
+        if !self.eighty_plus_eighty_mhz_in_five_ghz() {
+            return None;
+        }
+
+        if self.one_sixty_mhz_in_five_ghz() {
+            self.mcs_map(5)
+        } else {
+            self.mcs_map(3)
+        }
+    }
+
+    // PPE Thresholds
+
+    pub fn ppe_thresholds(&self) -> Vec<PpeThreshold> {
+        if !self.ppe_thresholds_present() {
+            return Vec::new();
+        }
+
+        let offset = (Self::MCS_NSS_OFFSET + self.mcs_nss_set_length()) * 8;
+        let ppe_bits = match self.bits.get(offset..) {
+            Some(ppe_bits) => ppe_bits,
+            None => return Vec::new(),
+        };
+
+        read_ppe_thresholds(ppe_bits, 3, 4)
+    }
+}
+
+impl InformationElement for HeCapabilities {
+    const NAME: &'static str = "HE Capabilities";
+    const ID: u8 = 255;
+    const ID_EXT: Option<u8> = Some(35);
+
+    fn bytes(&self) -> &[u8] {
+        self.bits.as_raw_slice()
+    }
+
+    fn information_fields(&self) -> Vec<Field> {
+        let mut information_fields = vec![
+            Field::with_subfields(
+                "HE MAC Capabilities Information",
+                format!("{:02?}", &self.bits.as_raw_slice()[0..=5]),
+                vec![
+                    Field::new("+HTC-HE Support", self.htc_he_support()),
+                    Field::new("TWT Requester Support", self.twt_requester_support()),
+                    Field::new("TWT Responder Support", self.twt_responder_support()),
+                    Field::new(
+                        "Dynamic Fragmentation Support",
+                        self.dynamic_fragmentation_support(),
+                    ),
+                    Field::new(
+                        "Maximum Number of Fragmented MSDUs/A-MSDUs Exponent",
+                        self.max_fragmented_msdus_exponent(),
+                    ),
+                    Field::new(
+                        "Minimum Fragment Size",
+                        match self.min_fragment_size() {
+                            Some(size) => format!("{} octets", size),
+                            None => "No Restriction".to_string(),
+                        },
+                    ),
+                    Field::new(
+                        "Trigger Frame MAC Padding Duration",
+                        format!("{} μs", self.trigger_frame_mac_padding_duration_us()),
+                    ),
+                    Field::new(
+                        "Multi-TID Aggregation Rx Support",
+                        self.multi_tid_aggregation_rx_support(),
+                    ),
+                    Field::new(
+                        "HE Link Adaptation Support",
+                        self.he_link_adaptation_support(),
+                    ),
+                    Field::new("All Ack Support", self.all_ack_support()),
+                    Field::new("TRS Support", self.trs_support()),
+                    Field::new("BSR Support", self.bsr_support()),
+                    Field::new("Broadcast TWT Support", self.broadcast_twt_support()),
+                    Field::new(
+                        "32-bit BA Bitmap Support",
+                        self.thirty_two_bit_ba_bitmap_support(),
+                    ),
+                    Field::new("MU Cascading Support", self.mu_cascading_support()),
+                    Field::new(
+                        "Ack-Enabled Aggregation Support",
+                        self.ack_enabled_aggregation_support(),
+                    ),
+                    Field::new("OM Control Support", self.om_control_support()),
+                    Field::new("OFDMA RA Support", self.ofdma_ra_support()),
+                    Field::new(
+                        "Maximum A-MPDU Length Exponent Extension",
+                        self.max_ampdu_length_exponent_extension(),
+                    ),
+                    Field::new(
+                        "A-MSDU Fragmentation Support",
+                        self.amsdu_fragmentation_support(),
+                    ),
+                    Field::new(
+                        "Flexible TWT Schedule Support",
+                        self.flexible_twt_schedule_support(),
+                    ),
+                    Field::new(
+                        "Rx Control Frame to MultiBSS",
+                        self.rx_control_frame_to_multibss(),
+                    ),
+                    Field::new(
+                        "BSRP BQRP A-MPDU Aggregation",
+                        self.bsrp_bqrp_ampdu_aggregation(),
+                    ),
+                    Field::new("QTP Support", self.qtp_support()),
+                    Field::new("BQR Support", self.bqr_support()),
+                    Field::new("PSR Responder", self.psr_responder()),
+                    Field::new(
+                        "NDP Feedback Report Support",
+                        self.ndp_feedback_report_support(),
+                    ),
+                    Field::new("OPS Support", self.ops_support()),
+                    Field::new(
+                        "A-MSDU Not Under BA in Ack-Enabled A-MPDU Support",
+                        self.amsdu_not_under_ba_in_ack_enabled_ampdu_support(),
+                    ),
+                    Field::new(
+                        "Multi-TID Aggregation Tx Support",
+                        self.multi_tid_aggregation_tx_support(),
+                    ),
+                    Field::new(
+                        "HE Subchannel Selective Transmission Support",
+                        self.he_subchannel_selective_transmission_support(),
+                    ),
+                    Field::new(
+                        "UL 2x996-tone RU Support",
+                        self.ul_two_by_nine_ninety_six_tone_ru_support(),
+                    ),
+                    Field::new(
+                        "OM Control UL MU Data Disable Rx Support",
+                        self.om_control_ul_mu_data_disable_rx_support(),
+                    ),
+                    Field::new("HE Dynamic SM Power Save", self.he_dynamic_sm_power_save()),
+                    Field::new(
+                        "Punctured Sounding Support",
+                        self.punctured_sounding_support(),
+                    ),
+                    Field::new(
+                        "HT and VHT Trigger Frame Rx Support",
+                        self.ht_and_vht_trigger_frame_rx_support(),
+                    ),
+                ],
+            ),
+            Field::with_subfields(
+                "HE PHY Capabilities Information",
+                format!("{:02?}", &self.bits.as_raw_slice()[6..=16]),
+                vec![
+                    Field::new("40 MHz in 2.4 GHz", self.forty_mhz_in_two_point_four_ghz()),
+                    Field::new(
+                        "40 and 80 MHz in 5 GHz/6 GHz",
+                        self.forty_and_eighty_mhz_in_five_ghz(),
+                    ),
+                    Field::new("160 MHz in 5 GHz/6 GHz", self.one_sixty_mhz_in_five_ghz()),
+                    Field::new(
+                        "160/80+80 MHz in 5 GHz/6 GHz",
+                        self.eighty_plus_eighty_mhz_in_five_ghz(),
+                    ),
+                    Field::new(
+                        "242-tone RUs in 2.4 GHz",
+                        self.two_forty_two_tone_rus_in_two_point_four_ghz(),
+                    ),
+                    Field::new(
+                        "242-tone RUs in 5 GHz/6 GHz",
+                        self.two_forty_two_tone_rus_in_five_ghz(),
+                    ),
+                    Field::new(
+                        "Punctured Preamble Rx",
+                        format!("{:#06b}", self.punctured_preamble_rx()),
+                    ),
+                    Field::new(
+                        "Device Class",
+                        if self.device_class_a() { "A" } else { "B" },
+                    ),
+                    Field::new("LDPC Coding in Payload", self.ldpc_coding_in_payload()),
+                    Field::new(
+                        "HE SU PPDU with 1x HE-LTF and 0.8 μs GI",
+                        self.he_su_ppdu_with_1x_he_ltf_and_0_8_us_gi(),
+                    ),
+                    Field::new("Midamble Tx/Rx Max NSTS", self.midamble_tx_rx_max_nsts()),
+                    Field::new(
+                        "NDP with 4x HE-LTF and 3.2 μs GI",
+                        self.ndp_with_4x_he_ltf_and_3_2_us_gi(),
+                    ),
+                    Field::new(
+                        "STBC Tx ≤ 80 MHz",
+                        self.stbc_tx_less_than_or_equal_eighty_mhz(),
+                    ),
+                    Field::new(
+                        "STBC Rx ≤ 80 MHz",
+                        self.stbc_rx_less_than_or_equal_eighty_mhz(),
+                    ),
+                    Field::new("Doppler Tx", self.doppler_tx()),
+                    Field::new("Doppler Rx", self.doppler_rx()),
+                    Field::new(
+                        "Full Bandwidth UL MU-MIMO",
+                        self.full_bandwidth_ul_mu_mimo(),
+                    ),
+                    Field::new(
+                        "Partial Bandwidth UL MU-MIMO",
+                        self.partial_bandwidth_ul_mu_mimo(),
+                    ),
+                    Field::new("DCM Max Constellation Tx", self.dcm_max_constellation_tx()),
+                    Field::new("DCM Max NSS Tx", self.dcm_max_nss_tx()),
+                    Field::new("DCM Max Constellation Rx", self.dcm_max_constellation_rx()),
+                    Field::new("DCM Max NSS Rx", self.dcm_max_nss_rx()),
+                    Field::new(
+                        "Rx Partial BW SU in 20 MHz HE MU PPDU",
+                        self.rx_partial_bw_su_in_twenty_mhz_he_mu_ppdu(),
+                    ),
+                    Field::new("SU Beamformer", self.su_beamformer()),
+                    Field::new("SU Beamformee", self.su_beamformee()),
+                    Field::new("MU Beamformer", self.mu_beamformer()),
+                    Field::new(
+                        "Beamformee STS ≤ 80 MHz",
+                        self.beamformee_sts_less_than_or_equal_eighty_mhz(),
+                    ),
+                    Field::new(
+                        "Beamformee STS > 80 MHz",
+                        self.beamformee_sts_greater_than_eighty_mhz(),
+                    ),
+                    Field::new(
+                        "Number of Sounding Dimensions ≤ 80 MHz",
+                        self.number_of_sounding_dimensions_less_than_or_equal_eighty_mhz(),
+                    ),
+                    Field::new(
+                        "Number of Sounding Dimensions > 80 MHz",
+                        self.number_of_sounding_dimensions_greater_than_eighty_mhz(),
+                    ),
+                    Field::new("Ng = 16 SU Feedback", self.ng_sixteen_su_feedback()),
+                    Field::new("Ng = 16 MU Feedback", self.ng_sixteen_mu_feedback()),
+                    Field::new(
+                        "Codebook Size (φ, ψ) = {4, 2} SU Feedback",
+                        self.codebook_size_su_feedback(),
+                    ),
+                    Field::new(
+                        "Codebook Size (φ, ψ) = {7, 5} MU Feedback",
+                        self.codebook_size_mu_feedback(),
+                    ),
+                    Field::new(
+                        "Triggered SU Beamforming Feedback",
+                        self.triggered_su_beamforming_feedback(),
+                    ),
+                    Field::new(
+                        "Triggered MU Beamforming Partial BW Feedback",
+                        self.triggered_mu_beamforming_partial_bw_feedback(),
+                    ),
+                    Field::new("Triggered CQI Feedback", self.triggered_cqi_feedback()),
+                    Field::new(
+                        "Partial Bandwidth Extended Range",
+                        self.partial_bandwidth_extended_range(),
+                    ),
+                    Field::new(
+                        "Partial Bandwidth DL MU-MIMO",
+                        self.partial_bandwidth_dl_mu_mimo(),
+                    ),
+                    Field::new("PPE Thresholds Present", self.ppe_thresholds_present()),
+                    Field::new("PSR-Based SR Support", self.psr_based_sr_support()),
+                    Field::new(
+                        "Power Boost Factor Support",
+                        self.power_boost_factor_support(),
+                    ),
+                    Field::new(
+                        "HE SU PPDU and HE MU PPDU with 4x HE-LTF and 0.8 μs GI",
+                        self.he_su_and_mu_ppdu_with_4x_he_ltf_and_0_8_us_gi(),
+                    ),
+                    Field::new("Max Nc", self.max_nc()),
+                    Field::new("STBC Tx > 80 MHz", self.stbc_tx_greater_than_eighty_mhz()),
+                    Field::new("STBC Rx > 80 MHz", self.stbc_rx_greater_than_eighty_mhz()),
+                    Field::new(
+                        "HE ER SU PPDU with 4x HE-LTF and 0.8 μs GI",
+                        self.he_er_su_ppdu_with_4x_he_ltf_and_0_8_us_gi(),
+                    ),
+                    Field::new(
+                        "20 MHz in 40 MHz HE PPDU in 2.4 GHz",
+                        self.twenty_mhz_in_forty_mhz_he_ppdu_in_two_point_four_ghz(),
+                    ),
+                    Field::new(
+                        "20 MHz in 160/80+80 MHz HE PPDU",
+                        self.twenty_mhz_in_one_sixty_mhz_he_ppdu(),
+                    ),
+                    Field::new(
+                        "80 MHz in 160/80+80 MHz HE PPDU",
+                        self.eighty_mhz_in_one_sixty_mhz_he_ppdu(),
+                    ),
+                    Field::new(
+                        "HE ER SU PPDU with 1x HE-LTF and 0.8 μs GI",
+                        self.he_er_su_ppdu_with_1x_he_ltf_and_0_8_us_gi(),
+                    ),
+                    Field::new(
+                        "Midamble Tx/Rx 2x and 1x HE-LTF",
+                        self.midamble_tx_rx_2x_and_1x_he_ltf(),
+                    ),
+                    Field::new("DCM Max RU", self.dcm_max_ru()),
+                    Field::new(
+                        "Longer Than 16 HE SIG-B OFDM Symbols Support",
+                        self.longer_than_sixteen_he_sig_b_ofdm_symbols_support(),
+                    ),
+                    Field::new(
+                        "Non-Triggered CQI Feedback",
+                        self.non_triggered_cqi_feedback(),
+                    ),
+                    Field::new(
+                        "Tx 1024-QAM Support < 242-tone RU",
+                        self.tx_1024_qam_support_less_than_242_tone_ru(),
+                    ),
+                    Field::new(
+                        "Rx 1024-QAM Support < 242-tone RU",
+                        self.rx_1024_qam_support_less_than_242_tone_ru(),
+                    ),
+                    Field::new(
+                        "Rx Full BW SU Using HE MU PPDU with Compressed HE-SIG-B",
+                        self.rx_full_bw_su_using_he_mu_ppdu_with_compressed_he_sig_b(),
+                    ),
+                    Field::new(
+                        "Rx Full BW SU Using HE MU PPDU with Non-Compressed HE-SIG-B",
+                        self.rx_full_bw_su_using_he_mu_ppdu_with_non_compressed_he_sig_b(),
+                    ),
+                    Field::new(
+                        "Nominal Packet Padding",
+                        format!("{} μs", self.nominal_packet_padding_us()),
+                    ),
+                    Field::new(
+                        "HE MU PPDU with More Than One RU Rx Max N_HE-LTF",
+                        self.he_mu_ppdu_with_more_than_one_ru_rx_max_n_he_ltf(),
+                    ),
+                ],
+            ),
+        ];
+
+        let mcs_maps = vec![
+            (
+                "Rx HE-MCS Map ≤ 80 MHz",
+                Some(self.rx_he_mcs_map_less_than_or_equal_eighty_mhz()),
+            ),
+            (
+                "Tx HE-MCS Map ≤ 80 MHz",
+                Some(self.tx_he_mcs_map_less_than_or_equal_eighty_mhz()),
+            ),
+            ("Rx HE-MCS Map 160 MHz", self.rx_he_mcs_map_one_sixty_mhz()),
+            ("Tx HE-MCS Map 160 MHz", self.tx_he_mcs_map_one_sixty_mhz()),
+            (
+                "Rx HE-MCS Map 80+80 MHz",
+                self.rx_he_mcs_map_eighty_plus_eighty_mhz(),
+            ),
+            (
+                "Tx HE-MCS Map 80+80 MHz",
+                self.tx_he_mcs_map_eighty_plus_eighty_mhz(),
+            ),
+        ];
+        for (title, mcs_map) in mcs_maps {
+            if let Some(mcs_map) = mcs_map {
+                information_fields.push(Field::with_subfields(title, mcs_map, mcs_map.fields()));
+            }
+        }
+
+        let ppe_thresholds = self.ppe_thresholds();
+        if !ppe_thresholds.is_empty() {
+            information_fields.push(Field::with_subfields(
+                "PPE Thresholds",
+                ppe_thresholds.len(),
+                ppe_thresholds
+                    .iter()
+                    .map(|threshold| {
+                        Field::new(
+                            format!(
+                                "NSS {} {}-tone RU",
+                                threshold.nss(),
+                                threshold.ru_size_tones()
+                            ),
+                            threshold,
+                        )
+                    })
+                    .collect(),
+            ));
+        }
+
+        information_fields
+    }
+}
+
+impl_display_for_ie!(HeCapabilities);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn he_capabilities_ppe_thresholds() {
+        let he_capabilities = HeCapabilities::new(vec![
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x00,
+            0x00, 0x00, 0x00, 0xFA, 0xFF, 0xFA, 0xFF, 0x99, 0x9E, 0x53, 0x70,
+        ])
+        .unwrap();
+
+        assert!(he_capabilities.ppe_thresholds_present());
+        assert_eq!(he_capabilities.rx_he_mcs_map_one_sixty_mhz(), None);
+
+        // Two spatial streams with thresholds for the 242-tone and 484-tone RUs
+        let ppe_thresholds = he_capabilities.ppe_thresholds();
+        assert_eq!(
+            ppe_thresholds
+                .iter()
+                .map(|threshold| (
+                    threshold.nss(),
+                    threshold.ru_size_tones(),
+                    threshold.ppet16(),
+                    threshold.ppet8()
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    1,
+                    242,
+                    PpeConstellation::TenTwentyFourQam,
+                    PpeConstellation::None
+                ),
+                (
+                    1,
+                    484,
+                    PpeConstellation::TwoFiftySixQam,
+                    PpeConstellation::SixtyFourQam
+                ),
+                (2, 242, PpeConstellation::SixteenQam, PpeConstellation::Qpsk),
+                (2, 484, PpeConstellation::Bpsk, PpeConstellation::None),
+            ]
+        );
+    }
+}
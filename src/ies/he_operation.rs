@@ -0,0 +1,436 @@
+use super::{vht_operation::vht_channel_widths, Field, HeMcsMap, IeError, InformationElement};
+use crate::{ChannelWidth, ChannelWidths};
+use bitvec::prelude::*;
+use std::fmt::Display;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SixGhzRegulatoryInfo {
+    IndoorAccessPoint,
+    StandardPowerAccessPoint,
+    VeryLowPowerAccessPoint,
+    Reserved(u8),
+}
+
+impl From<u8> for SixGhzRegulatoryInfo {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => SixGhzRegulatoryInfo::IndoorAccessPoint,
+            1 => SixGhzRegulatoryInfo::StandardPowerAccessPoint,
+            2 => SixGhzRegulatoryInfo::VeryLowPowerAccessPoint,
+            value => SixGhzRegulatoryInfo::Reserved(value),
+        }
+    }
+}
+
+impl Display for SixGhzRegulatoryInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SixGhzRegulatoryInfo::IndoorAccessPoint => write!(f, "Indoor Access Point"),
+            SixGhzRegulatoryInfo::StandardPowerAccessPoint => {
+                write!(f, "Standard Power Access Point")
+            }
+            SixGhzRegulatoryInfo::VeryLowPowerAccessPoint => {
+                write!(f, "Very Low Power Access Point")
+            }
+            SixGhzRegulatoryInfo::Reserved(value) => write!(f, "Reserved ({})", value),
+        }
+    }
+}
+
+/// The VHT Operation Information an HE AP operating in the 5 GHz band includes when it doesn't
+/// include a VHT Operation element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VhtOperationInformation {
+    bytes: [u8; 3],
+}
+
+impl VhtOperationInformation {
+    pub fn channel_width(&self) -> ChannelWidths {
+        vht_channel_widths(self.bytes[0])
+    }
+
+    pub fn channel_center_freq_segment_zero(&self) -> u8 {
+        self.bytes[1]
+    }
+
+    pub fn channel_center_freq_segment_one(&self) -> u8 {
+        self.bytes[2]
+    }
+
+    pub fn fields(&self) -> Vec<Field> {
+        vec![
+            Field::new("Channel Width", self.channel_width()),
+            Field::new(
+                "Channel Center Frequency Segment 0",
+                self.channel_center_freq_segment_zero(),
+            ),
+            Field::new(
+                "Channel Center Frequency Segment 1",
+                self.channel_center_freq_segment_one(),
+            ),
+        ]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SixGhzOperationInformation {
+    bytes: [u8; 5],
+}
+
+impl SixGhzOperationInformation {
+    pub fn primary_channel(&self) -> u8 {
+        self.bytes[0]
+    }
+
+    /// The channel width of the BSS, where 160 MHz and 80+80 MHz share an encoding and are told
+    /// apart by the distance between the two channel center frequency segments.
+    pub fn channel_width(&self) -> ChannelWidth {
+        match self.bytes[1] & 0b11 {
+            0 => ChannelWidth::TwentyMhz,
+            1 => ChannelWidth::FortyMhz,
+            2 => ChannelWidth::EightyMhz,
+            _ => {
+                let segment_zero = self.channel_center_freq_segment_zero();
+                let segment_one = self.channel_center_freq_segment_one();
+                if segment_one.max(segment_zero) - segment_one.min(segment_zero) == 8 {
+                    ChannelWidth::OneSixtyMhz
+                } else {
+                    ChannelWidth::EightyPlusEightyMhz
+                }
+            }
+        }
+    }
+
+    pub fn duplicate_beacon(&self) -> bool {
+        self.bytes[1] & 0b100 != 0
+    }
+
+    pub fn regulatory_info(&self) -> SixGhzRegulatoryInfo {
+        SixGhzRegulatoryInfo::from((self.bytes[1] >> 3) & 0b111)
+    }
+
+    pub fn channel_center_freq_segment_zero(&self) -> u8 {
+        self.bytes[2]
+    }
+
+    pub fn channel_center_freq_segment_one(&self) -> u8 {
+        self.bytes[3]
+    }
+
+    /// The minimum rate in Mbps that a non-AP STA uses to transmit to the AP.
+    pub fn minimum_rate_mbps(&self) -> u8 {
+        self.bytes[4]
+    }
+
+    pub fn fields(&self) -> Vec<Field> {
+        vec![
+            Field::new("Primary Channel", self.primary_channel()),
+            Field::with_subfields(
+                "Control",
+                format!("{:02?}", self.bytes[1]),
+                vec![
+                    Field::new("Channel Width", self.channel_width()),
+                    Field::new("Duplicate Beacon", self.duplicate_beacon()),
+                    Field::new("Regulatory Info", self.regulatory_info()),
+                ],
+            ),
+            Field::new(
+                "Channel Center Frequency Segment 0",
+                self.channel_center_freq_segment_zero(),
+            ),
+            Field::new(
+                "Channel Center Frequency Segment 1",
+                self.channel_center_freq_segment_one(),
+            ),
+            Field::new("Minimum Rate", format!("{} Mbps", self.minimum_rate_mbps())),
+        ]
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeOperation {
+    bits: BitVec<Lsb0, u8>,
+}
+
+impl HeOperation {
+    pub const MIN_LENGTH: usize = 6;
+
+    const VHT_OPERATION_INFORMATION_OFFSET: usize = 6;
+
+    pub fn new(bytes: Vec<u8>) -> Result<HeOperation, IeError> {
+        if bytes.len() >= Self::MIN_LENGTH {
+            Ok(HeOperation {
+                bits: BitVec::from_vec(bytes),
+            })
+        } else {
+            Err(IeError::InvalidLength {
+                ie_name: Self::NAME,
+                expected_length: Self::MIN_LENGTH,
+                actual_length: bytes.len(),
+            })
+        }
+    }
+
+    // HE Operation Parameters
+
+    pub fn default_pe_duration_us(&self) -> u8 {
+        self.bits[0..=2].load::<u8>() * 4
+    }
+
+    pub fn twt_required(&self) -> bool {
+        self.bits[3]
+    }
+
+    /// The TXOP duration RTS threshold in μs, or None if TXOP duration-based RTS/CTS is disabled.
+    pub fn txop_duration_rts_threshold_us(&self) -> Option<u16> {
+        match self.bits[4..=13].load::<u16>() {
+            1023 => None,
+            value => Some(value * 32),
+        }
+    }
+
+    pub fn vht_operation_information_present(&self) -> bool {
+        self.bits[14]
+    }
+
+    pub fn co_hosted_bss(&self) -> bool {
+        self.bits[15]
+    }
+
+    pub fn er_su_disable(&self) -> bool {
+        self.bits[16]
+    }
+
+    pub fn six_ghz_operation_information_present(&self) -> bool {
+        self.bits[17]
+    }
+
+    // BSS Color Information
+
+    pub fn bss_color(&self) -> u8 {
+        self.bits[24..=29].load()
+    }
+
+    pub fn partial_bss_color(&self) -> bool {
+        self.bits[30]
+    }
+
+    pub fn bss_color_disabled(&self) -> bool {
+        self.bits[31]
+    }
+
+    // Basic HE-MCS And NSS Set
+
+    pub fn basic_he_mcs_and_nss_set(&self) -> HeMcsMap {
+        let bytes = self.bits.as_raw_slice();
+        HeMcsMap::new([bytes[4], bytes[5]])
+    }
+
+    // Optional fields
+
+    fn max_co_hosted_bssid_indicator_offset(&self) -> usize {
+        if self.vht_operation_information_present() {
+            Self::VHT_OPERATION_INFORMATION_OFFSET + 3
+        } else {
+            Self::VHT_OPERATION_INFORMATION_OFFSET
+        }
+    }
+
+    fn six_ghz_operation_information_offset(&self) -> usize {
+        if self.co_hosted_bss() {
+            self.max_co_hosted_bssid_indicator_offset() + 1
+        } else {
+            self.max_co_hosted_bssid_indicator_offset()
+        }
+    }
+
+    pub fn vht_operation_information(&self) -> Option<VhtOperationInformation> {
+        if !self.vht_operation_information_present() {
+            return None;
+        }
+
+        let offset = Self::VHT_OPERATION_INFORMATION_OFFSET;
+        self.bits
+            .as_raw_slice()
+            .get(offset..offset + 3)
+            .map(|bytes| VhtOperationInformation {
+                bytes: [bytes[0], bytes[1], bytes[2]],
+            })
+    }
+
+    pub fn max_co_hosted_bssid_indicator(&self) -> Option<u8> {
+        if !self.co_hosted_bss() {
+            return None;
+        }
+
+        self.bits
+            .as_raw_slice()
+            .get(self.max_co_hosted_bssid_indicator_offset())
+            .copied()
+    }
+
+    pub fn six_ghz_operation_information(&self) -> Option<SixGhzOperationInformation> {
+        if !self.six_ghz_operation_information_present() {
+            return None;
+        }
+
+        let offset = self.six_ghz_operation_information_offset();
+        self.bits
+            .as_raw_slice()
+            .get(offset..offset + 5)
+            .map(|bytes| SixGhzOperationInformation {
+                bytes: [bytes[0], bytes[1], bytes[2], bytes[3], bytes[4]],
+            })
+    }
+}
+
+impl InformationElement for HeOperation {
+    const NAME: &'static str = "HE Operation";
+    const ID: u8 = 255;
+    const ID_EXT: Option<u8> = Some(36);
+
+    fn bytes(&self) -> &[u8] {
+        self.bits.as_raw_slice()
+    }
+
+    fn information_fields(&self) -> Vec<Field> {
+        let basic_he_mcs_and_nss_set = self.basic_he_mcs_and_nss_set();
+
+        let mut information_fields = vec![
+            Field::with_subfields(
+                "HE Operation Parameters",
+                format!("{:02?}", &self.bits.as_raw_slice()[0..=2]),
+                vec![
+                    Field::new(
+                        "Default PE Duration",
+                        format!("{} μs", self.default_pe_duration_us()),
+                    ),
+                    Field::new("TWT Required", self.twt_required()),
+                    Field::new(
+                        "TXOP Duration RTS Threshold",
+                        match self.txop_duration_rts_threshold_us() {
+                            Some(threshold) => format!("{} μs", threshold),
+                            None => "Disabled".to_string(),
+                        },
+                    ),
+                    Field::new(
+                        "VHT Operation Information Present",
+                        self.vht_operation_information_present(),
+                    ),
+                    Field::new("Co-Hosted BSS", self.co_hosted_bss()),
+                    Field::new("ER SU Disable", self.er_su_disable()),
+                    Field::new(
+                        "6 GHz Operation Information Present",
+                        self.six_ghz_operation_information_present(),
+                    ),
+                ],
+            ),
+            Field::with_subfields(
+                "BSS Color Information",
+                format!("{:02?}", self.bits.as_raw_slice()[3]),
+                vec![
+                    Field::new("BSS Color", self.bss_color()),
+                    Field::new("Partial BSS Color", self.partial_bss_color()),
+                    Field::new("BSS Color Disabled", self.bss_color_disabled()),
+                ],
+            ),
+            Field::with_subfields(
+                "Basic HE-MCS and NSS Set",
+                basic_he_mcs_and_nss_set,
+                basic_he_mcs_and_nss_set.fields(),
+            ),
+        ];
+
+        if let Some(vht_operation_information) = self.vht_operation_information() {
+            information_fields.push(Field::with_subfields(
+                "VHT Operation Information",
+                format!("{:02?}", vht_operation_information.bytes),
+                vht_operation_information.fields(),
+            ));
+        }
+
+        if let Some(max_co_hosted_bssid_indicator) = self.max_co_hosted_bssid_indicator() {
+            information_fields.push(Field::new(
+                "Max Co-Hosted BSSID Indicator",
+                max_co_hosted_bssid_indicator,
+            ));
+        }
+
+        if let Some(six_ghz_operation_information) = self.six_ghz_operation_information() {
+            information_fields.push(Field::with_subfields(
+                "6 GHz Operation Information",
+                format!("{:02?}", six_ghz_operation_information.bytes),
+                six_ghz_operation_information.fields(),
+            ));
+        }
+
+        information_fields
+    }
+}
+
+impl_display_for_ie!(HeOperation);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ies::{self, Ie};
+
+    #[test]
+    fn he_operation_six_ghz() {
+        let ies = ies::from_bytes(&[
+            0xFF, 0x0C, 0x24, 0xF1, 0x3F, 0x02, 0x2A, 0xFE, 0xFF, 0x25, 0x0B, 0x27, 0x2F, 0x06,
+            0x03, 0x01, 0x25,
+        ])
+        .unwrap();
+
+        // The element ID extension is counted in the length, so the next element still parses
+        assert_eq!(ies.len(), 2);
+        assert_eq!(ies[1].id(), 3);
+
+        let he_operation = match &ies[0] {
+            Ie::HeOperation(he_operation) => he_operation,
+            ie => panic!("Expected HE Operation, got {}", ie.name()),
+        };
+        assert_eq!(he_operation.default_pe_duration_us(), 4);
+        assert_eq!(he_operation.txop_duration_rts_threshold_us(), None);
+        assert_eq!(he_operation.bss_color(), 42);
+        assert_eq!(he_operation.basic_he_mcs_and_nss_set().max_nss(), 1);
+        assert_eq!(he_operation.max_co_hosted_bssid_indicator(), None);
+
+        let six_ghz_operation_information = he_operation.six_ghz_operation_information().unwrap();
+        assert_eq!(six_ghz_operation_information.primary_channel(), 37);
+        assert_eq!(
+            six_ghz_operation_information.channel_width(),
+            ChannelWidth::OneSixtyMhz
+        );
+        assert_eq!(
+            six_ghz_operation_information.regulatory_info(),
+            SixGhzRegulatoryInfo::StandardPowerAccessPoint
+        );
+        assert_eq!(six_ghz_operation_information.minimum_rate_mbps(), 6);
+        assert_eq!(ChannelWidth::from(&ies[..]), ChannelWidth::OneSixtyMhz);
+    }
+
+    #[test]
+    fn he_operation_vht_operation_information() {
+        // An 80 MHz BSS on channel 36 that has no VHT Operation element
+        let mut bytes = vec![0x3D, 0x16, 0x24, 0x05];
+        bytes.extend_from_slice(&[0x00; 20]);
+        bytes.extend_from_slice(&[
+            0xFF, 0x0A, 0x24, 0x00, 0x40, 0x00, 0x01, 0xFC, 0xFF, 0x01, 0x2A, 0x00,
+        ]);
+        let ies = ies::from_bytes(&bytes).unwrap();
+
+        let he_operation = match &ies[1] {
+            Ie::HeOperation(he_operation) => he_operation,
+            ie => panic!("Expected HE Operation, got {}", ie.name()),
+        };
+        let vht_operation_information = he_operation.vht_operation_information().unwrap();
+        assert_eq!(
+            vht_operation_information.channel_center_freq_segment_zero(),
+            42
+        );
+        assert_eq!(he_operation.six_ghz_operation_information(), None);
+        assert_eq!(ChannelWidth::from(&ies[..]), ChannelWidth::EightyMhz);
+    }
+}
@@ -0,0 +1,122 @@
+use super::{Field, IeError, InformationElement};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hotspot20Indication {
+    bytes: Vec<u8>,
+}
+
+impl Hotspot20Indication {
+    pub const OUI: [u8; 4] = [0x50, 0x6F, 0x9A, 0x10];
+    pub const MIN_LENGTH: usize = 5;
+
+    const HOTSPOT_CONFIGURATION_OFFSET: usize = 4;
+
+    pub fn new(bytes: Vec<u8>) -> Result<Hotspot20Indication, IeError> {
+        if bytes.len() >= Self::MIN_LENGTH {
+            Ok(Hotspot20Indication { bytes })
+        } else {
+            Err(IeError::InvalidLength {
+                ie_name: Self::NAME,
+                expected_length: Self::MIN_LENGTH,
+                actual_length: bytes.len(),
+            })
+        }
+    }
+
+    fn hotspot_configuration(&self) -> u8 {
+        self.bytes[Self::HOTSPOT_CONFIGURATION_OFFSET]
+    }
+
+    pub fn dgaf_disabled(&self) -> bool {
+        self.hotspot_configuration() & 0b0001 != 0
+    }
+
+    pub fn pps_mo_id_present(&self) -> bool {
+        self.hotspot_configuration() & 0b0010 != 0
+    }
+
+    pub fn anqp_domain_id_present(&self) -> bool {
+        self.hotspot_configuration() & 0b0100 != 0
+    }
+
+    /// The Hotspot 2.0 release, where 1 is Release 1.
+    pub fn release_number(&self) -> u8 {
+        (self.hotspot_configuration() >> 4) + 1
+    }
+
+    fn u16_field(&self, offset: usize) -> Option<u16> {
+        self.bytes
+            .get(offset..offset + 2)
+            .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]))
+    }
+
+    pub fn pps_mo_id(&self) -> Option<u16> {
+        if self.pps_mo_id_present() {
+            self.u16_field(Self::MIN_LENGTH)
+        } else {
+            None
+        }
+    }
+
+    pub fn anqp_domain_id(&self) -> Option<u16> {
+        if !self.anqp_domain_id_present() {
+            return None;
+        }
+
+        if self.pps_mo_id_present() {
+            self.u16_field(Self::MIN_LENGTH + 2)
+        } else {
+            self.u16_field(Self::MIN_LENGTH)
+        }
+    }
+}
+
+impl InformationElement for Hotspot20Indication {
+    const NAME: &'static str = "Hotspot 2.0 Indication";
+    const ID: u8 = 221;
+
+    fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    fn information_fields(&self) -> Vec<Field> {
+        let mut information_fields = vec![Field::with_subfields(
+            "Hotspot Configuration",
+            format!("{:02?}", self.hotspot_configuration()),
+            vec![
+                Field::new("DGAF Disabled", self.dgaf_disabled()),
+                Field::new("PPS MO ID Present", self.pps_mo_id_present()),
+                Field::new("ANQP Domain ID Present", self.anqp_domain_id_present()),
+                Field::new("Release Number", self.release_number()),
+            ],
+        )];
+
+        if let Some(pps_mo_id) = self.pps_mo_id() {
+            information_fields.push(Field::new("PPS MO ID", pps_mo_id));
+        }
+        if let Some(anqp_domain_id) = self.anqp_domain_id() {
+            information_fields.push(Field::new("ANQP Domain ID", anqp_domain_id));
+        }
+
+        information_fields
+    }
+}
+
+impl_display_for_ie!(Hotspot20Indication);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hotspot20_indication_release_three() {
+        let mut bytes = Hotspot20Indication::OUI.to_vec();
+        bytes.extend([0x25, 0x34, 0x12]);
+        let indication = Hotspot20Indication::new(bytes).unwrap();
+
+        assert_eq!(indication.release_number(), 3);
+        assert!(indication.dgaf_disabled());
+        assert_eq!(indication.pps_mo_id(), None);
+        assert_eq!(indication.anqp_domain_id(), Some(0x1234));
+    }
+}
@@ -84,6 +84,7 @@ impl Display for PcoTransitionTime {
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
 #[repr(u8)]
+#[allow(clippy::enum_variant_names)]
 pub enum McsFeedback {
     NoMfb,
     UnsolicitedMfb = 2,
@@ -263,6 +264,25 @@ impl HtCapabilities {
 
     // Supported MCS Set
 
+    /// Whether the STA can receive the HT-MCS, from the Rx MCS Bitmask subfield.
+    pub fn rx_mcs_supported(&self, mcs: u8) -> bool {
+        mcs <= 76 && self.bits[24 + mcs as usize]
+    }
+
+    /// The HT-MCSs the STA can receive.
+    pub fn rx_mcs_indices(&self) -> Vec<u8> {
+        (0..=76).filter(|mcs| self.rx_mcs_supported(*mcs)).collect()
+    }
+
+    /// The highest data rate the STA can receive in Mb/s, where 0 means it isn't stated.
+    pub fn rx_highest_supported_data_rate_mbps(&self) -> u16 {
+        self.bits[104..=113].load_le::<u16>()
+    }
+
+    pub fn tx_mcs_set_defined(&self) -> bool {
+        self.bits[120]
+    }
+
     // HT Extended Capabilities
 
     pub fn pco(&self) -> bool {
@@ -467,7 +487,14 @@ impl InformationElement for HtCapabilities {
             Field::with_subfields(
                 "Supported MCS Set",
                 format!("{:02?}", &self.bits.as_raw_slice()[3..=18]),
-                Vec::new(),
+                vec![
+                    Field::new("Rx MCS Indices", format!("{:?}", self.rx_mcs_indices())),
+                    Field::new(
+                        "Rx Highest Supported Data Rate",
+                        format!("{} Mb/s", self.rx_highest_supported_data_rate_mbps()),
+                    ),
+                    Field::new("Tx MCS Set Defined", self.tx_mcs_set_defined()),
+                ],
             ),
             Field::with_subfields(
                 "HT Extended Capabilities",
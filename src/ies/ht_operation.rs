@@ -1,6 +1,46 @@
 use super::{Field, IeError, InformationElement};
 use crate::{ChannelWidth, ChannelWidths};
 use bitvec::prelude::*;
+use num_enum::TryFromPrimitive;
+use std::{convert::TryFrom, fmt::Display};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
+#[repr(u8)]
+pub enum SecondaryChannelOffset {
+    NoSecondaryChannel = 0,
+    Above = 1,
+    Below = 3,
+}
+
+impl Display for SecondaryChannelOffset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SecondaryChannelOffset::NoSecondaryChannel => write!(f, "No Secondary Channel"),
+            SecondaryChannelOffset::Above => write!(f, "Above"),
+            SecondaryChannelOffset::Below => write!(f, "Below"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
+#[repr(u8)]
+pub enum HtProtection {
+    NoProtection,
+    NonmemberProtection,
+    TwentyMhzProtection,
+    NonHtMixed,
+}
+
+impl Display for HtProtection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HtProtection::NoProtection => write!(f, "No Protection"),
+            HtProtection::NonmemberProtection => write!(f, "Nonmember Protection"),
+            HtProtection::TwentyMhzProtection => write!(f, "20 MHz Protection"),
+            HtProtection::NonHtMixed => write!(f, "Non-HT Mixed"),
+        }
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct HtOperation {
@@ -23,31 +63,106 @@ impl HtOperation {
             })
         }
     }
-}
 
-impl InformationElement for HtOperation {
-    fn name(&self) -> &'static str {
-        HtOperation::NAME
+    pub fn primary_channel(&self) -> u8 {
+        self.bits.as_raw_slice()[0]
+    }
+
+    // HT Operation Information
+
+    pub fn secondary_channel_offset(&self) -> SecondaryChannelOffset {
+        SecondaryChannelOffset::try_from(self.bits[8..=9].load::<u8>())
+            .unwrap_or(SecondaryChannelOffset::NoSecondaryChannel)
+    }
+
+    pub fn sta_channel_width(&self) -> ChannelWidths {
+        if self.bits[10] {
+            (ChannelWidth::TwentyMhz | ChannelWidth::FortyMhz).into()
+        } else {
+            ChannelWidth::TwentyMhz.into()
+        }
     }
 
-    fn id(&self) -> u8 {
-        HtOperation::ID
+    pub fn rifs_mode(&self) -> bool {
+        self.bits[11]
     }
 
+    pub fn ht_protection(&self) -> HtProtection {
+        HtProtection::try_from(self.bits[16..=17].load::<u8>())
+            .unwrap_or(HtProtection::NoProtection)
+    }
+
+    pub fn nongreenfield_ht_stas_present(&self) -> bool {
+        self.bits[18]
+    }
+
+    pub fn obss_non_ht_stas_present(&self) -> bool {
+        self.bits[20]
+    }
+
+    pub fn channel_center_freq_segment_two(&self) -> u8 {
+        self.bits[21..=28].load::<u8>()
+    }
+
+    pub fn dual_beacon(&self) -> bool {
+        self.bits[38]
+    }
+
+    pub fn dual_cts_protection(&self) -> bool {
+        self.bits[39]
+    }
+
+    pub fn stbc_beacon(&self) -> bool {
+        self.bits[40]
+    }
+
+    // Basic HT-MCS Set
+
+    pub fn basic_ht_mcs_set(&self) -> &[u8] {
+        &self.bits.as_raw_slice()[6..Self::LENGTH]
+    }
+}
+
 impl InformationElement for HtOperation {
     const NAME: &'static str = "HT Operation";
     const ID: u8 = 61;
 
     fn bytes(&self) -> &[u8] {
-        &self.bits.as_raw_slice()
+        self.bits.as_raw_slice()
     }
 
     fn information_fields(&self) -> Vec<Field> {
-        Vec::new()
+        vec![
+            Field::new("Primary Channel", self.primary_channel()),
+            Field::with_subfields(
+                "HT Operation Information",
+                format!("{:02?}", &self.bits.as_raw_slice()[1..=5]),
+                vec![
+                    Field::new("Secondary Channel Offset", self.secondary_channel_offset()),
+                    Field::new("STA Channel Width", self.sta_channel_width()),
+                    Field::new("RIFS Mode", self.rifs_mode()),
+                    Field::new("HT Protection", self.ht_protection()),
+                    Field::new(
+                        "Nongreenfield HT STAs Present",
+                        self.nongreenfield_ht_stas_present(),
+                    ),
+                    Field::new("OBSS Non-HT STAs Present", self.obss_non_ht_stas_present()),
+                    Field::new(
+                        "Channel Center Frequency Segment 2",
+                        self.channel_center_freq_segment_two(),
+                    ),
+                    Field::new("Dual Beacon", self.dual_beacon()),
+                    Field::new("Dual CTS Protection", self.dual_cts_protection()),
+                    Field::new("STBC Beacon", self.stbc_beacon()),
+                ],
+            ),
+            Field::with_subfields(
+                "Basic HT-MCS Set",
+                format!("{:02?}", self.basic_ht_mcs_set()),
+                Vec::new(),
+            ),
+        ]
     }
 }
 
 impl_display_for_ie!(HtOperation);
-
-    }
-}
@@ -0,0 +1,302 @@
+use super::{Field, IeError, InformationElement};
+use macaddr::MacAddr6;
+use std::fmt::Display;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccessNetworkType {
+    PrivateNetwork,
+    PrivateNetworkWithGuestAccess,
+    ChargeablePublicNetwork,
+    FreePublicNetwork,
+    PersonalDeviceNetwork,
+    EmergencyServicesOnlyNetwork,
+    TestOrExperimental,
+    Wildcard,
+    Reserved(u8),
+}
+
+impl From<u8> for AccessNetworkType {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => AccessNetworkType::PrivateNetwork,
+            1 => AccessNetworkType::PrivateNetworkWithGuestAccess,
+            2 => AccessNetworkType::ChargeablePublicNetwork,
+            3 => AccessNetworkType::FreePublicNetwork,
+            4 => AccessNetworkType::PersonalDeviceNetwork,
+            5 => AccessNetworkType::EmergencyServicesOnlyNetwork,
+            14 => AccessNetworkType::TestOrExperimental,
+            15 => AccessNetworkType::Wildcard,
+            value => AccessNetworkType::Reserved(value),
+        }
+    }
+}
+
+impl Display for AccessNetworkType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AccessNetworkType::PrivateNetwork => write!(f, "Private Network"),
+            AccessNetworkType::PrivateNetworkWithGuestAccess => {
+                write!(f, "Private Network with Guest Access")
+            }
+            AccessNetworkType::ChargeablePublicNetwork => write!(f, "Chargeable Public Network"),
+            AccessNetworkType::FreePublicNetwork => write!(f, "Free Public Network"),
+            AccessNetworkType::PersonalDeviceNetwork => write!(f, "Personal Device Network"),
+            AccessNetworkType::EmergencyServicesOnlyNetwork => {
+                write!(f, "Emergency Services Only Network")
+            }
+            AccessNetworkType::TestOrExperimental => write!(f, "Test or Experimental"),
+            AccessNetworkType::Wildcard => write!(f, "Wildcard"),
+            AccessNetworkType::Reserved(value) => write!(f, "Reserved ({})", value),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VenueGroup {
+    Unspecified,
+    Assembly,
+    Business,
+    Educational,
+    FactoryAndIndustrial,
+    Institutional,
+    Mercantile,
+    Residential,
+    Storage,
+    UtilityAndMiscellaneous,
+    Vehicular,
+    Outdoor,
+    Reserved(u8),
+}
+
+impl From<u8> for VenueGroup {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => VenueGroup::Unspecified,
+            1 => VenueGroup::Assembly,
+            2 => VenueGroup::Business,
+            3 => VenueGroup::Educational,
+            4 => VenueGroup::FactoryAndIndustrial,
+            5 => VenueGroup::Institutional,
+            6 => VenueGroup::Mercantile,
+            7 => VenueGroup::Residential,
+            8 => VenueGroup::Storage,
+            9 => VenueGroup::UtilityAndMiscellaneous,
+            10 => VenueGroup::Vehicular,
+            11 => VenueGroup::Outdoor,
+            value => VenueGroup::Reserved(value),
+        }
+    }
+}
+
+impl Display for VenueGroup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VenueGroup::Unspecified => write!(f, "Unspecified"),
+            VenueGroup::Assembly => write!(f, "Assembly"),
+            VenueGroup::Business => write!(f, "Business"),
+            VenueGroup::Educational => write!(f, "Educational"),
+            VenueGroup::FactoryAndIndustrial => write!(f, "Factory and Industrial"),
+            VenueGroup::Institutional => write!(f, "Institutional"),
+            VenueGroup::Mercantile => write!(f, "Mercantile"),
+            VenueGroup::Residential => write!(f, "Residential"),
+            VenueGroup::Storage => write!(f, "Storage"),
+            VenueGroup::UtilityAndMiscellaneous => write!(f, "Utility and Miscellaneous"),
+            VenueGroup::Vehicular => write!(f, "Vehicular"),
+            VenueGroup::Outdoor => write!(f, "Outdoor"),
+            VenueGroup::Reserved(value) => write!(f, "Reserved ({})", value),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Interworking {
+    bytes: Vec<u8>,
+}
+
+impl Interworking {
+    pub const MIN_LENGTH: usize = 1;
+    /// The lengths of the element with and without the optional Venue Info (2 octets) and HESSID
+    /// (6 octets) fields. Octets past the longest one are ignored.
+    pub const VALID_LENGTHS: [usize; 4] = [1, 3, 7, 9];
+
+    pub fn new(bytes: Vec<u8>) -> Result<Interworking, IeError> {
+        if Self::VALID_LENGTHS.contains(&bytes.len()) || bytes.len() > 9 {
+            Ok(Interworking { bytes })
+        } else {
+            // Report the length the element would have if its last field weren't cut short
+            Err(IeError::InvalidLength {
+                ie_name: Self::NAME,
+                expected_length: Self::VALID_LENGTHS
+                    .iter()
+                    .copied()
+                    .find(|length| *length > bytes.len())
+                    .unwrap_or(Self::MIN_LENGTH),
+                actual_length: bytes.len(),
+            })
+        }
+    }
+
+    // The length of the fields the element has, leaving out any octets past the HESSID
+    fn fields_length(&self) -> usize {
+        self.bytes.len().min(9)
+    }
+
+    // Access Network Options
+
+    pub fn access_network_type(&self) -> AccessNetworkType {
+        AccessNetworkType::from(self.bytes[0] & 0b1111)
+    }
+
+    pub fn internet(&self) -> bool {
+        self.bytes[0] & 0b0001_0000 != 0
+    }
+
+    pub fn asra(&self) -> bool {
+        self.bytes[0] & 0b0010_0000 != 0
+    }
+
+    pub fn esr(&self) -> bool {
+        self.bytes[0] & 0b0100_0000 != 0
+    }
+
+    pub fn uesa(&self) -> bool {
+        self.bytes[0] & 0b1000_0000 != 0
+    }
+
+    // Venue Info
+
+    pub fn venue_group(&self) -> Option<VenueGroup> {
+        self.venue_info().map(|(venue_group, _)| venue_group)
+    }
+
+    pub fn venue_type(&self) -> Option<u8> {
+        self.venue_info().map(|(_, venue_type)| venue_type)
+    }
+
+    fn venue_info(&self) -> Option<(VenueGroup, u8)> {
+        if matches!(self.fields_length(), 3 | 9) {
+            Some((VenueGroup::from(self.bytes[1]), self.bytes[2]))
+        } else {
+            None
+        }
+    }
+
+    // HESSID
+
+    pub fn hessid(&self) -> Option<MacAddr6> {
+        let offset = self
+            .fields_length()
+            .checked_sub(6)
+            .filter(|offset| *offset > 0)?;
+        let mut hessid = [0; 6];
+        hessid.copy_from_slice(&self.bytes[offset..offset + 6]);
+        Some(MacAddr6::from(hessid))
+    }
+}
+
+impl InformationElement for Interworking {
+    const NAME: &'static str = "Interworking";
+    const ID: u8 = 107;
+
+    fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    fn information_fields(&self) -> Vec<Field> {
+        let mut information_fields = vec![Field::with_subfields(
+            "Access Network Options",
+            format!("{:02?}", self.bytes[0]),
+            vec![
+                Field::new("Access Network Type", self.access_network_type()),
+                Field::new("Internet", self.internet()),
+                Field::new("ASRA", self.asra()),
+                Field::new("ESR", self.esr()),
+                Field::new("UESA", self.uesa()),
+            ],
+        )];
+
+        if let Some((venue_group, venue_type)) = self.venue_info() {
+            information_fields.push(Field::with_subfields(
+                "Venue Info",
+                format!("{:02?}", &self.bytes[1..3]),
+                vec![
+                    Field::new("Venue Group", venue_group),
+                    Field::new("Venue Type", venue_type),
+                ],
+            ));
+        }
+
+        if let Some(hessid) = self.hessid() {
+            information_fields.push(Field::new("HESSID", hessid));
+        }
+
+        information_fields
+    }
+}
+
+impl_display_for_ie!(Interworking);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ies::{Hotspot20Indication, Ie, VendorSpecific};
+
+    #[test]
+    fn interworking_with_venue_info_and_hessid() {
+        let interworking =
+            Interworking::new(vec![0x13, 0x02, 0x08, 0x02, 0x11, 0x22, 0x33, 0x44, 0x55]).unwrap();
+
+        assert_eq!(
+            interworking.access_network_type(),
+            AccessNetworkType::FreePublicNetwork
+        );
+        assert!(interworking.internet());
+        assert!(!interworking.asra());
+        assert_eq!(interworking.venue_group(), Some(VenueGroup::Business));
+        assert_eq!(interworking.venue_type(), Some(8));
+        assert_eq!(
+            interworking.hessid(),
+            Some(MacAddr6::new(0x02, 0x11, 0x22, 0x33, 0x44, 0x55))
+        );
+
+        let interworking = Interworking::new(vec![0x00]).unwrap();
+        assert_eq!(interworking.venue_group(), None);
+        assert_eq!(interworking.hessid(), None);
+    }
+
+    #[test]
+    fn interworking_lengths() {
+        // Octets past the HESSID are ignored
+        let interworking = Interworking::new(vec![
+            0x13, 0x02, 0x08, 0x02, 0x11, 0x22, 0x33, 0x44, 0x55, 0xAA, 0xBB,
+        ])
+        .unwrap();
+        assert_eq!(interworking.venue_type(), Some(8));
+        assert_eq!(
+            interworking.hessid(),
+            Some(MacAddr6::new(0x02, 0x11, 0x22, 0x33, 0x44, 0x55))
+        );
+
+        assert!(matches!(
+            Interworking::new(vec![0x13, 0x02, 0x08, 0x02, 0x11]),
+            Err(IeError::InvalidLength {
+                expected_length: 7,
+                actual_length: 5,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn malformed_hotspot20_indication_is_kept_as_vendor_specific() {
+        let ie = Ie::new(
+            Hotspot20Indication::OUI.to_vec(),
+            VendorSpecific::ID,
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert!(matches!(ie, Ie::VendorSpecific(_)));
+    }
+}
@@ -0,0 +1,47 @@
+use super::{Field, IeError, InformationElement};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MaxChannelSwitchTime {
+    bytes: Vec<u8>,
+}
+
+impl MaxChannelSwitchTime {
+    pub const LENGTH: usize = 3;
+
+    pub fn new(bytes: Vec<u8>) -> Result<MaxChannelSwitchTime, IeError> {
+        if bytes.len() == Self::LENGTH {
+            Ok(MaxChannelSwitchTime { bytes })
+        } else {
+            Err(IeError::InvalidLength {
+                ie_name: Self::NAME,
+                expected_length: Self::LENGTH,
+                actual_length: bytes.len(),
+            })
+        }
+    }
+
+    /// The maximum time from the last beacon on the old channel to the first beacon on the new
+    /// channel, in TUs.
+    pub fn switch_time_tu(&self) -> u32 {
+        u32::from_le_bytes([self.bytes[0], self.bytes[1], self.bytes[2], 0])
+    }
+}
+
+impl InformationElement for MaxChannelSwitchTime {
+    const NAME: &'static str = "Max Channel Switch Time";
+    const ID: u8 = 255;
+    const ID_EXT: Option<u8> = Some(34);
+
+    fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    fn information_fields(&self) -> Vec<Field> {
+        vec![Field::new(
+            "Switch Time",
+            format!("{} TUs", self.switch_time_tu()),
+        )]
+    }
+}
+
+impl_display_for_ie!(MaxChannelSwitchTime);
@@ -31,13 +31,8 @@ impl MeasurementPilotTransmission {
 
         let mut subelements = Vec::new();
 
-        loop {
-            // The first byte of the subelement is the ID
-            let se_id = match bytes.read_u8() {
-                Ok(se_id) => se_id,
-                _ => break,
-            };
-
+        // The first byte of the subelement is the ID
+        while let Ok(se_id) = bytes.read_u8() {
             // The second byte of the subelement is the number of bytes of data
             let se_len = match bytes.read_u8() {
                 Ok(se_len) => se_len,
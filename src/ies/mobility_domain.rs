@@ -0,0 +1,84 @@
+use super::{Field, IeError, InformationElement};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MobilityDomain {
+    bytes: Vec<u8>,
+}
+
+impl MobilityDomain {
+    pub const LENGTH: usize = 3;
+
+    pub fn new(bytes: Vec<u8>) -> Result<MobilityDomain, IeError> {
+        if bytes.len() == Self::LENGTH {
+            Ok(MobilityDomain { bytes })
+        } else {
+            Err(IeError::InvalidLength {
+                ie_name: Self::NAME,
+                expected_length: Self::LENGTH,
+                actual_length: bytes.len(),
+            })
+        }
+    }
+
+    /// The mobility domain identifier, which every AP that a STA can fast transition between
+    /// shares.
+    pub fn mdid(&self) -> u16 {
+        u16::from_le_bytes([self.bytes[0], self.bytes[1]])
+    }
+
+    // FT Capability and Policy
+
+    pub fn fast_bss_transition_over_ds(&self) -> bool {
+        self.bytes[2] & 0b01 != 0
+    }
+
+    pub fn resource_request_protocol_capability(&self) -> bool {
+        self.bytes[2] & 0b10 != 0
+    }
+}
+
+impl InformationElement for MobilityDomain {
+    const NAME: &'static str = "Mobility Domain";
+    const ID: u8 = 54;
+
+    fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    fn information_fields(&self) -> Vec<Field> {
+        vec![
+            Field::new("MDID", format!("{:#06x}", self.mdid())),
+            Field::with_subfields(
+                "FT Capability and Policy",
+                format!("{:02?}", self.bytes[2]),
+                vec![
+                    Field::new(
+                        "Fast BSS Transition over DS",
+                        self.fast_bss_transition_over_ds(),
+                    ),
+                    Field::new(
+                        "Resource Request Protocol Capability",
+                        self.resource_request_protocol_capability(),
+                    ),
+                ],
+            ),
+        ]
+    }
+}
+
+impl_display_for_ie!(MobilityDomain);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mobility_domain() {
+        let mobility_domain = MobilityDomain::new(vec![0x34, 0x12, 0x01]).unwrap();
+
+        assert_eq!(mobility_domain.mdid(), 0x1234);
+        assert!(mobility_domain.fast_bss_transition_over_ds());
+        assert!(!mobility_domain.resource_request_protocol_capability());
+        assert!(MobilityDomain::new(vec![0x34, 0x12]).is_err());
+    }
+}
@@ -34,24 +34,49 @@ macro_rules! impl_display_for_ie {
     };
 }
 
+mod advertisement_protocol;
 mod antenna;
 mod ap_channel_report;
 mod bss_load;
+mod channel_switch_announcement;
+mod channel_switch_wrapper;
 mod country;
 mod ds_parameter_set;
+mod eht_capabilities;
+mod eht_operation;
 mod erp_info;
 mod extended_capabilities;
+mod extended_channel_switch_announcement;
+mod fast_bss_transition;
+mod he_capabilities;
+mod he_operation;
+mod hotspot20_indication;
 mod ht_capabilities;
 mod ht_operation;
 mod ibss_parameter_set;
+mod interworking;
+mod max_channel_switch_time;
 mod measurement_pilot_transmission;
 mod mesh_configuration;
 mod mesh_id;
+mod mobility_domain;
+mod multi_link;
+mod multiple_bssid;
+mod multiple_bssid_index;
+mod non_inheritance;
+mod nontransmitted_bssid_capability;
 mod overlapping_bss_scan_params;
+mod p2p;
 mod power_constraint;
+mod quiet;
+mod quiet_channel;
+mod reduced_neighbor_report;
 mod rm_enabled_capabilities;
+mod roaming_consortium;
 mod rsn;
+mod secondary_channel_offset;
 mod ssid;
+mod supported_operating_classes;
 mod supported_rates;
 mod tim;
 mod transmit_power_envelope;
@@ -60,65 +85,135 @@ mod unknown;
 mod vendor_specific;
 mod vht_capabilities;
 mod vht_operation;
+mod wide_bandwidth_channel_switch;
+mod wmm;
 mod wpa;
+mod wps;
 
+pub use advertisement_protocol::{
+    AdvertisementProtocol, AdvertisementProtocolId, AdvertisementProtocolTuple,
+};
 pub use antenna::Antenna;
 pub use ap_channel_report::ApChannelReport;
 pub use bss_load::BssLoad;
+pub use channel_switch_announcement::ChannelSwitchAnnouncement;
+pub use channel_switch_wrapper::ChannelSwitchWrapper;
 pub use country::Country;
 pub use ds_parameter_set::DsParameterSet;
+pub use eht_capabilities::{EhtCapabilities, EhtMaxMpduLength, EhtMcsMap};
+pub use eht_operation::{EhtOperation, EhtOperationInformation, ThreeTwentyMhzChannelization};
 pub use erp_info::ErpInfo;
 pub use extended_capabilities::ExtendedCapabilities;
+pub use extended_channel_switch_announcement::ExtendedChannelSwitchAnnouncement;
+pub use fast_bss_transition::FastBssTransition;
+pub use he_capabilities::{
+    DcmConstellation, DcmMaxRu, HeCapabilities, HeMcsMap, HeMcsSupport, PpeConstellation,
+    PpeThreshold,
+};
+pub use he_operation::{
+    HeOperation, SixGhzOperationInformation, SixGhzRegulatoryInfo, VhtOperationInformation,
+};
+pub use hotspot20_indication::Hotspot20Indication;
 pub use ht_capabilities::HtCapabilities;
-pub use ht_operation::HtOperation;
+pub use ht_operation::{HtOperation, SecondaryChannelOffset};
 pub use ibss_parameter_set::IbssParameterSet;
+pub use interworking::{AccessNetworkType, Interworking, VenueGroup};
+pub use max_channel_switch_time::MaxChannelSwitchTime;
 pub use measurement_pilot_transmission::MeasurementPilotTransmission;
 pub use mesh_configuration::MeshConfiguration;
 pub use mesh_id::MeshId;
+pub use mobility_domain::MobilityDomain;
+pub use multi_link::{EmlCapabilities, MldCapabilities, MultiLink, MultiLinkType, PerStaProfile};
+pub use multiple_bssid::{MultipleBssid, NontransmittedBssidProfile};
+pub use multiple_bssid_index::MultipleBssidIndex;
+pub use non_inheritance::NonInheritance;
+pub use nontransmitted_bssid_capability::NontransmittedBssidCapability;
 pub use overlapping_bss_scan_params::OverlappingBssScanParams;
+pub use p2p::{
+    NoticeOfAbsence, NoticeOfAbsenceDescriptor, P2p, P2pChannel, P2pChannelEntry, P2pChannelList,
+    P2pClientInfo, P2pDeviceCapability, P2pDeviceInfo, P2pGroupCapability,
+};
 pub use power_constraint::PowerConstraint;
+pub use quiet::{Quiet, QuietInterval};
+pub use quiet_channel::QuietChannel;
+pub use reduced_neighbor_report::{
+    NeighborApInformation, ReducedNeighborReport, RnrBssParameters, RnrMldParameters,
+    TbttInformation,
+};
 pub use rm_enabled_capabilities::RmEnabledCapabilities;
-pub use rsn::Rsn;
-pub use ssid::Ssid;
+pub use roaming_consortium::RoamingConsortium;
+pub use rsn::{AkmSuite, CipherSuite, Rsn, RsnCapabilities};
+pub use secondary_channel_offset::SecondaryChannelOffsetElement;
+pub use ssid::{DecodedSsid, Ssid, SsidEncoding, SsidKind};
+pub use supported_operating_classes::SupportedOperatingClasses;
 pub use supported_rates::{DataRate, ExtendedSupportedRates, SupportedRates};
 pub use tim::Tim;
 pub use transmit_power_envelope::TransmitPowerEnvelope;
 pub use twenty_forty_bss_coexistence::TwentyFortyBssCoexistence;
 pub use unknown::Unknown;
 pub use vendor_specific::VendorSpecific;
-pub use vht_capabilities::VhtCapabilities;
+pub use vht_capabilities::{VhtCapabilities, VhtMcsMap, VhtMcsSupport};
 pub use vht_operation::VhtOperation;
+pub use wide_bandwidth_channel_switch::WideBandwidthChannelSwitch;
+pub use wmm::{AcParameters, AccessCategory, Wmm, WmmSubtype};
 pub use wpa::Wpa;
+pub use wps::{ConfigMethod, DevicePasswordId, DeviceType, Wps, WpsResponseType, WpsState};
 
-use crate::Field;
+use crate::{Field, OperatingClass};
 use byteorder::ReadBytesExt;
 use std::fmt::Display;
 use std::io::{Cursor, Read};
 use thiserror::Error;
 
+// The ID of the Fragment element
+const FRAGMENT_ID: u8 = 242;
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Ie {
+    AdvertisementProtocol(AdvertisementProtocol),
     Antenna(Antenna),
     ApChannelReport(ApChannelReport),
     BssLoad(BssLoad),
+    ChannelSwitchAnnouncement(ChannelSwitchAnnouncement),
+    ChannelSwitchWrapper(ChannelSwitchWrapper),
     Country(Country),
     DsParameterSet(DsParameterSet),
+    EhtCapabilities(EhtCapabilities),
+    EhtOperation(EhtOperation),
     ErpInfo(ErpInfo),
     ExtendedCapabilities(ExtendedCapabilities),
+    ExtendedChannelSwitchAnnouncement(ExtendedChannelSwitchAnnouncement),
     ExtendedSupportedRates(ExtendedSupportedRates),
+    FastBssTransition(FastBssTransition),
     HeCapabilities(HeCapabilities),
     HeOperation(HeOperation),
+    Hotspot20Indication(Hotspot20Indication),
     HtCapabilities(HtCapabilities),
     HtOperation(HtOperation),
     IbssParameterSet(IbssParameterSet),
+    Interworking(Interworking),
+    MaxChannelSwitchTime(MaxChannelSwitchTime),
     MeasurementPilotTransmission(MeasurementPilotTransmission),
     MeshConfiguration(MeshConfiguration),
     MeshId(MeshId),
+    MobilityDomain(MobilityDomain),
+    MultiLink(MultiLink),
+    MultipleBssid(MultipleBssid),
+    MultipleBssidIndex(MultipleBssidIndex),
+    NonInheritance(NonInheritance),
+    NontransmittedBssidCapability(NontransmittedBssidCapability),
     OverlappingBssScanParams(OverlappingBssScanParams),
+    P2p(P2p),
     PowerConstraint(PowerConstraint),
+    Quiet(Quiet),
+    QuietChannel(QuietChannel),
+    ReducedNeighborReport(ReducedNeighborReport),
     RmEnabledCapabilities(RmEnabledCapabilities),
+    RoamingConsortium(RoamingConsortium),
     Rsn(Rsn),
+    SecondaryChannelOffset(SecondaryChannelOffsetElement),
     Ssid(Ssid),
+    SupportedOperatingClasses(SupportedOperatingClasses),
     SupportedRates(SupportedRates),
     Tim(Tim),
     TransmitPowerEnvelope(TransmitPowerEnvelope),
@@ -127,33 +222,59 @@ pub enum Ie {
     VendorSpecific(VendorSpecific),
     VhtCapabilities(VhtCapabilities),
     VhtOperation(VhtOperation),
+    WideBandwidthChannelSwitch(WideBandwidthChannelSwitch),
+    Wmm(Wmm),
     Wpa(Wpa),
+    Wps(Wps),
 }
 
 macro_rules! match_inner_ie {
     ($ie:ident, $inner_ie:ident, $output:expr) => {
         match $ie {
+            Ie::AdvertisementProtocol($inner_ie) => $output,
             Ie::Antenna($inner_ie) => $output,
             Ie::ApChannelReport($inner_ie) => $output,
             Ie::BssLoad($inner_ie) => $output,
+            Ie::ChannelSwitchAnnouncement($inner_ie) => $output,
+            Ie::ChannelSwitchWrapper($inner_ie) => $output,
             Ie::Country($inner_ie) => $output,
             Ie::DsParameterSet($inner_ie) => $output,
+            Ie::EhtCapabilities($inner_ie) => $output,
+            Ie::EhtOperation($inner_ie) => $output,
             Ie::ErpInfo($inner_ie) => $output,
             Ie::ExtendedCapabilities($inner_ie) => $output,
+            Ie::ExtendedChannelSwitchAnnouncement($inner_ie) => $output,
             Ie::ExtendedSupportedRates($inner_ie) => $output,
+            Ie::FastBssTransition($inner_ie) => $output,
             Ie::HeCapabilities($inner_ie) => $output,
             Ie::HeOperation($inner_ie) => $output,
+            Ie::Hotspot20Indication($inner_ie) => $output,
             Ie::HtCapabilities($inner_ie) => $output,
             Ie::HtOperation($inner_ie) => $output,
             Ie::IbssParameterSet($inner_ie) => $output,
+            Ie::Interworking($inner_ie) => $output,
+            Ie::MaxChannelSwitchTime($inner_ie) => $output,
             Ie::MeasurementPilotTransmission($inner_ie) => $output,
             Ie::MeshConfiguration($inner_ie) => $output,
             Ie::MeshId($inner_ie) => $output,
+            Ie::MobilityDomain($inner_ie) => $output,
+            Ie::MultiLink($inner_ie) => $output,
+            Ie::MultipleBssid($inner_ie) => $output,
+            Ie::MultipleBssidIndex($inner_ie) => $output,
+            Ie::NonInheritance($inner_ie) => $output,
+            Ie::NontransmittedBssidCapability($inner_ie) => $output,
             Ie::OverlappingBssScanParams($inner_ie) => $output,
+            Ie::P2p($inner_ie) => $output,
             Ie::PowerConstraint($inner_ie) => $output,
+            Ie::Quiet($inner_ie) => $output,
+            Ie::QuietChannel($inner_ie) => $output,
+            Ie::ReducedNeighborReport($inner_ie) => $output,
             Ie::RmEnabledCapabilities($inner_ie) => $output,
+            Ie::RoamingConsortium($inner_ie) => $output,
             Ie::Rsn($inner_ie) => $output,
+            Ie::SecondaryChannelOffset($inner_ie) => $output,
             Ie::Ssid($inner_ie) => $output,
+            Ie::SupportedOperatingClasses($inner_ie) => $output,
             Ie::SupportedRates($inner_ie) => $output,
             Ie::Tim($inner_ie) => $output,
             Ie::TransmitPowerEnvelope($inner_ie) => $output,
@@ -162,17 +283,71 @@ macro_rules! match_inner_ie {
             Ie::VendorSpecific($inner_ie) => $output,
             Ie::VhtCapabilities($inner_ie) => $output,
             Ie::VhtOperation($inner_ie) => $output,
+            Ie::WideBandwidthChannelSwitch($inner_ie) => $output,
+            Ie::Wmm($inner_ie) => $output,
             Ie::Wpa($inner_ie) => $output,
+            Ie::Wps($inner_ie) => $output,
         }
     };
 }
 
+// A vendor element that doesn't parse as the element its OUI identifies is kept as a plain vendor
+// specific element rather than dropping the whole BSS
+fn vendor_ie_or_vendor_specific<T>(
+    ie: Result<T, IeError>,
+    variant: fn(T) -> Ie,
+    ie_data: Vec<u8>,
+) -> Ie {
+    ie.map_or_else(
+        |_| Ie::VendorSpecific(VendorSpecific::new(ie_data)),
+        variant,
+    )
+}
+
+// An element that's too short to parse is kept as an unknown element rather than dropping the
+// whole BSS
+fn ie_or_unknown<T>(
+    ie: Result<T, IeError>,
+    variant: fn(T) -> Ie,
+    ie_data: Vec<u8>,
+    ie_id: u8,
+) -> Ie {
+    match ie {
+        Ok(ie) => variant(ie),
+        Err(_) => Ie::Unknown(Unknown::new(ie_data, ie_id, None)),
+    }
+}
+
+// An operating class field, described with the global operating class table when it's a class
+// of that table
+fn operating_class_field(title: &str, class: u8) -> Field {
+    match OperatingClass::global(class) {
+        Some(operating_class) => Field::new(title, operating_class),
+        None => Field::new(title, class),
+    }
+}
+
 impl Ie {
-    fn new(ie_data: Vec<u8>, ie_id: u8, ie_id_ext: Option<u8>) -> Result<Ie, IeError> {
+    // The HE Capabilities element of the frame is needed to parse the EHT Capabilities element
+    fn new(
+        ie_data: Vec<u8>,
+        ie_id: u8,
+        ie_id_ext: Option<u8>,
+        he_capabilities: Option<&HeCapabilities>,
+    ) -> Result<Ie, IeError> {
         Ok(match ie_id {
+            AdvertisementProtocol::ID => {
+                Ie::AdvertisementProtocol(AdvertisementProtocol::new(ie_data)?)
+            }
             Antenna::ID => Ie::Antenna(Antenna::new(ie_data)?),
             ApChannelReport::ID => Ie::ApChannelReport(ApChannelReport::new(ie_data)?),
             BssLoad::ID => Ie::BssLoad(BssLoad::new(ie_data)?),
+            ChannelSwitchAnnouncement::ID => {
+                Ie::ChannelSwitchAnnouncement(ChannelSwitchAnnouncement::new(ie_data)?)
+            }
+            ChannelSwitchWrapper::ID => {
+                Ie::ChannelSwitchWrapper(ChannelSwitchWrapper::new(ie_data)?)
+            }
             Country::ID => Ie::Country(Country::new(ie_data)?),
             DsParameterSet::ID => Ie::DsParameterSet(DsParameterSet::new(ie_data)?),
             ErpInfo::ID => Ie::ErpInfo(ErpInfo::new(ie_data)?),
@@ -182,25 +357,48 @@ impl Ie {
             ExtendedSupportedRates::ID => {
                 Ie::ExtendedSupportedRates(ExtendedSupportedRates::new(ie_data))
             }
+            ExtendedChannelSwitchAnnouncement::ID => Ie::ExtendedChannelSwitchAnnouncement(
+                ExtendedChannelSwitchAnnouncement::new(ie_data)?,
+            ),
+            FastBssTransition::ID => Ie::FastBssTransition(FastBssTransition::new(ie_data)?),
             HtCapabilities::ID => Ie::HtCapabilities(HtCapabilities::new(ie_data)?),
             HtOperation::ID => Ie::HtOperation(HtOperation::new(ie_data)?),
             IbssParameterSet::ID => Ie::IbssParameterSet(IbssParameterSet::new(ie_data)?),
+            Interworking::ID => Ie::Interworking(Interworking::new(ie_data)?),
             MeasurementPilotTransmission::ID => {
                 Ie::MeasurementPilotTransmission(MeasurementPilotTransmission::new(ie_data)?)
             }
             MeshConfiguration::ID => Ie::MeshConfiguration(MeshConfiguration::new(ie_data)?),
             MeshId::ID => Ie::MeshId(MeshId::new(ie_data)),
+            MobilityDomain::ID => Ie::MobilityDomain(MobilityDomain::new(ie_data)?),
+            MultipleBssid::ID => Ie::MultipleBssid(MultipleBssid::new(ie_data)?),
+            MultipleBssidIndex::ID => Ie::MultipleBssidIndex(MultipleBssidIndex::new(ie_data)?),
+            NontransmittedBssidCapability::ID => {
+                Ie::NontransmittedBssidCapability(NontransmittedBssidCapability::new(ie_data)?)
+            }
             OverlappingBssScanParams::ID => {
                 Ie::OverlappingBssScanParams(OverlappingBssScanParams::new(ie_data)?)
             }
             PowerConstraint::ID => Ie::PowerConstraint(PowerConstraint::new(ie_data)?),
+            ReducedNeighborReport::ID => {
+                Ie::ReducedNeighborReport(ReducedNeighborReport::new(ie_data)?)
+            }
             RmEnabledCapabilities::ID => {
                 Ie::RmEnabledCapabilities(RmEnabledCapabilities::new(ie_data)?)
             }
-            Rsn::ID => Ie::Rsn(Rsn::new(ie_data)),
+            Quiet::ID => Ie::Quiet(Quiet::new(ie_data)?),
+            QuietChannel::ID => Ie::QuietChannel(QuietChannel::new(ie_data)?),
+            RoamingConsortium::ID => Ie::RoamingConsortium(RoamingConsortium::new(ie_data)?),
+            Rsn::ID => ie_or_unknown(Rsn::new(ie_data.clone()), Ie::Rsn, ie_data, ie_id),
+            SecondaryChannelOffsetElement::ID => {
+                Ie::SecondaryChannelOffset(SecondaryChannelOffsetElement::new(ie_data)?)
+            }
             Ssid::ID => Ie::Ssid(Ssid::new(ie_data)),
+            SupportedOperatingClasses::ID => {
+                Ie::SupportedOperatingClasses(SupportedOperatingClasses::new(ie_data)?)
+            }
             SupportedRates::ID => Ie::SupportedRates(SupportedRates::new(ie_data)),
-            Tim::ID => Ie::Tim(Tim::new(ie_data)),
+            Tim::ID => ie_or_unknown(Tim::new(ie_data.clone()), Ie::Tim, ie_data, ie_id),
             TransmitPowerEnvelope::ID => {
                 Ie::TransmitPowerEnvelope(TransmitPowerEnvelope::new(ie_data)?)
             }
@@ -209,16 +407,40 @@ impl Ie {
             }
             VendorSpecific::ID => {
                 if ie_data.starts_with(&Wpa::OUI) {
-                    Ie::Wpa(Wpa::new(ie_data))
+                    vendor_ie_or_vendor_specific(Wpa::new(ie_data.clone()), Ie::Wpa, ie_data)
+                } else if ie_data.starts_with(&Wmm::OUI) {
+                    vendor_ie_or_vendor_specific(Wmm::new(ie_data.clone()), Ie::Wmm, ie_data)
+                } else if ie_data.starts_with(&Wps::OUI) {
+                    vendor_ie_or_vendor_specific(Wps::new(ie_data.clone()), Ie::Wps, ie_data)
+                } else if ie_data.starts_with(&P2p::OUI) {
+                    vendor_ie_or_vendor_specific(P2p::new(ie_data.clone()), Ie::P2p, ie_data)
+                } else if ie_data.starts_with(&Hotspot20Indication::OUI) {
+                    vendor_ie_or_vendor_specific(
+                        Hotspot20Indication::new(ie_data.clone()),
+                        Ie::Hotspot20Indication,
+                        ie_data,
+                    )
                 } else {
                     Ie::VendorSpecific(VendorSpecific::new(ie_data))
                 }
             }
             VhtCapabilities::ID => Ie::VhtCapabilities(VhtCapabilities::new(ie_data)?),
             VhtOperation::ID => Ie::VhtOperation(VhtOperation::new(ie_data)?),
+            WideBandwidthChannelSwitch::ID => {
+                Ie::WideBandwidthChannelSwitch(WideBandwidthChannelSwitch::new(ie_data)?)
+            }
             u8::MAX => match ie_id_ext {
-                HeCapabilities::ID_EXT => Ie::HeCapabilities(HeCapabilities::new(ie_data)),
-                HeOperation::ID_EXT => Ie::HeOperation(HeOperation::new(ie_data)),
+                EhtCapabilities::ID_EXT => {
+                    Ie::EhtCapabilities(EhtCapabilities::new(ie_data, he_capabilities)?)
+                }
+                EhtOperation::ID_EXT => Ie::EhtOperation(EhtOperation::new(ie_data)?),
+                HeCapabilities::ID_EXT => Ie::HeCapabilities(HeCapabilities::new(ie_data)?),
+                HeOperation::ID_EXT => Ie::HeOperation(HeOperation::new(ie_data)?),
+                MaxChannelSwitchTime::ID_EXT => {
+                    Ie::MaxChannelSwitchTime(MaxChannelSwitchTime::new(ie_data)?)
+                }
+                MultiLink::ID_EXT => Ie::MultiLink(MultiLink::new(ie_data)?),
+                NonInheritance::ID_EXT => Ie::NonInheritance(NonInheritance::new(ie_data)?),
                 _ => Ie::Unknown(Unknown::new(ie_data, ie_id, ie_id_ext)),
             },
             _ => Ie::Unknown(Unknown::new(ie_data, ie_id, ie_id_ext)),
@@ -265,16 +487,11 @@ pub enum IeError {
 pub fn from_bytes(bytes: &[u8]) -> Result<Vec<Ie>, IeError> {
     let mut bytes = Cursor::new(bytes);
 
-    let mut ies = Vec::new();
-
-    loop {
-        // The first byte of the IE is the ID
-        // Break out of the loop if reading the byte fails
-        let ie_id = match bytes.read_u8() {
-            Ok(ie_id) => ie_id,
-            _ => break,
-        };
+    let mut elements = Vec::new();
 
+    // The first byte of the IE is the ID
+    // Break out of the loop if reading the byte fails
+    while let Ok(ie_id) = bytes.read_u8() {
         // The second byte of the IE is the number of bytes of data
         // Break out of the loop if reading the byte fails
         let ie_len = match bytes.read_u8() {
@@ -282,25 +499,80 @@ pub fn from_bytes(bytes: &[u8]) -> Result<Vec<Ie>, IeError> {
             _ => break,
         };
 
-        // If the element ID is 255 then the next byte is the element ID extension
-        let ie_id_ext = match ie_id {
-            u8::MAX => bytes.read_u8().ok(),
-            _ => None,
+        // If the element ID is 255 then the next byte is the element ID extension, which is
+        // counted in the length
+        let (ie_id_ext, ie_data_len) = match ie_id {
+            u8::MAX => match bytes.read_u8() {
+                Ok(ie_id_ext) => (Some(ie_id_ext), ie_len.saturating_sub(1)),
+                _ => break,
+            },
+            _ => (None, ie_len),
         };
 
-        // Bytes [2..ie_len+2] or [3..ie_len+3] is the data
-        let ie_data = {
-            let mut ie_data = vec![0; ie_len as usize];
+        // Bytes [2..ie_len+2] or [3..ie_len+2] is the data
+        let mut ie_data = {
+            let mut ie_data = vec![0; ie_data_len as usize];
             match bytes.read_exact(&mut ie_data) {
                 Ok(_) => ie_data,
                 _ => break,
             }
         };
 
-        // Using the IE's ID, try to create an information element and add it to the vector of IEs
-        // If there's an error creating an information element, return the error
-        ies.push(Ie::new(ie_data, ie_id, ie_id_ext)?);
+        // An element with more than 255 bytes of data is split across Fragment elements that
+        // immediately follow it, each full one followed by another
+        let mut fragment_len = ie_len;
+        while fragment_len == u8::MAX
+            && bytes.get_ref().get(bytes.position() as usize) == Some(&FRAGMENT_ID)
+        {
+            bytes.set_position(bytes.position() + 1);
+            fragment_len = match bytes.read_u8() {
+                Ok(fragment_len) => fragment_len,
+                _ => break,
+            };
+
+            let mut fragment_data = vec![0; fragment_len as usize];
+            match bytes.read_exact(&mut fragment_data) {
+                Ok(_) => ie_data.extend(fragment_data),
+                _ => break,
+            }
+        }
+
+        elements.push((ie_data, ie_id, ie_id_ext));
     }
 
-    Ok(ies)
+    // Which EHT-MCS maps the EHT Capabilities element has depends on the HE Capabilities element
+    // in the same frame
+    let he_capabilities = elements
+        .iter()
+        .find(|(_, ie_id, ie_id_ext)| {
+            *ie_id == HeCapabilities::ID && *ie_id_ext == HeCapabilities::ID_EXT
+        })
+        .and_then(|(ie_data, _, _)| HeCapabilities::new(ie_data.clone()).ok());
+
+    // Using the IE's ID, try to create an information element for each of the elements
+    // If there's an error creating an information element, return the error
+    elements
+        .into_iter()
+        .map(|(ie_data, ie_id, ie_id_ext)| {
+            Ie::new(ie_data, ie_id, ie_id_ext, he_capabilities.as_ref())
+        })
+        .collect()
+}
+
+// Parses the EHT Capabilities element again with the HE Capabilities element of a list of
+// elements that was put together from several lists, like a nontransmitted BSS's elements
+fn reparse_eht_capabilities(ies: &mut [Ie]) {
+    let he_capabilities = ies.iter().find_map(|ie| match ie {
+        Ie::HeCapabilities(he_capabilities) => Some(he_capabilities.clone()),
+        _ => None,
+    });
+    for ie in ies.iter_mut() {
+        if let Ie::EhtCapabilities(eht_capabilities) = ie {
+            if let Ok(reparsed_eht_capabilities) =
+                EhtCapabilities::new(eht_capabilities.bytes().to_vec(), he_capabilities.as_ref())
+            {
+                *eht_capabilities = reparsed_eht_capabilities;
+            }
+        }
+    }
 }
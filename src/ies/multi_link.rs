@@ -0,0 +1,623 @@
+use super::{Field, Ie, IeError, InformationElement};
+use crate::CapabilityInfo;
+use bitvec::prelude::*;
+use byteorder::{LittleEndian, ReadBytesExt};
+use macaddr::MacAddr6;
+use std::{
+    fmt::Display,
+    io::{Cursor, Read},
+};
+
+// Link Info subelement IDs
+const PER_STA_PROFILE_ID: u8 = 0;
+const FRAGMENT_ID: u8 = 254;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MultiLinkType {
+    Basic,
+    ProbeRequest,
+    Reconfiguration,
+    Tdls,
+    PriorityAccess,
+    Reserved(u8),
+}
+
+impl From<u8> for MultiLinkType {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => MultiLinkType::Basic,
+            1 => MultiLinkType::ProbeRequest,
+            2 => MultiLinkType::Reconfiguration,
+            3 => MultiLinkType::Tdls,
+            4 => MultiLinkType::PriorityAccess,
+            value => MultiLinkType::Reserved(value),
+        }
+    }
+}
+
+impl Display for MultiLinkType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MultiLinkType::Basic => write!(f, "Basic"),
+            MultiLinkType::ProbeRequest => write!(f, "Probe Request"),
+            MultiLinkType::Reconfiguration => write!(f, "Reconfiguration"),
+            MultiLinkType::Tdls => write!(f, "TDLS"),
+            MultiLinkType::PriorityAccess => write!(f, "Priority Access"),
+            MultiLinkType::Reserved(value) => write!(f, "Reserved ({})", value),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmlCapabilities {
+    bits: BitArray<LocalBits, [u8; 2]>,
+}
+
+impl EmlCapabilities {
+    pub fn new(eml_capabilities: [u8; 2]) -> EmlCapabilities {
+        EmlCapabilities {
+            bits: BitArray::new(eml_capabilities),
+        }
+    }
+
+    pub fn emlsr_support(&self) -> bool {
+        self.bits[0]
+    }
+
+    pub fn emlsr_padding_delay_us(&self) -> u16 {
+        match self.bits[1..=3].load::<u8>() {
+            0 => 0,
+            value => 16 << value,
+        }
+    }
+
+    pub fn emlsr_transition_delay_us(&self) -> u16 {
+        match self.bits[4..=6].load::<u8>() {
+            0 => 0,
+            value => 8 << value,
+        }
+    }
+
+    pub fn emlmr_support(&self) -> bool {
+        self.bits[7]
+    }
+
+    pub fn transition_timeout_us(&self) -> u32 {
+        match self.bits[11..=14].load::<u8>() {
+            0 => 0,
+            value => 64 << value,
+        }
+    }
+
+    pub fn fields(&self) -> Vec<Field> {
+        vec![
+            Field::new("EMLSR Support", self.emlsr_support()),
+            Field::new(
+                "EMLSR Padding Delay",
+                format!("{} μs", self.emlsr_padding_delay_us()),
+            ),
+            Field::new(
+                "EMLSR Transition Delay",
+                format!("{} μs", self.emlsr_transition_delay_us()),
+            ),
+            Field::new("EMLMR Support", self.emlmr_support()),
+            Field::new(
+                "Transition Timeout",
+                format!("{} μs", self.transition_timeout_us()),
+            ),
+        ]
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MldCapabilities {
+    bits: BitArray<LocalBits, [u8; 2]>,
+}
+
+impl MldCapabilities {
+    pub fn new(mld_capabilities: [u8; 2]) -> MldCapabilities {
+        MldCapabilities {
+            bits: BitArray::new(mld_capabilities),
+        }
+    }
+
+    pub fn max_simultaneous_links(&self) -> u8 {
+        self.bits[0..=3].load::<u8>() + 1
+    }
+
+    pub fn srs_support(&self) -> bool {
+        self.bits[4]
+    }
+
+    pub fn tid_to_link_mapping_negotiation_support(&self) -> u8 {
+        self.bits[5..=6].load()
+    }
+
+    pub fn frequency_separation_for_str(&self) -> u8 {
+        self.bits[7..=11].load()
+    }
+
+    pub fn aar_support(&self) -> bool {
+        self.bits[12]
+    }
+
+    pub fn fields(&self) -> Vec<Field> {
+        vec![
+            Field::new(
+                "Maximum Number of Simultaneous Links",
+                self.max_simultaneous_links(),
+            ),
+            Field::new("SRS Support", self.srs_support()),
+            Field::new(
+                "TID-to-Link Mapping Negotiation Support",
+                self.tid_to_link_mapping_negotiation_support(),
+            ),
+            Field::new(
+                "Frequency Separation for STR",
+                self.frequency_separation_for_str(),
+            ),
+            Field::new("AAR Support", self.aar_support()),
+        ]
+    }
+}
+
+/// A link of the MLD other than the one the Multi-Link element was received on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PerStaProfile {
+    link_id: u8,
+    complete_profile: bool,
+    sta_mac_address: Option<MacAddr6>,
+    beacon_interval_tu: Option<u16>,
+    tsf_offset: Option<i64>,
+    dtim_info: Option<(u8, u8)>,
+    bss_parameters_change_count: Option<u8>,
+    capability_info: Option<CapabilityInfo>,
+    ies: Vec<Ie>,
+}
+
+impl PerStaProfile {
+    fn new(bytes: &[u8]) -> Option<PerStaProfile> {
+        let mut cursor = Cursor::new(bytes);
+        let sta_control = cursor.read_u16::<LittleEndian>().ok()?;
+        let sta_control = sta_control.view_bits::<Lsb0>();
+
+        let mut per_sta_profile = PerStaProfile {
+            link_id: sta_control[0..=3].load(),
+            complete_profile: sta_control[4],
+            sta_mac_address: None,
+            beacon_interval_tu: None,
+            tsf_offset: None,
+            dtim_info: None,
+            bss_parameters_change_count: None,
+            capability_info: None,
+            ies: Vec::new(),
+        };
+
+        // The STA Info Length counts itself, so the STA Profile starts right after the STA Info
+        let sta_info_length = cursor.read_u8().ok()? as usize;
+        let sta_profile_offset = 2 + sta_info_length;
+
+        if sta_control[5] {
+            let mut sta_mac_address = [0; 6];
+            cursor.read_exact(&mut sta_mac_address).ok()?;
+            per_sta_profile.sta_mac_address = Some(MacAddr6::from(sta_mac_address));
+        }
+        if sta_control[6] {
+            per_sta_profile.beacon_interval_tu = Some(cursor.read_u16::<LittleEndian>().ok()?);
+        }
+        if sta_control[7] {
+            per_sta_profile.tsf_offset = Some(cursor.read_i64::<LittleEndian>().ok()?);
+        }
+        if sta_control[8] {
+            per_sta_profile.dtim_info = Some((cursor.read_u8().ok()?, cursor.read_u8().ok()?));
+        }
+        if sta_control[9] {
+            let nstr_bitmap_length = if sta_control[10] { 2 } else { 1 };
+            cursor.set_position(cursor.position() + nstr_bitmap_length);
+        }
+        if sta_control[11] {
+            per_sta_profile.bss_parameters_change_count = Some(cursor.read_u8().ok()?);
+        }
+
+        // A complete profile carries the link's Capability Information and elements
+        if per_sta_profile.complete_profile {
+            if let Some(sta_profile) = bytes.get(sta_profile_offset..) {
+                if sta_profile.len() >= CapabilityInfo::LENGTH {
+                    per_sta_profile.capability_info =
+                        Some(CapabilityInfo::new([sta_profile[0], sta_profile[1]]));
+                    per_sta_profile.ies = super::from_bytes(&sta_profile[CapabilityInfo::LENGTH..])
+                        .unwrap_or_default();
+                }
+            }
+        }
+
+        Some(per_sta_profile)
+    }
+
+    pub fn link_id(&self) -> u8 {
+        self.link_id
+    }
+
+    pub fn complete_profile(&self) -> bool {
+        self.complete_profile
+    }
+
+    pub fn sta_mac_address(&self) -> Option<MacAddr6> {
+        self.sta_mac_address
+    }
+
+    pub fn beacon_interval_tu(&self) -> Option<u16> {
+        self.beacon_interval_tu
+    }
+
+    pub fn tsf_offset(&self) -> Option<i64> {
+        self.tsf_offset
+    }
+
+    pub fn dtim_count(&self) -> Option<u8> {
+        self.dtim_info.map(|(dtim_count, _)| dtim_count)
+    }
+
+    pub fn dtim_period(&self) -> Option<u8> {
+        self.dtim_info.map(|(_, dtim_period)| dtim_period)
+    }
+
+    pub fn bss_parameters_change_count(&self) -> Option<u8> {
+        self.bss_parameters_change_count
+    }
+
+    pub fn capability_info(&self) -> Option<&CapabilityInfo> {
+        self.capability_info.as_ref()
+    }
+
+    pub fn ies(&self) -> &[Ie] {
+        &self.ies
+    }
+
+    pub fn fields(&self) -> Vec<Field> {
+        let mut fields = vec![
+            Field::new("Link ID", self.link_id()),
+            Field::new("Complete Profile", self.complete_profile()),
+        ];
+
+        if let Some(sta_mac_address) = self.sta_mac_address() {
+            fields.push(Field::new("STA MAC Address", sta_mac_address));
+        }
+        if let Some(beacon_interval_tu) = self.beacon_interval_tu() {
+            fields.push(Field::new(
+                "Beacon Interval",
+                format!("{} TU", beacon_interval_tu),
+            ));
+        }
+        if let Some(tsf_offset) = self.tsf_offset() {
+            fields.push(Field::new("TSF Offset", tsf_offset));
+        }
+        if let Some((dtim_count, dtim_period)) = self.dtim_info {
+            fields.push(Field::new("DTIM Count", dtim_count));
+            fields.push(Field::new("DTIM Period", dtim_period));
+        }
+        if let Some(bss_parameters_change_count) = self.bss_parameters_change_count() {
+            fields.push(Field::new(
+                "BSS Parameters Change Count",
+                bss_parameters_change_count,
+            ));
+        }
+        for ie in self.ies() {
+            fields.push(Field::with_subfields(
+                ie.name(),
+                format!("{:02?}", ie.bytes()),
+                ie.information_fields(),
+            ));
+        }
+
+        fields
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultiLink {
+    bytes: Vec<u8>,
+    multi_link_type: MultiLinkType,
+    mld_mac_address: Option<MacAddr6>,
+    link_id: Option<u8>,
+    bss_parameters_change_count: Option<u8>,
+    medium_synchronization_delay_information: Option<u16>,
+    eml_capabilities: Option<EmlCapabilities>,
+    mld_capabilities: Option<MldCapabilities>,
+    ap_mld_id: Option<u8>,
+    extended_mld_capabilities: Option<u16>,
+    per_sta_profiles: Vec<PerStaProfile>,
+}
+
+impl MultiLink {
+    pub const MIN_LENGTH: usize = 2;
+
+    pub fn new(bytes: Vec<u8>) -> Result<MultiLink, IeError> {
+        if bytes.len() < Self::MIN_LENGTH {
+            return Err(IeError::InvalidLength {
+                ie_name: Self::NAME,
+                expected_length: Self::MIN_LENGTH,
+                actual_length: bytes.len(),
+            });
+        }
+
+        let multi_link_control = u16::from_le_bytes([bytes[0], bytes[1]]);
+        let multi_link_control = multi_link_control.view_bits::<Lsb0>();
+
+        let mut multi_link = MultiLink {
+            bytes: bytes.clone(),
+            multi_link_type: MultiLinkType::from(multi_link_control[0..=2].load::<u8>()),
+            mld_mac_address: None,
+            link_id: None,
+            bss_parameters_change_count: None,
+            medium_synchronization_delay_information: None,
+            eml_capabilities: None,
+            mld_capabilities: None,
+            ap_mld_id: None,
+            extended_mld_capabilities: None,
+            per_sta_profiles: Vec::new(),
+        };
+
+        // Only the Basic variant's Common Info is decoded
+        if multi_link.multi_link_type != MultiLinkType::Basic {
+            return Ok(multi_link);
+        }
+
+        let common_info_length = match bytes.get(2) {
+            Some(common_info_length) => *common_info_length as usize,
+            None => return Ok(multi_link),
+        };
+        // The Common Info Length counts itself, so it's at least 1
+        if common_info_length == 0 {
+            return Err(IeError::InvalidLength {
+                ie_name: Self::NAME,
+                expected_length: 1,
+                actual_length: common_info_length,
+            });
+        }
+        let common_info = match bytes.get(2..2 + common_info_length) {
+            Some(common_info) => common_info,
+            None => return Ok(multi_link),
+        };
+
+        multi_link.read_common_info(&common_info[1..], &multi_link_control[4..]);
+        multi_link.per_sta_profiles = read_subelements(&bytes[2 + common_info_length..])
+            .into_iter()
+            .filter(|(subelement_id, _)| *subelement_id == PER_STA_PROFILE_ID)
+            .filter_map(|(_, subelement)| PerStaProfile::new(&subelement))
+            .collect();
+
+        Ok(multi_link)
+    }
+
+    fn read_common_info(&mut self, common_info: &[u8], presence_bitmap: &BitSlice<Lsb0, u16>) {
+        let mut cursor = Cursor::new(common_info);
+
+        let mut mld_mac_address = [0; 6];
+        if cursor.read_exact(&mut mld_mac_address).is_err() {
+            return;
+        }
+        self.mld_mac_address = Some(MacAddr6::from(mld_mac_address));
+
+        if presence_bitmap[0] {
+            self.link_id = cursor.read_u8().ok().map(|link_info| link_info & 0x0F);
+        }
+        if presence_bitmap[1] {
+            self.bss_parameters_change_count = cursor.read_u8().ok();
+        }
+        if presence_bitmap[2] {
+            self.medium_synchronization_delay_information = cursor.read_u16::<LittleEndian>().ok();
+        }
+        if presence_bitmap[3] {
+            self.eml_capabilities = cursor
+                .read_u16::<LittleEndian>()
+                .ok()
+                .map(|eml_capabilities| EmlCapabilities::new(eml_capabilities.to_le_bytes()));
+        }
+        if presence_bitmap[4] {
+            self.mld_capabilities = cursor
+                .read_u16::<LittleEndian>()
+                .ok()
+                .map(|mld_capabilities| MldCapabilities::new(mld_capabilities.to_le_bytes()));
+        }
+        if presence_bitmap[5] {
+            self.ap_mld_id = cursor.read_u8().ok();
+        }
+        if presence_bitmap[6] {
+            self.extended_mld_capabilities = cursor.read_u16::<LittleEndian>().ok();
+        }
+    }
+
+    pub fn multi_link_type(&self) -> MultiLinkType {
+        self.multi_link_type
+    }
+
+    pub fn mld_mac_address(&self) -> Option<MacAddr6> {
+        self.mld_mac_address
+    }
+
+    /// The ID of the link the element was received on.
+    pub fn link_id(&self) -> Option<u8> {
+        self.link_id
+    }
+
+    pub fn bss_parameters_change_count(&self) -> Option<u8> {
+        self.bss_parameters_change_count
+    }
+
+    pub fn medium_synchronization_delay_information(&self) -> Option<u16> {
+        self.medium_synchronization_delay_information
+    }
+
+    pub fn eml_capabilities(&self) -> Option<&EmlCapabilities> {
+        self.eml_capabilities.as_ref()
+    }
+
+    pub fn mld_capabilities(&self) -> Option<&MldCapabilities> {
+        self.mld_capabilities.as_ref()
+    }
+
+    pub fn ap_mld_id(&self) -> Option<u8> {
+        self.ap_mld_id
+    }
+
+    pub fn extended_mld_capabilities(&self) -> Option<u16> {
+        self.extended_mld_capabilities
+    }
+
+    pub fn per_sta_profiles(&self) -> &[PerStaProfile] {
+        &self.per_sta_profiles
+    }
+}
+
+// Splits the Link Info field into subelements, joining any subelement longer than 255 octets with
+// the Fragment subelements that follow it
+fn read_subelements(bytes: &[u8]) -> Vec<(u8, Vec<u8>)> {
+    let mut cursor = Cursor::new(bytes);
+    let mut subelements: Vec<(u8, Vec<u8>)> = Vec::new();
+    let mut previous_length = 0;
+
+    while let (Ok(subelement_id), Ok(subelement_length)) = (cursor.read_u8(), cursor.read_u8()) {
+        let mut data = vec![0; subelement_length as usize];
+        if cursor.read_exact(&mut data).is_err() {
+            break;
+        }
+
+        match subelements.last_mut() {
+            Some((_, previous_data)) if subelement_id == FRAGMENT_ID && previous_length == 255 => {
+                previous_data.extend(data)
+            }
+            _ => subelements.push((subelement_id, data)),
+        }
+        previous_length = subelement_length;
+    }
+
+    subelements
+}
+
+impl InformationElement for MultiLink {
+    const NAME: &'static str = "Multi-Link";
+    const ID: u8 = 255;
+    const ID_EXT: Option<u8> = Some(107);
+
+    fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    fn information_fields(&self) -> Vec<Field> {
+        let mut information_fields = vec![Field::new("Type", self.multi_link_type())];
+
+        if let Some(mld_mac_address) = self.mld_mac_address() {
+            information_fields.push(Field::new("MLD MAC Address", mld_mac_address));
+        }
+        if let Some(link_id) = self.link_id() {
+            information_fields.push(Field::new("Link ID", link_id));
+        }
+        if let Some(bss_parameters_change_count) = self.bss_parameters_change_count() {
+            information_fields.push(Field::new(
+                "BSS Parameters Change Count",
+                bss_parameters_change_count,
+            ));
+        }
+        if let Some(medium_synchronization_delay_information) =
+            self.medium_synchronization_delay_information()
+        {
+            information_fields.push(Field::new(
+                "Medium Synchronization Delay Information",
+                format!("{:#06x}", medium_synchronization_delay_information),
+            ));
+        }
+        if let Some(eml_capabilities) = self.eml_capabilities() {
+            information_fields.push(Field::with_subfields(
+                "EML Capabilities",
+                format!("{:02?}", eml_capabilities.bits.as_raw_slice()),
+                eml_capabilities.fields(),
+            ));
+        }
+        if let Some(mld_capabilities) = self.mld_capabilities() {
+            information_fields.push(Field::with_subfields(
+                "MLD Capabilities and Operations",
+                format!("{:02?}", mld_capabilities.bits.as_raw_slice()),
+                mld_capabilities.fields(),
+            ));
+        }
+        if let Some(ap_mld_id) = self.ap_mld_id() {
+            information_fields.push(Field::new("AP MLD ID", ap_mld_id));
+        }
+        if let Some(extended_mld_capabilities) = self.extended_mld_capabilities() {
+            information_fields.push(Field::new(
+                "Extended MLD Capabilities and Operations",
+                format!("{:#06x}", extended_mld_capabilities),
+            ));
+        }
+        for per_sta_profile in self.per_sta_profiles() {
+            information_fields.push(Field::with_subfields(
+                "Per-STA Profile",
+                per_sta_profile.link_id(),
+                per_sta_profile.fields(),
+            ));
+        }
+
+        information_fields
+    }
+}
+
+impl_display_for_ie!(MultiLink);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn basic_multi_link_with_fragmented_per_sta_profile() {
+        let mut bytes = vec![
+            0x30, 0x01, 0x0B, 0x02, 0x11, 0x22, 0x33, 0x44, 0x55, 0x01, 0x03, 0x01, 0x00,
+        ];
+
+        // A complete per-STA profile for link 2 whose elements push it past 255 octets
+        let mut per_sta_profile = vec![
+            0x72, 0x00, 0x09, 0x02, 0x11, 0x22, 0x33, 0x44, 0x66, 0x64, 0x00, 0x11, 0x04,
+        ];
+        per_sta_profile.extend([0xDD, 0xFA]);
+        per_sta_profile.extend(vec![0x00; 250]);
+        bytes.extend([PER_STA_PROFILE_ID, 0xFF]);
+        bytes.extend(&per_sta_profile[..255]);
+        bytes.extend([FRAGMENT_ID, (per_sta_profile.len() - 255) as u8]);
+        bytes.extend(&per_sta_profile[255..]);
+
+        let multi_link = MultiLink::new(bytes).unwrap();
+        assert_eq!(multi_link.multi_link_type(), MultiLinkType::Basic);
+        assert_eq!(
+            multi_link.mld_mac_address(),
+            Some(MacAddr6::new(0x02, 0x11, 0x22, 0x33, 0x44, 0x55))
+        );
+        assert_eq!(multi_link.link_id(), Some(1));
+        assert_eq!(multi_link.bss_parameters_change_count(), Some(3));
+        assert_eq!(
+            multi_link
+                .mld_capabilities()
+                .unwrap()
+                .max_simultaneous_links(),
+            2
+        );
+
+        let per_sta_profile = &multi_link.per_sta_profiles()[0];
+        assert_eq!(per_sta_profile.link_id(), 2);
+        assert!(per_sta_profile.complete_profile());
+        assert_eq!(
+            per_sta_profile.sta_mac_address(),
+            Some(MacAddr6::new(0x02, 0x11, 0x22, 0x33, 0x44, 0x66))
+        );
+        assert_eq!(per_sta_profile.beacon_interval_tu(), Some(100));
+        assert!(per_sta_profile.capability_info().unwrap().privacy());
+        assert_eq!(per_sta_profile.ies().len(), 1);
+    }
+
+    #[test]
+    fn zero_common_info_length() {
+        assert!(matches!(
+            MultiLink::new(vec![0x00, 0x00, 0x00]),
+            Err(IeError::InvalidLength { .. })
+        ));
+    }
+}
@@ -0,0 +1,264 @@
+use super::{
+    Field, Ie, IeError, InformationElement, MultipleBssidIndex, NonInheritance, VendorSpecific,
+};
+use crate::CapabilityInfo;
+use macaddr::MacAddr6;
+
+// Subelement IDs
+const NONTRANSMITTED_BSSID_PROFILE: u8 = 0;
+
+/// The elements of one nontransmitted BSS, which are added to or override the elements of the
+/// transmitted BSS.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NontransmittedBssidProfile {
+    ies: Vec<Ie>,
+}
+
+impl NontransmittedBssidProfile {
+    pub fn ies(&self) -> &[Ie] {
+        &self.ies
+    }
+
+    pub fn capability_info(&self) -> Option<CapabilityInfo> {
+        self.ies.iter().find_map(|ie| match ie {
+            Ie::NontransmittedBssidCapability(capability) => Some(capability.capability_info()),
+            _ => None,
+        })
+    }
+
+    pub fn ssid(&self) -> Option<&str> {
+        self.ies.iter().find_map(|ie| match ie {
+            Ie::Ssid(ssid) => ssid.as_str().ok(),
+            _ => None,
+        })
+    }
+
+    fn multiple_bssid_index(&self) -> Option<&MultipleBssidIndex> {
+        self.ies.iter().find_map(|ie| match ie {
+            Ie::MultipleBssidIndex(multiple_bssid_index) => Some(multiple_bssid_index),
+            _ => None,
+        })
+    }
+
+    pub fn bssid_index(&self) -> Option<u8> {
+        self.multiple_bssid_index()
+            .map(|multiple_bssid_index| multiple_bssid_index.bssid_index())
+    }
+
+    pub fn non_inheritance(&self) -> Option<&NonInheritance> {
+        self.ies.iter().find_map(|ie| match ie {
+            Ie::NonInheritance(non_inheritance) => Some(non_inheritance),
+            _ => None,
+        })
+    }
+
+    /// Appends the elements of a profile that was split across Multiple BSSID elements.
+    pub(crate) fn extend(&mut self, other: NontransmittedBssidProfile) {
+        self.ies.extend(other.ies);
+    }
+
+    /// The elements of the nontransmitted BSS, which are the elements of the transmitted BSS with
+    /// the ones in the profile overriding them and the ones in the Non-Inheritance element left
+    /// out. The Multiple BSSID elements aren't inherited.
+    pub fn inherited_ies(&self, transmitted_ies: &[Ie]) -> Vec<Ie> {
+        let mut profile_ies: Vec<&Ie> = self
+            .ies
+            .iter()
+            .filter(|ie| {
+                !matches!(
+                    ie,
+                    Ie::NontransmittedBssidCapability(_) | Ie::NonInheritance(_)
+                )
+            })
+            .collect();
+
+        let mut ies = Vec::new();
+        for ie in transmitted_ies {
+            if matches!(ie, Ie::MultipleBssid(_))
+                || self
+                    .non_inheritance()
+                    .is_some_and(|non_inheritance| non_inheritance.excludes(ie))
+            {
+                continue;
+            }
+
+            match profile_ies
+                .iter()
+                .position(|profile_ie| overrides(profile_ie, ie))
+            {
+                Some(index) => ies.push(profile_ies.remove(index).clone()),
+                None => ies.push(ie.clone()),
+            }
+        }
+        ies.extend(profile_ies.into_iter().cloned());
+        super::reparse_eht_capabilities(&mut ies);
+
+        ies
+    }
+}
+
+// Whether the element in a profile replaces the element of the transmitted BSS. Vendor specific
+// elements are told apart by their OUI and type.
+fn overrides(profile_ie: &Ie, transmitted_ie: &Ie) -> bool {
+    if profile_ie.id() != transmitted_ie.id() || profile_ie.id_ext() != transmitted_ie.id_ext() {
+        return false;
+    }
+
+    if profile_ie.id() == VendorSpecific::ID {
+        profile_ie.bytes().get(..4) == transmitted_ie.bytes().get(..4)
+    } else {
+        true
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultipleBssid {
+    bytes: Vec<u8>,
+    nontransmitted_bssid_profiles: Vec<NontransmittedBssidProfile>,
+}
+
+impl MultipleBssid {
+    pub const MIN_LENGTH: usize = 1;
+
+    pub fn new(bytes: Vec<u8>) -> Result<MultipleBssid, IeError> {
+        if bytes.len() < Self::MIN_LENGTH {
+            return Err(IeError::InvalidLength {
+                ie_name: Self::NAME,
+                expected_length: Self::MIN_LENGTH,
+                actual_length: bytes.len(),
+            });
+        }
+
+        let mut nontransmitted_bssid_profiles = Vec::new();
+        let mut remaining = &bytes[1..];
+        while let [subelement_id, length, rest @ ..] = remaining {
+            let data = match rest.get(..*length as usize) {
+                Some(data) => data,
+                None => break,
+            };
+            remaining = &rest[data.len()..];
+
+            if *subelement_id == NONTRANSMITTED_BSSID_PROFILE {
+                nontransmitted_bssid_profiles.push(NontransmittedBssidProfile {
+                    ies: super::from_bytes(data).unwrap_or_default(),
+                });
+            }
+        }
+
+        Ok(MultipleBssid {
+            bytes,
+            nontransmitted_bssid_profiles,
+        })
+    }
+
+    /// The number of low-order bits of the BSSIDs that differ between the BSSs of the set.
+    pub fn max_bssid_indicator(&self) -> u8 {
+        self.bytes[0]
+    }
+
+    /// The maximum number of BSSs in the set, including the transmitted BSS.
+    pub fn max_bssids(&self) -> u16 {
+        1 << self.max_bssid_indicator().min(8)
+    }
+
+    pub fn nontransmitted_bssid_profiles(&self) -> &[NontransmittedBssidProfile] {
+        &self.nontransmitted_bssid_profiles
+    }
+
+    /// The BSSID of the nontransmitted BSS with the BSSID index, which is the transmitted BSSID
+    /// with its low-order bits incremented by the index modulo the maximum number of BSSIDs.
+    pub fn nontransmitted_bssid(&self, transmitted_bssid: MacAddr6, bssid_index: u8) -> MacAddr6 {
+        let mut bssid_bytes = [0; 8];
+        bssid_bytes[2..].copy_from_slice(transmitted_bssid.as_bytes());
+        let bssid = u64::from_be_bytes(bssid_bytes);
+
+        let mask = u64::from(self.max_bssids()) - 1;
+        let low_bits = ((bssid & mask) + u64::from(bssid_index)) & mask;
+        let bssid_bytes = ((bssid & !mask) | low_bits).to_be_bytes();
+
+        MacAddr6::new(
+            bssid_bytes[2],
+            bssid_bytes[3],
+            bssid_bytes[4],
+            bssid_bytes[5],
+            bssid_bytes[6],
+            bssid_bytes[7],
+        )
+    }
+}
+
+impl InformationElement for MultipleBssid {
+    const NAME: &'static str = "Multiple BSSID";
+    const ID: u8 = 71;
+
+    fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    fn information_fields(&self) -> Vec<Field> {
+        let mut information_fields =
+            vec![Field::new("MaxBSSID Indicator", self.max_bssid_indicator())];
+
+        for profile in &self.nontransmitted_bssid_profiles {
+            information_fields.push(Field::with_subfields(
+                "Nontransmitted BSSID Profile",
+                profile.ssid().unwrap_or_default(),
+                profile
+                    .ies
+                    .iter()
+                    .map(|ie| Field::with_subfields(ie.name(), "", ie.information_fields()))
+                    .collect(),
+            ));
+        }
+
+        information_fields
+    }
+}
+
+impl_display_for_ie!(MultipleBssid);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ies;
+
+    #[test]
+    fn multiple_bssid_inheritance() {
+        let transmitted_ies = ies::from_bytes(&[
+            0x00, 0x04, b'm', b'a', b'i', b'n', // SSID
+            0x05, 0x04, 0x00, 0x03, 0x00, 0x00, // TIM
+            0x0B, 0x05, 0x02, 0x00, 0x10, 0x00, 0x00, // BSS Load
+            0x47, 0x17, 0x03, // Multiple BSSID, MaxBSSID Indicator 3
+            0x00, 0x14, // Nontransmitted BSSID Profile
+            0x53, 0x02, 0x01, 0x04, // Nontransmitted BSSID Capability
+            0x00, 0x05, b'g', b'u', b'e', b's', b't', // SSID
+            0x55, 0x01, 0x07, // Multiple BSSID-Index
+            0xFF, 0x04, 0x38, 0x01, 0x0B, 0x00, // Non-Inheritance of BSS Load
+        ])
+        .unwrap();
+
+        let multiple_bssid = match &transmitted_ies[3] {
+            Ie::MultipleBssid(multiple_bssid) => multiple_bssid,
+            ie => panic!("Expected Multiple BSSID, got {}", ie.name()),
+        };
+        assert_eq!(multiple_bssid.max_bssids(), 8);
+
+        let profile = &multiple_bssid.nontransmitted_bssid_profiles()[0];
+        assert_eq!(profile.ssid(), Some("guest"));
+        assert_eq!(profile.bssid_index(), Some(7));
+        assert_eq!(
+            multiple_bssid.nontransmitted_bssid(
+                MacAddr6::new(0x02, 0x11, 0x22, 0x33, 0x44, 0x5A),
+                profile.bssid_index().unwrap()
+            ),
+            MacAddr6::new(0x02, 0x11, 0x22, 0x33, 0x44, 0x59)
+        );
+
+        let names: Vec<&str> = profile
+            .inherited_ies(&transmitted_ies)
+            .iter()
+            .map(|ie| ie.name())
+            .collect();
+        assert_eq!(names, vec!["SSID", "TIM", "Multiple BSSID-Index"]);
+    }
+}
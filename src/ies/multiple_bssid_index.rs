@@ -0,0 +1,62 @@
+use super::{Field, IeError, InformationElement};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultipleBssidIndex {
+    bytes: Vec<u8>,
+}
+
+impl MultipleBssidIndex {
+    pub const MIN_LENGTH: usize = 1;
+
+    pub fn new(bytes: Vec<u8>) -> Result<MultipleBssidIndex, IeError> {
+        if bytes.len() >= Self::MIN_LENGTH {
+            Ok(MultipleBssidIndex { bytes })
+        } else {
+            Err(IeError::InvalidLength {
+                ie_name: Self::NAME,
+                expected_length: Self::MIN_LENGTH,
+                actual_length: bytes.len(),
+            })
+        }
+    }
+
+    /// The index that the nontransmitted BSSID is derived from and that identifies the BSS in the
+    /// TIM element's bitmap.
+    pub fn bssid_index(&self) -> u8 {
+        self.bytes[0]
+    }
+
+    /// The DTIM period, which is only included in beacons.
+    pub fn dtim_period(&self) -> Option<u8> {
+        self.bytes.get(1).copied()
+    }
+
+    /// The DTIM count, which is only included in beacons.
+    pub fn dtim_count(&self) -> Option<u8> {
+        self.bytes.get(2).copied()
+    }
+}
+
+impl InformationElement for MultipleBssidIndex {
+    const NAME: &'static str = "Multiple BSSID-Index";
+    const ID: u8 = 85;
+
+    fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    fn information_fields(&self) -> Vec<Field> {
+        let mut information_fields = vec![Field::new("BSSID Index", self.bssid_index())];
+
+        if let Some(dtim_period) = self.dtim_period() {
+            information_fields.push(Field::new("DTIM Period", dtim_period));
+        }
+        if let Some(dtim_count) = self.dtim_count() {
+            information_fields.push(Field::new("DTIM Count", dtim_count));
+        }
+
+        information_fields
+    }
+}
+
+impl_display_for_ie!(MultipleBssidIndex);
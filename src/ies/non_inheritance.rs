@@ -0,0 +1,68 @@
+use super::{Field, Ie, IeError, InformationElement};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NonInheritance {
+    bytes: Vec<u8>,
+}
+
+impl NonInheritance {
+    pub const MIN_LENGTH: usize = 2;
+
+    pub fn new(bytes: Vec<u8>) -> Result<NonInheritance, IeError> {
+        if bytes.len() >= Self::MIN_LENGTH {
+            Ok(NonInheritance { bytes })
+        } else {
+            Err(IeError::InvalidLength {
+                ie_name: Self::NAME,
+                expected_length: Self::MIN_LENGTH,
+                actual_length: bytes.len(),
+            })
+        }
+    }
+
+    /// The IDs of the elements that aren't inherited from the transmitted BSS.
+    pub fn element_ids(&self) -> &[u8] {
+        let length = self.bytes[0] as usize;
+        self.bytes.get(1..1 + length).unwrap_or_default()
+    }
+
+    /// The extension IDs of the elements with an ID of 255 that aren't inherited from the
+    /// transmitted BSS.
+    pub fn element_id_extensions(&self) -> &[u8] {
+        let offset = 1 + self.bytes[0] as usize;
+        let length = self.bytes.get(offset).copied().unwrap_or_default() as usize;
+        self.bytes
+            .get(offset + 1..offset + 1 + length)
+            .unwrap_or_default()
+    }
+
+    /// Whether the IE is left out of the nontransmitted BSS.
+    pub fn excludes(&self, ie: &Ie) -> bool {
+        match ie.id_ext() {
+            Some(id_ext) => self.element_id_extensions().contains(&id_ext),
+            None => self.element_ids().contains(&ie.id()),
+        }
+    }
+}
+
+impl InformationElement for NonInheritance {
+    const NAME: &'static str = "Non-Inheritance";
+    const ID: u8 = 255;
+    const ID_EXT: Option<u8> = Some(56);
+
+    fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    fn information_fields(&self) -> Vec<Field> {
+        vec![
+            Field::new("Element IDs", format!("{:?}", self.element_ids())),
+            Field::new(
+                "Element ID Extensions",
+                format!("{:?}", self.element_id_extensions()),
+            ),
+        ]
+    }
+}
+
+impl_display_for_ie!(NonInheritance);
@@ -0,0 +1,48 @@
+use super::{Field, IeError, InformationElement};
+use crate::CapabilityInfo;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NontransmittedBssidCapability {
+    bytes: Vec<u8>,
+}
+
+impl NontransmittedBssidCapability {
+    pub const LENGTH: usize = 2;
+
+    pub fn new(bytes: Vec<u8>) -> Result<NontransmittedBssidCapability, IeError> {
+        if bytes.len() == Self::LENGTH {
+            Ok(NontransmittedBssidCapability { bytes })
+        } else {
+            Err(IeError::InvalidLength {
+                ie_name: Self::NAME,
+                expected_length: Self::LENGTH,
+                actual_length: bytes.len(),
+            })
+        }
+    }
+
+    /// The Capability Information field of the nontransmitted BSS.
+    pub fn capability_info(&self) -> CapabilityInfo {
+        CapabilityInfo::new([self.bytes[0], self.bytes[1]])
+    }
+}
+
+impl InformationElement for NontransmittedBssidCapability {
+    const NAME: &'static str = "Nontransmitted BSSID Capability";
+    const ID: u8 = 83;
+
+    fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    fn information_fields(&self) -> Vec<Field> {
+        let capability_info = self.capability_info();
+        vec![Field::with_subfields(
+            CapabilityInfo::NAME,
+            format!("{:02?}", self.bytes),
+            capability_info.fields(),
+        )]
+    }
+}
+
+impl_display_for_ie!(NontransmittedBssidCapability);
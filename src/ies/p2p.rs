@@ -0,0 +1,733 @@
+use super::{
+    wps::config_methods_to_string, ConfigMethod, DeviceType, Field, IeError, InformationElement,
+};
+use byteorder::{BigEndian, LittleEndian, ReadBytesExt};
+use enumflags2::{bitflags, BitFlags};
+use macaddr::MacAddr6;
+use std::{
+    fmt::Display,
+    io::{Cursor, Read},
+};
+
+// P2P attribute IDs
+const P2P_CAPABILITY: u8 = 2;
+const P2P_DEVICE_ID: u8 = 3;
+const CHANNEL_LIST: u8 = 11;
+const NOTICE_OF_ABSENCE: u8 = 12;
+const P2P_DEVICE_INFO: u8 = 13;
+const P2P_GROUP_INFO: u8 = 14;
+const OPERATING_CHANNEL: u8 = 17;
+
+// The WPS attribute type of the device name embedded in device info and client info descriptors
+const WPS_DEVICE_NAME: u16 = 0x1011;
+
+#[bitflags]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum P2pDeviceCapability {
+    ServiceDiscovery = 1 << 0,
+    ClientDiscoverability = 1 << 1,
+    ConcurrentOperation = 1 << 2,
+    InfrastructureManaged = 1 << 3,
+    DeviceLimit = 1 << 4,
+    InvitationProcedure = 1 << 5,
+}
+
+impl Display for P2pDeviceCapability {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            P2pDeviceCapability::ServiceDiscovery => write!(f, "Service Discovery"),
+            P2pDeviceCapability::ClientDiscoverability => {
+                write!(f, "P2P Client Discoverability")
+            }
+            P2pDeviceCapability::ConcurrentOperation => write!(f, "Concurrent Operation"),
+            P2pDeviceCapability::InfrastructureManaged => write!(f, "P2P Infrastructure Managed"),
+            P2pDeviceCapability::DeviceLimit => write!(f, "P2P Device Limit"),
+            P2pDeviceCapability::InvitationProcedure => write!(f, "P2P Invitation Procedure"),
+        }
+    }
+}
+
+#[bitflags]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum P2pGroupCapability {
+    GroupOwner = 1 << 0,
+    PersistentGroup = 1 << 1,
+    GroupLimit = 1 << 2,
+    IntraBssDistribution = 1 << 3,
+    CrossConnection = 1 << 4,
+    PersistentReconnect = 1 << 5,
+    GroupFormation = 1 << 6,
+    IpAddressAllocation = 1 << 7,
+}
+
+impl Display for P2pGroupCapability {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            P2pGroupCapability::GroupOwner => write!(f, "P2P Group Owner"),
+            P2pGroupCapability::PersistentGroup => write!(f, "Persistent P2P Group"),
+            P2pGroupCapability::GroupLimit => write!(f, "P2P Group Limit"),
+            P2pGroupCapability::IntraBssDistribution => write!(f, "Intra-BSS Distribution"),
+            P2pGroupCapability::CrossConnection => write!(f, "Cross Connection"),
+            P2pGroupCapability::PersistentReconnect => write!(f, "Persistent Reconnect"),
+            P2pGroupCapability::GroupFormation => write!(f, "Group Formation"),
+            P2pGroupCapability::IpAddressAllocation => write!(f, "IP Address Allocation"),
+        }
+    }
+}
+
+fn flags_to_string<T>(flags: BitFlags<T>) -> String
+where
+    T: enumflags2::BitFlag + Display,
+{
+    flags
+        .iter()
+        .map(|flag| flag.to_string())
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+fn read_mac_address(cursor: &mut Cursor<&[u8]>) -> Option<MacAddr6> {
+    let mut mac_address = [0; 6];
+    cursor.read_exact(&mut mac_address).ok()?;
+    Some(MacAddr6::from(mac_address))
+}
+
+fn read_device_type(cursor: &mut Cursor<&[u8]>) -> Option<DeviceType> {
+    let mut device_type = [0; 8];
+    cursor.read_exact(&mut device_type).ok()?;
+    Some(DeviceType::new(device_type))
+}
+
+// Reads the part shared by the P2P Device Info attribute and client info descriptors: config
+// methods, primary and secondary device types and the device name as a WPS attribute
+fn read_device_details(
+    cursor: &mut Cursor<&[u8]>,
+) -> Option<(BitFlags<ConfigMethod>, DeviceType, Vec<DeviceType>, String)> {
+    let config_methods = BitFlags::from_bits_truncate(cursor.read_u16::<BigEndian>().ok()?);
+    let primary_device_type = read_device_type(cursor)?;
+
+    let secondary_device_type_count = cursor.read_u8().ok()?;
+    let secondary_device_types = (0..secondary_device_type_count)
+        .map(|_| read_device_type(cursor))
+        .collect::<Option<Vec<DeviceType>>>()?;
+
+    if cursor.read_u16::<BigEndian>().ok()? != WPS_DEVICE_NAME {
+        return None;
+    }
+    let mut device_name = vec![0; cursor.read_u16::<BigEndian>().ok()? as usize];
+    cursor.read_exact(&mut device_name).ok()?;
+    let device_name = String::from_utf8_lossy(&device_name)
+        .trim_end_matches(char::from(0))
+        .to_string();
+
+    Some((
+        config_methods,
+        primary_device_type,
+        secondary_device_types,
+        device_name,
+    ))
+}
+
+fn device_detail_fields(
+    config_methods: BitFlags<ConfigMethod>,
+    primary_device_type: &DeviceType,
+    secondary_device_types: &[DeviceType],
+    device_name: &str,
+) -> Vec<Field> {
+    let mut fields = vec![
+        Field::new("Config Methods", config_methods_to_string(config_methods)),
+        Field::new("Primary Device Type", primary_device_type),
+    ];
+    for secondary_device_type in secondary_device_types {
+        fields.push(Field::new("Secondary Device Type", secondary_device_type));
+    }
+    fields.push(Field::new("Device Name", device_name));
+
+    fields
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct P2pDeviceInfo {
+    device_address: MacAddr6,
+    config_methods: BitFlags<ConfigMethod>,
+    primary_device_type: DeviceType,
+    secondary_device_types: Vec<DeviceType>,
+    device_name: String,
+}
+
+impl P2pDeviceInfo {
+    fn parse(data: &[u8]) -> Option<P2pDeviceInfo> {
+        let mut cursor = Cursor::new(data);
+        let device_address = read_mac_address(&mut cursor)?;
+        let (config_methods, primary_device_type, secondary_device_types, device_name) =
+            read_device_details(&mut cursor)?;
+
+        Some(P2pDeviceInfo {
+            device_address,
+            config_methods,
+            primary_device_type,
+            secondary_device_types,
+            device_name,
+        })
+    }
+
+    pub fn device_address(&self) -> MacAddr6 {
+        self.device_address
+    }
+
+    pub fn config_methods(&self) -> BitFlags<ConfigMethod> {
+        self.config_methods
+    }
+
+    pub fn primary_device_type(&self) -> DeviceType {
+        self.primary_device_type
+    }
+
+    pub fn secondary_device_types(&self) -> &[DeviceType] {
+        &self.secondary_device_types
+    }
+
+    pub fn device_name(&self) -> &str {
+        &self.device_name
+    }
+
+    pub fn fields(&self) -> Vec<Field> {
+        let mut fields = vec![Field::new("P2P Device Address", self.device_address)];
+        fields.extend(device_detail_fields(
+            self.config_methods,
+            &self.primary_device_type,
+            &self.secondary_device_types,
+            &self.device_name,
+        ));
+
+        fields
+    }
+}
+
+/// A P2P client in a group owner's P2P Group Info attribute.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct P2pClientInfo {
+    device_address: MacAddr6,
+    interface_address: MacAddr6,
+    device_capability: BitFlags<P2pDeviceCapability>,
+    config_methods: BitFlags<ConfigMethod>,
+    primary_device_type: DeviceType,
+    secondary_device_types: Vec<DeviceType>,
+    device_name: String,
+}
+
+impl P2pClientInfo {
+    fn parse(data: &[u8]) -> Option<P2pClientInfo> {
+        let mut cursor = Cursor::new(data);
+        let device_address = read_mac_address(&mut cursor)?;
+        let interface_address = read_mac_address(&mut cursor)?;
+        let device_capability = BitFlags::from_bits_truncate(cursor.read_u8().ok()?);
+        let (config_methods, primary_device_type, secondary_device_types, device_name) =
+            read_device_details(&mut cursor)?;
+
+        Some(P2pClientInfo {
+            device_address,
+            interface_address,
+            device_capability,
+            config_methods,
+            primary_device_type,
+            secondary_device_types,
+            device_name,
+        })
+    }
+
+    pub fn device_address(&self) -> MacAddr6 {
+        self.device_address
+    }
+
+    pub fn interface_address(&self) -> MacAddr6 {
+        self.interface_address
+    }
+
+    pub fn device_capability(&self) -> BitFlags<P2pDeviceCapability> {
+        self.device_capability
+    }
+
+    pub fn config_methods(&self) -> BitFlags<ConfigMethod> {
+        self.config_methods
+    }
+
+    pub fn primary_device_type(&self) -> DeviceType {
+        self.primary_device_type
+    }
+
+    pub fn secondary_device_types(&self) -> &[DeviceType] {
+        &self.secondary_device_types
+    }
+
+    pub fn device_name(&self) -> &str {
+        &self.device_name
+    }
+
+    pub fn fields(&self) -> Vec<Field> {
+        let mut fields = vec![
+            Field::new("P2P Device Address", self.device_address),
+            Field::new("P2P Interface Address", self.interface_address),
+            Field::new("Device Capability", flags_to_string(self.device_capability)),
+        ];
+        fields.extend(device_detail_fields(
+            self.config_methods,
+            &self.primary_device_type,
+            &self.secondary_device_types,
+            &self.device_name,
+        ));
+
+        fields
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoticeOfAbsenceDescriptor {
+    bytes: [u8; 13],
+}
+
+impl NoticeOfAbsenceDescriptor {
+    /// The number of absence intervals, where 255 means the schedule repeats indefinitely.
+    pub fn count(&self) -> u8 {
+        self.bytes[0]
+    }
+
+    pub fn duration_us(&self) -> u32 {
+        u32::from_le_bytes([self.bytes[1], self.bytes[2], self.bytes[3], self.bytes[4]])
+    }
+
+    pub fn interval_us(&self) -> u32 {
+        u32::from_le_bytes([self.bytes[5], self.bytes[6], self.bytes[7], self.bytes[8]])
+    }
+
+    /// The lower 4 bytes of the TSF timer at which the first absence starts.
+    pub fn start_time(&self) -> u32 {
+        u32::from_le_bytes([
+            self.bytes[9],
+            self.bytes[10],
+            self.bytes[11],
+            self.bytes[12],
+        ])
+    }
+
+    pub fn fields(&self) -> Vec<Field> {
+        vec![
+            Field::new("Count/Type", self.count()),
+            Field::new("Duration", format!("{} µs", self.duration_us())),
+            Field::new("Interval", format!("{} µs", self.interval_us())),
+            Field::new("Start Time", self.start_time()),
+        ]
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoticeOfAbsence {
+    bytes: Vec<u8>,
+}
+
+impl NoticeOfAbsence {
+    const DESCRIPTOR_LENGTH: usize = 13;
+
+    pub fn index(&self) -> u8 {
+        self.bytes[0]
+    }
+
+    pub fn ct_window(&self) -> u8 {
+        self.bytes[1] & 0b0111_1111
+    }
+
+    pub fn opp_ps(&self) -> bool {
+        self.bytes[1] & 0b1000_0000 != 0
+    }
+
+    pub fn descriptors(&self) -> Vec<NoticeOfAbsenceDescriptor> {
+        self.bytes[2..]
+            .chunks_exact(Self::DESCRIPTOR_LENGTH)
+            .map(|chunk| {
+                let mut bytes = [0; Self::DESCRIPTOR_LENGTH];
+                bytes.copy_from_slice(chunk);
+                NoticeOfAbsenceDescriptor { bytes }
+            })
+            .collect()
+    }
+
+    pub fn fields(&self) -> Vec<Field> {
+        let mut fields = vec![
+            Field::new("Index", self.index()),
+            Field::new("CTWindow", format!("{} TU", self.ct_window())),
+            Field::new("OppPS", self.opp_ps()),
+        ];
+        for descriptor in self.descriptors() {
+            fields.push(Field::with_subfields(
+                "Notice of Absence Descriptor",
+                format!("{:02?}", descriptor.bytes),
+                descriptor.fields(),
+            ));
+        }
+
+        fields
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct P2pChannelEntry {
+    pub operating_class: u8,
+    pub channels: Vec<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct P2pChannelList {
+    country_string: String,
+    entries: Vec<P2pChannelEntry>,
+}
+
+impl P2pChannelList {
+    fn parse(data: &[u8]) -> Option<P2pChannelList> {
+        let country_string = String::from_utf8_lossy(data.get(0..3)?).to_string();
+
+        let mut entries = Vec::new();
+        let mut remaining = &data[3..];
+        while let [operating_class, channel_count, rest @ ..] = remaining {
+            let channels = rest.get(..*channel_count as usize)?.to_vec();
+            remaining = &rest[channels.len()..];
+            entries.push(P2pChannelEntry {
+                operating_class: *operating_class,
+                channels,
+            });
+        }
+
+        Some(P2pChannelList {
+            country_string,
+            entries,
+        })
+    }
+
+    pub fn country_string(&self) -> &str {
+        &self.country_string
+    }
+
+    pub fn entries(&self) -> &[P2pChannelEntry] {
+        &self.entries
+    }
+
+    pub fn fields(&self) -> Vec<Field> {
+        let mut fields = vec![Field::new("Country String", &self.country_string)];
+        for entry in &self.entries {
+            fields.push(Field::new(
+                format!("Operating Class {}", entry.operating_class),
+                format!("{:?}", entry.channels),
+            ));
+        }
+
+        fields
+    }
+}
+
+/// A channel given as a country string, operating class and channel number, which is the format of
+/// the Operating Channel and Listen Channel attributes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct P2pChannel {
+    pub country_string: String,
+    pub operating_class: u8,
+    pub channel: u8,
+}
+
+impl P2pChannel {
+    fn parse(data: &[u8]) -> Option<P2pChannel> {
+        if data.len() != 5 {
+            return None;
+        }
+
+        Some(P2pChannel {
+            country_string: String::from_utf8_lossy(&data[0..3]).to_string(),
+            operating_class: data[3],
+            channel: data[4],
+        })
+    }
+
+    pub fn fields(&self) -> Vec<Field> {
+        vec![
+            Field::new("Country String", &self.country_string),
+            Field::new("Operating Class", self.operating_class),
+            Field::new("Channel", self.channel),
+        ]
+    }
+}
+
+// Reads P2P attributes, which have a 1-octet ID and a little-endian 2-octet length
+fn read_attributes(bytes: &[u8]) -> Vec<(u8, Vec<u8>)> {
+    let mut cursor = Cursor::new(bytes);
+    let mut attributes = Vec::new();
+
+    while let (Ok(attribute_id), Ok(attribute_length)) =
+        (cursor.read_u8(), cursor.read_u16::<LittleEndian>())
+    {
+        let mut data = vec![0; attribute_length as usize];
+        if cursor.read_exact(&mut data).is_err() {
+            break;
+        }
+        attributes.push((attribute_id, data));
+    }
+
+    attributes
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct P2p {
+    bytes: Vec<u8>,
+    attributes: Vec<(u8, Vec<u8>)>,
+}
+
+impl P2p {
+    pub const OUI: [u8; 4] = [0x50, 0x6F, 0x9A, 0x09];
+    pub const MIN_LENGTH: usize = 4;
+
+    pub fn new(bytes: Vec<u8>) -> Result<P2p, IeError> {
+        if bytes.len() < Self::MIN_LENGTH {
+            return Err(IeError::InvalidLength {
+                ie_name: Self::NAME,
+                expected_length: Self::MIN_LENGTH,
+                actual_length: bytes.len(),
+            });
+        }
+
+        let attributes = read_attributes(&bytes[Self::OUI.len()..]);
+        Ok(P2p { bytes, attributes })
+    }
+
+    /// Joins the P2P elements of a frame into one. An attribute can be split across elements, so
+    /// their data is joined before the attributes are read.
+    pub fn join<'a>(elements: impl IntoIterator<Item = &'a P2p>) -> Option<P2p> {
+        let mut elements = elements.into_iter();
+        let mut bytes = elements.next()?.bytes.clone();
+        for element in elements {
+            bytes.extend_from_slice(&element.bytes[Self::OUI.len()..]);
+        }
+
+        P2p::new(bytes).ok()
+    }
+
+    fn attribute(&self, attribute_id: u8) -> Option<&[u8]> {
+        self.attributes
+            .iter()
+            .find(|(this_id, _)| *this_id == attribute_id)
+            .map(|(_, data)| data.as_slice())
+    }
+
+    pub fn device_capability(&self) -> Option<BitFlags<P2pDeviceCapability>> {
+        self.attribute(P2P_CAPABILITY)
+            .filter(|data| data.len() == 2)
+            .map(|data| BitFlags::from_bits_truncate(data[0]))
+    }
+
+    pub fn group_capability(&self) -> Option<BitFlags<P2pGroupCapability>> {
+        self.attribute(P2P_CAPABILITY)
+            .filter(|data| data.len() == 2)
+            .map(|data| BitFlags::from_bits_truncate(data[1]))
+    }
+
+    pub fn device_id(&self) -> Option<MacAddr6> {
+        self.attribute(P2P_DEVICE_ID)
+            .and_then(|data| read_mac_address(&mut Cursor::new(data)))
+    }
+
+    pub fn device_info(&self) -> Option<P2pDeviceInfo> {
+        self.attribute(P2P_DEVICE_INFO)
+            .and_then(P2pDeviceInfo::parse)
+    }
+
+    /// The clients of a group owner. Each client info descriptor is prefixed by its length.
+    pub fn group_info(&self) -> Option<Vec<P2pClientInfo>> {
+        let mut remaining = self.attribute(P2P_GROUP_INFO)?;
+        let mut clients = Vec::new();
+
+        while let [length, rest @ ..] = remaining {
+            let descriptor = rest.get(..*length as usize)?;
+            clients.push(P2pClientInfo::parse(descriptor)?);
+            remaining = &rest[descriptor.len()..];
+        }
+
+        Some(clients)
+    }
+
+    pub fn notice_of_absence(&self) -> Option<NoticeOfAbsence> {
+        self.attribute(NOTICE_OF_ABSENCE)
+            .filter(|data| data.len() >= 2)
+            .map(|data| NoticeOfAbsence {
+                bytes: data.to_vec(),
+            })
+    }
+
+    pub fn channel_list(&self) -> Option<P2pChannelList> {
+        self.attribute(CHANNEL_LIST).and_then(P2pChannelList::parse)
+    }
+
+    pub fn operating_channel(&self) -> Option<P2pChannel> {
+        self.attribute(OPERATING_CHANNEL)
+            .and_then(P2pChannel::parse)
+    }
+}
+
+impl InformationElement for P2p {
+    const NAME: &'static str = "P2P";
+    const ID: u8 = 221;
+
+    fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    fn information_fields(&self) -> Vec<Field> {
+        let mut information_fields = Vec::new();
+
+        if let (Some(device_capability), Some(group_capability)) =
+            (self.device_capability(), self.group_capability())
+        {
+            information_fields.push(Field::with_subfields(
+                "P2P Capability",
+                format!("{:02?}", self.attribute(P2P_CAPABILITY).unwrap_or_default()),
+                vec![
+                    Field::new("Device Capability", flags_to_string(device_capability)),
+                    Field::new("Group Capability", flags_to_string(group_capability)),
+                ],
+            ));
+        }
+        if let Some(device_id) = self.device_id() {
+            information_fields.push(Field::new("P2P Device ID", device_id));
+        }
+        if let Some(device_info) = self.device_info() {
+            information_fields.push(Field::with_subfields(
+                "P2P Device Info",
+                device_info.device_name(),
+                device_info.fields(),
+            ));
+        }
+        if let Some(group_info) = self.group_info() {
+            information_fields.push(Field::with_subfields(
+                "P2P Group Info",
+                format!("{} clients", group_info.len()),
+                group_info
+                    .iter()
+                    .map(|client| {
+                        Field::with_subfields(
+                            "P2P Client Info",
+                            client.device_name(),
+                            client.fields(),
+                        )
+                    })
+                    .collect(),
+            ));
+        }
+        if let Some(notice_of_absence) = self.notice_of_absence() {
+            information_fields.push(Field::with_subfields(
+                "Notice of Absence",
+                format!("{:02?}", notice_of_absence.bytes),
+                notice_of_absence.fields(),
+            ));
+        }
+        if let Some(channel_list) = self.channel_list() {
+            information_fields.push(Field::with_subfields(
+                "Channel List",
+                channel_list.country_string(),
+                channel_list.fields(),
+            ));
+        }
+        if let Some(operating_channel) = self.operating_channel() {
+            information_fields.push(Field::with_subfields(
+                "Operating Channel",
+                operating_channel.channel,
+                operating_channel.fields(),
+            ));
+        }
+
+        information_fields
+    }
+}
+
+impl_display_for_ie!(P2p);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn p2p_group_owner() {
+        let mut bytes = P2p::OUI.to_vec();
+        // P2P Capability
+        bytes.extend([0x02, 0x02, 0x00, 0x25, 0x09]);
+        // P2P Device Info
+        bytes.extend([0x0D, 0x1B, 0x00]);
+        bytes.extend([0x02, 0x11, 0x22, 0x33, 0x44, 0x55]);
+        bytes.extend([0x00, 0x88]);
+        bytes.extend([0x00, 0x03, 0x00, 0x50, 0xF2, 0x04, 0x00, 0x01]);
+        bytes.push(0x00);
+        bytes.extend([0x10, 0x11, 0x00, 0x06]);
+        bytes.extend(b"HP-M15");
+        // Operating Channel
+        bytes.extend([0x11, 0x05, 0x00, 0x55, 0x53, 0x04, 0x51, 0x06]);
+        // Channel List
+        bytes.extend([
+            0x0B, 0x0A, 0x00, 0x55, 0x53, 0x04, 0x51, 0x03, 0x01, 0x06, 0x0B, 0x73,
+        ]);
+        bytes.push(0x00);
+
+        let p2p = P2p::new(bytes).unwrap();
+        assert_eq!(
+            p2p.device_capability(),
+            Some(
+                P2pDeviceCapability::ServiceDiscovery
+                    | P2pDeviceCapability::ConcurrentOperation
+                    | P2pDeviceCapability::InvitationProcedure
+            )
+        );
+        assert!(p2p
+            .group_capability()
+            .unwrap()
+            .contains(P2pGroupCapability::GroupOwner));
+
+        let device_info = p2p.device_info().unwrap();
+        assert_eq!(device_info.device_name(), "HP-M15");
+        assert_eq!(
+            device_info.primary_device_type().category_name(),
+            Some("Printer, Scanner, Fax or Copier")
+        );
+        assert_eq!(p2p.operating_channel().unwrap().channel, 6);
+
+        let channel_list = p2p.channel_list().unwrap();
+        assert_eq!(channel_list.country_string(), "US\u{4}");
+        assert_eq!(
+            channel_list.entries(),
+            &[
+                P2pChannelEntry {
+                    operating_class: 81,
+                    channels: vec![1, 6, 11],
+                },
+                P2pChannelEntry {
+                    operating_class: 115,
+                    channels: vec![],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn p2p_attribute_split_across_elements() {
+        // The P2P Capability attribute is split after its first octet
+        let mut first = P2p::OUI.to_vec();
+        first.extend([0x11, 0x05, 0x00, 0x55, 0x53, 0x04, 0x51, 0x06]);
+        first.extend([0x02, 0x02, 0x00, 0x25]);
+        let mut second = P2p::OUI.to_vec();
+        second.push(0x09);
+        let elements = [P2p::new(first).unwrap(), P2p::new(second).unwrap()];
+
+        assert_eq!(elements[0].group_capability(), None);
+
+        let p2p = P2p::join(&elements).unwrap();
+        assert_eq!(p2p.operating_channel().unwrap().channel, 6);
+        assert!(p2p
+            .group_capability()
+            .unwrap()
+            .contains(P2pGroupCapability::GroupOwner));
+    }
+}
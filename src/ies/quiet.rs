@@ -0,0 +1,90 @@
+use super::{Field, IeError, InformationElement};
+
+/// The fields shared by the Quiet element and the Quiet Channel element that describe a quiet
+/// interval.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuietInterval {
+    bytes: [u8; 6],
+}
+
+impl QuietInterval {
+    pub(super) fn new(bytes: &[u8]) -> QuietInterval {
+        let mut quiet_interval = [0; 6];
+        quiet_interval.copy_from_slice(&bytes[..6]);
+        QuietInterval {
+            bytes: quiet_interval,
+        }
+    }
+
+    /// The number of TBTTs until the beacon interval in which the next quiet interval starts.
+    pub fn quiet_count(&self) -> u8 {
+        self.bytes[0]
+    }
+
+    /// The number of beacon intervals between quiet intervals, where 0 means there's no periodic
+    /// quiet interval.
+    pub fn quiet_period(&self) -> u8 {
+        self.bytes[1]
+    }
+
+    pub fn quiet_duration_tu(&self) -> u16 {
+        u16::from_le_bytes([self.bytes[2], self.bytes[3]])
+    }
+
+    /// The offset of the quiet interval from the TBTT, in TUs.
+    pub fn quiet_offset_tu(&self) -> u16 {
+        u16::from_le_bytes([self.bytes[4], self.bytes[5]])
+    }
+
+    pub fn fields(&self) -> Vec<Field> {
+        vec![
+            Field::new("Quiet Count", self.quiet_count()),
+            Field::new("Quiet Period", self.quiet_period()),
+            Field::new(
+                "Quiet Duration",
+                format!("{} TUs", self.quiet_duration_tu()),
+            ),
+            Field::new("Quiet Offset", format!("{} TUs", self.quiet_offset_tu())),
+        ]
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Quiet {
+    bytes: Vec<u8>,
+}
+
+impl Quiet {
+    pub const LENGTH: usize = 6;
+
+    pub fn new(bytes: Vec<u8>) -> Result<Quiet, IeError> {
+        if bytes.len() == Self::LENGTH {
+            Ok(Quiet { bytes })
+        } else {
+            Err(IeError::InvalidLength {
+                ie_name: Self::NAME,
+                expected_length: Self::LENGTH,
+                actual_length: bytes.len(),
+            })
+        }
+    }
+
+    pub fn quiet_interval(&self) -> QuietInterval {
+        QuietInterval::new(&self.bytes)
+    }
+}
+
+impl InformationElement for Quiet {
+    const NAME: &'static str = "Quiet";
+    const ID: u8 = 40;
+
+    fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    fn information_fields(&self) -> Vec<Field> {
+        self.quiet_interval().fields()
+    }
+}
+
+impl_display_for_ie!(Quiet);
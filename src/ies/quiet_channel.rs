@@ -0,0 +1,59 @@
+use super::{Field, IeError, InformationElement, QuietInterval};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuietChannel {
+    bytes: Vec<u8>,
+}
+
+impl QuietChannel {
+    pub const MIN_LENGTH: usize = 1;
+
+    const WITH_QUIET_INTERVAL_LENGTH: usize = 7;
+
+    pub fn new(bytes: Vec<u8>) -> Result<QuietChannel, IeError> {
+        if bytes.len() == Self::MIN_LENGTH || bytes.len() == Self::WITH_QUIET_INTERVAL_LENGTH {
+            Ok(QuietChannel { bytes })
+        } else {
+            Err(IeError::InvalidLength {
+                ie_name: Self::NAME,
+                expected_length: Self::MIN_LENGTH,
+                actual_length: bytes.len(),
+            })
+        }
+    }
+
+    /// Whether the quiet interval only applies to the primary 80 MHz channel, so the AP keeps
+    /// operating on the primary 80 MHz channel of an 80+80 or 160 MHz BSS.
+    pub fn ap_quiet_mode(&self) -> bool {
+        self.bytes[0] & 0b1 != 0
+    }
+
+    /// The quiet interval, which is only included when the AP Quiet Mode is set.
+    pub fn quiet_interval(&self) -> Option<QuietInterval> {
+        self.bytes
+            .get(1..)
+            .filter(|bytes| bytes.len() == 6)
+            .map(QuietInterval::new)
+    }
+}
+
+impl InformationElement for QuietChannel {
+    const NAME: &'static str = "Quiet Channel";
+    const ID: u8 = 198;
+
+    fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    fn information_fields(&self) -> Vec<Field> {
+        let mut information_fields = vec![Field::new("AP Quiet Mode", self.ap_quiet_mode())];
+
+        if let Some(quiet_interval) = self.quiet_interval() {
+            information_fields.extend(quiet_interval.fields());
+        }
+
+        information_fields
+    }
+}
+
+impl_display_for_ie!(QuietChannel);
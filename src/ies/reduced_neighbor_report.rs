@@ -0,0 +1,399 @@
+use super::{Field, IeError, InformationElement};
+use crate::{ChannelBand, OperatingClass};
+use macaddr::MacAddr6;
+
+/// The BSS Parameters subfield of a TBTT Information field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RnrBssParameters {
+    byte: u8,
+}
+
+impl RnrBssParameters {
+    pub fn oct_recommended(&self) -> bool {
+        self.byte & 0b0000_0001 != 0
+    }
+
+    /// Whether the neighbor has the same SSID as the reporting BSS.
+    pub fn same_ssid(&self) -> bool {
+        self.byte & 0b0000_0010 != 0
+    }
+
+    pub fn multiple_bssid(&self) -> bool {
+        self.byte & 0b0000_0100 != 0
+    }
+
+    pub fn transmitted_bssid(&self) -> bool {
+        self.byte & 0b0000_1000 != 0
+    }
+
+    pub fn member_of_ess_with_two_four_or_five_ghz_colocated_ap(&self) -> bool {
+        self.byte & 0b0001_0000 != 0
+    }
+
+    pub fn unsolicited_probe_responses_active(&self) -> bool {
+        self.byte & 0b0010_0000 != 0
+    }
+
+    /// Whether the neighbor is operated by the same device as the reporting BSS.
+    pub fn colocated_ap(&self) -> bool {
+        self.byte & 0b0100_0000 != 0
+    }
+
+    pub fn fields(&self) -> Vec<Field> {
+        vec![
+            Field::new("OCT Recommended", self.oct_recommended()),
+            Field::new("Same SSID", self.same_ssid()),
+            Field::new("Multiple BSSID", self.multiple_bssid()),
+            Field::new("Transmitted BSSID", self.transmitted_bssid()),
+            Field::new(
+                "Member of ESS with 2.4/5 GHz Co-located AP",
+                self.member_of_ess_with_two_four_or_five_ghz_colocated_ap(),
+            ),
+            Field::new(
+                "Unsolicited Probe Responses Active",
+                self.unsolicited_probe_responses_active(),
+            ),
+            Field::new("Co-located AP", self.colocated_ap()),
+        ]
+    }
+}
+
+/// The MLD Parameters subfield of a TBTT Information field, for neighbors affiliated with an AP
+/// MLD.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RnrMldParameters {
+    bytes: [u8; 3],
+}
+
+impl RnrMldParameters {
+    pub fn ap_mld_id(&self) -> u8 {
+        self.bytes[0]
+    }
+
+    pub fn link_id(&self) -> u8 {
+        self.bytes[1] & 0b1111
+    }
+
+    pub fn bss_parameters_change_count(&self) -> u8 {
+        (self.bytes[1] >> 4) | (self.bytes[2] << 4)
+    }
+
+    pub fn all_updates_included(&self) -> bool {
+        self.bytes[2] & 0b0001_0000 != 0
+    }
+
+    pub fn disabled_link_indication(&self) -> bool {
+        self.bytes[2] & 0b0010_0000 != 0
+    }
+
+    pub fn fields(&self) -> Vec<Field> {
+        vec![
+            Field::new("AP MLD ID", self.ap_mld_id()),
+            Field::new("Link ID", self.link_id()),
+            Field::new(
+                "BSS Parameters Change Count",
+                self.bss_parameters_change_count(),
+            ),
+            Field::new("All Updates Included", self.all_updates_included()),
+            Field::new("Disabled Link Indication", self.disabled_link_indication()),
+        ]
+    }
+}
+
+/// One TBTT Information field, whose subfields depend on its length.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TbttInformation {
+    bytes: Vec<u8>,
+}
+
+impl TbttInformation {
+    // The offsets of the BSSID, Short-SSID, BSS Parameters, 20 MHz PSD and MLD Parameters
+    // subfields for each TBTT Information Length
+    fn layout(&self) -> [Option<usize>; 5] {
+        match self.bytes.len() {
+            2 => [None, None, Some(1), None, None],
+            4 => [None, None, None, None, Some(1)],
+            5 => [None, Some(1), None, None, None],
+            6 => [None, Some(1), Some(5), None, None],
+            7 => [Some(1), None, None, None, None],
+            8 => [Some(1), None, Some(7), None, None],
+            9 => [Some(1), None, Some(7), Some(8), None],
+            10 => [Some(1), None, None, None, Some(7)],
+            11 => [Some(1), Some(7), None, None, None],
+            12 => [Some(1), Some(7), Some(11), None, None],
+            13 => [Some(1), Some(7), Some(11), Some(12), None],
+            length if length >= 16 => [Some(1), Some(7), Some(11), Some(12), Some(13)],
+            _ => [None; 5],
+        }
+    }
+
+    /// The offset in TUs from the reporting BSS's next TBTT to the neighbor's next TBTT, where 254
+    /// means 254 TUs or more and 255 means unknown.
+    pub fn tbtt_offset(&self) -> u8 {
+        self.bytes[0]
+    }
+
+    pub fn bssid(&self) -> Option<MacAddr6> {
+        self.layout()[0].map(|offset| {
+            let bssid = &self.bytes[offset..offset + 6];
+            MacAddr6::new(bssid[0], bssid[1], bssid[2], bssid[3], bssid[4], bssid[5])
+        })
+    }
+
+    /// The CRC-32 of the neighbor's SSID.
+    pub fn short_ssid(&self) -> Option<u32> {
+        self.layout()[1].map(|offset| {
+            u32::from_le_bytes([
+                self.bytes[offset],
+                self.bytes[offset + 1],
+                self.bytes[offset + 2],
+                self.bytes[offset + 3],
+            ])
+        })
+    }
+
+    pub fn bss_parameters(&self) -> Option<RnrBssParameters> {
+        self.layout()[2].map(|offset| RnrBssParameters {
+            byte: self.bytes[offset],
+        })
+    }
+
+    /// The maximum transmit power spectral density of the neighbor's primary 20 MHz channel in
+    /// dBm/MHz, or None if it isn't included or is unknown.
+    pub fn psd_twenty_mhz_dbm_per_mhz(&self) -> Option<f64> {
+        self.layout()[3]
+            .map(|offset| self.bytes[offset] as i8)
+            .filter(|psd| *psd != i8::MAX && *psd != i8::MIN)
+            .map(|psd| f64::from(psd) / 2.0)
+    }
+
+    pub fn mld_parameters(&self) -> Option<RnrMldParameters> {
+        self.layout()[4].map(|offset| RnrMldParameters {
+            bytes: [
+                self.bytes[offset],
+                self.bytes[offset + 1],
+                self.bytes[offset + 2],
+            ],
+        })
+    }
+
+    pub fn fields(&self) -> Vec<Field> {
+        let mut fields = vec![Field::new("Neighbor AP TBTT Offset", self.tbtt_offset())];
+
+        if let Some(bssid) = self.bssid() {
+            fields.push(Field::new("BSSID", bssid));
+        }
+        if let Some(short_ssid) = self.short_ssid() {
+            fields.push(Field::new("Short-SSID", format!("{:#010x}", short_ssid)));
+        }
+        if let Some(bss_parameters) = self.bss_parameters() {
+            fields.push(Field::with_subfields(
+                "BSS Parameters",
+                format!("{:02?}", bss_parameters.byte),
+                bss_parameters.fields(),
+            ));
+        }
+        if let Some(psd) = self.psd_twenty_mhz_dbm_per_mhz() {
+            fields.push(Field::new("20 MHz PSD", format!("{} dBm/MHz", psd)));
+        }
+        if let Some(mld_parameters) = self.mld_parameters() {
+            fields.push(Field::with_subfields(
+                "MLD Parameters",
+                format!("{:02?}", mld_parameters.bytes),
+                mld_parameters.fields(),
+            ));
+        }
+
+        fields
+    }
+}
+
+/// A Neighbor AP Information field, which describes the neighbors on one channel.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NeighborApInformation {
+    bytes: Vec<u8>,
+}
+
+impl NeighborApInformation {
+    const HEADER_LENGTH: usize = 4;
+
+    pub fn tbtt_information_field_type(&self) -> u8 {
+        self.bytes[0] & 0b11
+    }
+
+    /// Whether every neighbor in the field matches the SSID in the probe request that solicited
+    /// the report.
+    pub fn filtered_neighbor_ap(&self) -> bool {
+        self.bytes[0] & 0b0100 != 0
+    }
+
+    pub fn tbtt_information_count(&self) -> u8 {
+        (self.bytes[0] >> 4) + 1
+    }
+
+    pub fn tbtt_information_length(&self) -> u8 {
+        self.bytes[1]
+    }
+
+    pub fn operating_class(&self) -> u8 {
+        self.bytes[2]
+    }
+
+    pub fn channel(&self) -> u8 {
+        self.bytes[3]
+    }
+
+    /// Whether the operating class is a 6 GHz one.
+    pub fn is_six_ghz(&self) -> bool {
+        OperatingClass::global(self.operating_class())
+            .is_some_and(|operating_class| operating_class.band() == ChannelBand::SixGhz)
+    }
+
+    pub fn tbtt_informations(&self) -> Vec<TbttInformation> {
+        let length = self.tbtt_information_length() as usize;
+        if length == 0 {
+            return Vec::new();
+        }
+
+        self.bytes[Self::HEADER_LENGTH..]
+            .chunks_exact(length)
+            .map(|bytes| TbttInformation {
+                bytes: bytes.to_vec(),
+            })
+            .collect()
+    }
+
+    pub fn fields(&self) -> Vec<Field> {
+        let mut fields = vec![
+            Field::with_subfields(
+                "TBTT Information Header",
+                format!("{:02?}", &self.bytes[0..2]),
+                vec![
+                    Field::new(
+                        "TBTT Information Field Type",
+                        self.tbtt_information_field_type(),
+                    ),
+                    Field::new("Filtered Neighbor AP", self.filtered_neighbor_ap()),
+                    Field::new("TBTT Information Count", self.tbtt_information_count()),
+                    Field::new("TBTT Information Length", self.tbtt_information_length()),
+                ],
+            ),
+            Field::new("Operating Class", self.operating_class()),
+            Field::new("Channel Number", self.channel()),
+        ];
+
+        for tbtt_information in self.tbtt_informations() {
+            fields.push(Field::with_subfields(
+                "TBTT Information",
+                format!("{:02?}", tbtt_information.bytes),
+                tbtt_information.fields(),
+            ));
+        }
+
+        fields
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReducedNeighborReport {
+    bytes: Vec<u8>,
+}
+
+impl ReducedNeighborReport {
+    pub const MIN_LENGTH: usize = 5;
+
+    pub fn new(bytes: Vec<u8>) -> Result<ReducedNeighborReport, IeError> {
+        if bytes.len() >= Self::MIN_LENGTH {
+            Ok(ReducedNeighborReport { bytes })
+        } else {
+            Err(IeError::InvalidLength {
+                ie_name: Self::NAME,
+                expected_length: Self::MIN_LENGTH,
+                actual_length: bytes.len(),
+            })
+        }
+    }
+
+    pub fn neighbor_ap_informations(&self) -> Vec<NeighborApInformation> {
+        let mut neighbor_ap_informations = Vec::new();
+        let mut remaining = self.bytes.as_slice();
+
+        while remaining.len() >= NeighborApInformation::HEADER_LENGTH {
+            let count = (remaining[0] >> 4) as usize + 1;
+            let length = NeighborApInformation::HEADER_LENGTH + count * remaining[1] as usize;
+            let bytes = match remaining.get(..length) {
+                Some(bytes) => bytes,
+                None => break,
+            };
+
+            neighbor_ap_informations.push(NeighborApInformation {
+                bytes: bytes.to_vec(),
+            });
+            remaining = &remaining[length..];
+        }
+
+        neighbor_ap_informations
+    }
+}
+
+impl InformationElement for ReducedNeighborReport {
+    const NAME: &'static str = "Reduced Neighbor Report";
+    const ID: u8 = 201;
+
+    fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    fn information_fields(&self) -> Vec<Field> {
+        self.neighbor_ap_informations()
+            .iter()
+            .map(|neighbor_ap_information| {
+                Field::with_subfields(
+                    "Neighbor AP Information",
+                    format!(
+                        "Operating Class {}, Channel {}",
+                        neighbor_ap_information.operating_class(),
+                        neighbor_ap_information.channel()
+                    ),
+                    neighbor_ap_information.fields(),
+                )
+            })
+            .collect()
+    }
+}
+
+impl_display_for_ie!(ReducedNeighborReport);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reduced_neighbor_report_six_ghz_neighbors() {
+        let reduced_neighbor_report = ReducedNeighborReport::new(vec![
+            // 6 GHz channel 37 with one 13-octet TBTT Information field
+            0x00, 0x0D, 0x83, 0x25, 0xFF, 0x02, 0x11, 0x22, 0x33, 0x44, 0x55, 0x78, 0x56, 0x34,
+            0x12, 0x46, 0x2E, // 5 GHz channel 36 with two 1-octet TBTT Information fields
+            0x10, 0x01, 0x73, 0x24, 0x0A, 0x14,
+        ])
+        .unwrap();
+
+        let neighbor_ap_informations = reduced_neighbor_report.neighbor_ap_informations();
+        assert_eq!(neighbor_ap_informations.len(), 2);
+        assert!(neighbor_ap_informations[0].is_six_ghz());
+        assert_eq!(neighbor_ap_informations[0].channel(), 37);
+
+        let tbtt_information = &neighbor_ap_informations[0].tbtt_informations()[0];
+        assert_eq!(
+            tbtt_information.bssid(),
+            Some(MacAddr6::new(0x02, 0x11, 0x22, 0x33, 0x44, 0x55))
+        );
+        assert_eq!(tbtt_information.short_ssid(), Some(0x12345678));
+        let bss_parameters = tbtt_information.bss_parameters().unwrap();
+        assert!(bss_parameters.same_ssid() && bss_parameters.colocated_ap());
+        assert_eq!(tbtt_information.psd_twenty_mhz_dbm_per_mhz(), Some(23.0));
+
+        assert!(!neighbor_ap_informations[1].is_six_ghz());
+        assert_eq!(neighbor_ap_informations[1].tbtt_informations().len(), 2);
+    }
+}
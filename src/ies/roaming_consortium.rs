@@ -0,0 +1,104 @@
+use super::{Field, IeError, InformationElement};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoamingConsortium {
+    bytes: Vec<u8>,
+}
+
+impl RoamingConsortium {
+    pub const MIN_LENGTH: usize = 2;
+
+    pub fn new(bytes: Vec<u8>) -> Result<RoamingConsortium, IeError> {
+        if bytes.len() >= Self::MIN_LENGTH {
+            Ok(RoamingConsortium { bytes })
+        } else {
+            Err(IeError::InvalidLength {
+                ie_name: Self::NAME,
+                expected_length: Self::MIN_LENGTH,
+                actual_length: bytes.len(),
+            })
+        }
+    }
+
+    /// The number of additional OIs that are only available through ANQP.
+    pub fn number_of_anqp_ois(&self) -> u8 {
+        self.bytes[0]
+    }
+
+    /// Up to three OIs. The lengths of the first two are in the OI #1 and #2 Lengths field and the
+    /// third takes up the rest of the element.
+    pub fn ois(&self) -> Vec<Vec<u8>> {
+        let oi_one_length = (self.bytes[1] & 0b1111) as usize;
+        let oi_two_length = (self.bytes[1] >> 4) as usize;
+
+        let oi_bytes = &self.bytes[2..];
+        let mut ois = Vec::new();
+
+        if let Some(oi_one) = oi_bytes.get(..oi_one_length).filter(|oi| !oi.is_empty()) {
+            ois.push(oi_one.to_vec());
+        }
+        if let Some(oi_two) = oi_bytes
+            .get(oi_one_length..oi_one_length + oi_two_length)
+            .filter(|oi| !oi.is_empty())
+        {
+            ois.push(oi_two.to_vec());
+        }
+        if let Some(oi_three) = oi_bytes
+            .get(oi_one_length + oi_two_length..)
+            .filter(|oi| !oi.is_empty())
+        {
+            ois.push(oi_three.to_vec());
+        }
+
+        ois
+    }
+}
+
+impl InformationElement for RoamingConsortium {
+    const NAME: &'static str = "Roaming Consortium";
+    const ID: u8 = 111;
+
+    fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    fn information_fields(&self) -> Vec<Field> {
+        let mut information_fields =
+            vec![Field::new("Number of ANQP OIs", self.number_of_anqp_ois())];
+
+        for oi in self.ois() {
+            information_fields.push(Field::new(
+                "OI",
+                oi.iter()
+                    .map(|byte| format!("{:02X}", byte))
+                    .collect::<String>(),
+            ));
+        }
+
+        information_fields
+    }
+}
+
+impl_display_for_ie!(RoamingConsortium);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roaming_consortium_ois() {
+        let roaming_consortium = RoamingConsortium::new(vec![
+            0x00, 0x33, 0x50, 0x6F, 0x9A, 0x00, 0x1B, 0xC5, 0x04, 0xBD,
+        ])
+        .unwrap();
+
+        assert_eq!(
+            roaming_consortium.ois(),
+            vec![
+                vec![0x50, 0x6F, 0x9A],
+                vec![0x00, 0x1B, 0xC5],
+                vec![0x04, 0xBD]
+            ]
+        );
+    }
+}
@@ -1,24 +1,481 @@
-use super::{Field, InformationElement};
+use super::{Field, IeError, InformationElement};
+use bitvec::prelude::*;
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::{
+    fmt::Display,
+    io::{Cursor, Read},
+};
+
+const IEEE_OUI: [u8; 3] = [0x00, 0x0F, 0xAC];
+const MICROSOFT_OUI: [u8; 3] = [0x00, 0x50, 0xF2];
+const WFA_OUI: [u8; 3] = [0x50, 0x6F, 0x9A];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CipherSuite {
+    UseGroupCipher,
+    Wep40,
+    Tkip,
+    Ccmp128,
+    Wep104,
+    BipCmac128,
+    GroupAddressedTrafficNotAllowed,
+    Gcmp128,
+    Gcmp256,
+    Ccmp256,
+    BipGmac128,
+    BipGmac256,
+    BipCmac256,
+    Reserved(u8),
+    VendorSpecific { oui: [u8; 3], suite_type: u8 },
+}
+
+impl CipherSuite {
+    pub fn from_selector(selector: [u8; 4]) -> CipherSuite {
+        let (oui, suite_type) = ([selector[0], selector[1], selector[2]], selector[3]);
+
+        match (oui, suite_type) {
+            (IEEE_OUI, 0) => CipherSuite::UseGroupCipher,
+            (IEEE_OUI, 1) => CipherSuite::Wep40,
+            (IEEE_OUI, 2) => CipherSuite::Tkip,
+            (IEEE_OUI, 4) => CipherSuite::Ccmp128,
+            (IEEE_OUI, 5) => CipherSuite::Wep104,
+            (IEEE_OUI, 6) => CipherSuite::BipCmac128,
+            (IEEE_OUI, 7) => CipherSuite::GroupAddressedTrafficNotAllowed,
+            (IEEE_OUI, 8) => CipherSuite::Gcmp128,
+            (IEEE_OUI, 9) => CipherSuite::Gcmp256,
+            (IEEE_OUI, 10) => CipherSuite::Ccmp256,
+            (IEEE_OUI, 11) => CipherSuite::BipGmac128,
+            (IEEE_OUI, 12) => CipherSuite::BipGmac256,
+            (IEEE_OUI, 13) => CipherSuite::BipCmac256,
+            (IEEE_OUI, suite_type) => CipherSuite::Reserved(suite_type),
+            // The legacy WPA element uses the same suite types under Microsoft's OUI
+            (MICROSOFT_OUI, 0) => CipherSuite::UseGroupCipher,
+            (MICROSOFT_OUI, 1) => CipherSuite::Wep40,
+            (MICROSOFT_OUI, 2) => CipherSuite::Tkip,
+            (MICROSOFT_OUI, 4) => CipherSuite::Ccmp128,
+            (MICROSOFT_OUI, 5) => CipherSuite::Wep104,
+            (oui, suite_type) => CipherSuite::VendorSpecific { oui, suite_type },
+        }
+    }
+}
+
+impl Display for CipherSuite {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CipherSuite::UseGroupCipher => write!(f, "Use Group Cipher Suite"),
+            CipherSuite::Wep40 => write!(f, "WEP-40"),
+            CipherSuite::Tkip => write!(f, "TKIP"),
+            CipherSuite::Ccmp128 => write!(f, "CCMP-128"),
+            CipherSuite::Wep104 => write!(f, "WEP-104"),
+            CipherSuite::BipCmac128 => write!(f, "BIP-CMAC-128"),
+            CipherSuite::GroupAddressedTrafficNotAllowed => {
+                write!(f, "Group Addressed Traffic Not Allowed")
+            }
+            CipherSuite::Gcmp128 => write!(f, "GCMP-128"),
+            CipherSuite::Gcmp256 => write!(f, "GCMP-256"),
+            CipherSuite::Ccmp256 => write!(f, "CCMP-256"),
+            CipherSuite::BipGmac128 => write!(f, "BIP-GMAC-128"),
+            CipherSuite::BipGmac256 => write!(f, "BIP-GMAC-256"),
+            CipherSuite::BipCmac256 => write!(f, "BIP-CMAC-256"),
+            CipherSuite::Reserved(suite_type) => write!(f, "Reserved ({})", suite_type),
+            CipherSuite::VendorSpecific { oui, suite_type } => write!(
+                f,
+                "Vendor Specific ({:02X}-{:02X}-{:02X}:{})",
+                oui[0], oui[1], oui[2], suite_type
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AkmSuite {
+    Ieee8021X,
+    Psk,
+    FtIeee8021X,
+    FtPsk,
+    Ieee8021XSha256,
+    PskSha256,
+    Tdls,
+    Sae,
+    FtSae,
+    ApPeerKey,
+    Ieee8021XSuiteB,
+    Ieee8021XSuiteB192,
+    FtIeee8021XSha384,
+    FilsSha256,
+    FilsSha384,
+    FtFilsSha256,
+    FtFilsSha384,
+    Owe,
+    FtPskSha384,
+    PskSha384,
+    Pasn,
+    Ieee8021XSha384,
+    SaeExtKey,
+    FtSaeExtKey,
+    Osen,
+    Dpp,
+    Reserved(u8),
+    VendorSpecific { oui: [u8; 3], suite_type: u8 },
+}
+
+impl AkmSuite {
+    pub fn from_selector(selector: [u8; 4]) -> AkmSuite {
+        let (oui, suite_type) = ([selector[0], selector[1], selector[2]], selector[3]);
+
+        match (oui, suite_type) {
+            (IEEE_OUI, 1) => AkmSuite::Ieee8021X,
+            (IEEE_OUI, 2) => AkmSuite::Psk,
+            (IEEE_OUI, 3) => AkmSuite::FtIeee8021X,
+            (IEEE_OUI, 4) => AkmSuite::FtPsk,
+            (IEEE_OUI, 5) => AkmSuite::Ieee8021XSha256,
+            (IEEE_OUI, 6) => AkmSuite::PskSha256,
+            (IEEE_OUI, 7) => AkmSuite::Tdls,
+            (IEEE_OUI, 8) => AkmSuite::Sae,
+            (IEEE_OUI, 9) => AkmSuite::FtSae,
+            (IEEE_OUI, 10) => AkmSuite::ApPeerKey,
+            (IEEE_OUI, 11) => AkmSuite::Ieee8021XSuiteB,
+            (IEEE_OUI, 12) => AkmSuite::Ieee8021XSuiteB192,
+            (IEEE_OUI, 13) => AkmSuite::FtIeee8021XSha384,
+            (IEEE_OUI, 14) => AkmSuite::FilsSha256,
+            (IEEE_OUI, 15) => AkmSuite::FilsSha384,
+            (IEEE_OUI, 16) => AkmSuite::FtFilsSha256,
+            (IEEE_OUI, 17) => AkmSuite::FtFilsSha384,
+            (IEEE_OUI, 18) => AkmSuite::Owe,
+            (IEEE_OUI, 19) => AkmSuite::FtPskSha384,
+            (IEEE_OUI, 20) => AkmSuite::PskSha384,
+            (IEEE_OUI, 21) => AkmSuite::Pasn,
+            (IEEE_OUI, 23) => AkmSuite::Ieee8021XSha384,
+            (IEEE_OUI, 24) => AkmSuite::SaeExtKey,
+            (IEEE_OUI, 25) => AkmSuite::FtSaeExtKey,
+            (IEEE_OUI, suite_type) => AkmSuite::Reserved(suite_type),
+            (MICROSOFT_OUI, 1) => AkmSuite::Ieee8021X,
+            (MICROSOFT_OUI, 2) => AkmSuite::Psk,
+            (WFA_OUI, 1) => AkmSuite::Osen,
+            (WFA_OUI, 2) => AkmSuite::Dpp,
+            (oui, suite_type) => AkmSuite::VendorSpecific { oui, suite_type },
+        }
+    }
+
+    pub fn is_fast_transition(&self) -> bool {
+        matches!(
+            self,
+            AkmSuite::FtIeee8021X
+                | AkmSuite::FtPsk
+                | AkmSuite::FtSae
+                | AkmSuite::FtIeee8021XSha384
+                | AkmSuite::FtFilsSha256
+                | AkmSuite::FtFilsSha384
+                | AkmSuite::FtPskSha384
+                | AkmSuite::FtSaeExtKey
+        )
+    }
+}
+
+impl Display for AkmSuite {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AkmSuite::Ieee8021X => write!(f, "IEEE 802.1X"),
+            AkmSuite::Psk => write!(f, "PSK"),
+            AkmSuite::FtIeee8021X => write!(f, "FT over IEEE 802.1X"),
+            AkmSuite::FtPsk => write!(f, "FT-PSK"),
+            AkmSuite::Ieee8021XSha256 => write!(f, "IEEE 802.1X (SHA-256)"),
+            AkmSuite::PskSha256 => write!(f, "PSK (SHA-256)"),
+            AkmSuite::Tdls => write!(f, "TDLS"),
+            AkmSuite::Sae => write!(f, "SAE"),
+            AkmSuite::FtSae => write!(f, "FT over SAE"),
+            AkmSuite::ApPeerKey => write!(f, "APPeerKey"),
+            AkmSuite::Ieee8021XSuiteB => write!(f, "IEEE 802.1X Suite B"),
+            AkmSuite::Ieee8021XSuiteB192 => write!(f, "IEEE 802.1X Suite B 192-bit"),
+            AkmSuite::FtIeee8021XSha384 => write!(f, "FT over IEEE 802.1X (SHA-384)"),
+            AkmSuite::FilsSha256 => write!(f, "FILS (SHA-256)"),
+            AkmSuite::FilsSha384 => write!(f, "FILS (SHA-384)"),
+            AkmSuite::FtFilsSha256 => write!(f, "FT over FILS (SHA-256)"),
+            AkmSuite::FtFilsSha384 => write!(f, "FT over FILS (SHA-384)"),
+            AkmSuite::Owe => write!(f, "OWE"),
+            AkmSuite::FtPskSha384 => write!(f, "FT-PSK (SHA-384)"),
+            AkmSuite::PskSha384 => write!(f, "PSK (SHA-384)"),
+            AkmSuite::Pasn => write!(f, "PASN"),
+            AkmSuite::Ieee8021XSha384 => write!(f, "IEEE 802.1X (SHA-384)"),
+            AkmSuite::SaeExtKey => write!(f, "SAE (Group-Dependent Hash)"),
+            AkmSuite::FtSaeExtKey => write!(f, "FT over SAE (Group-Dependent Hash)"),
+            AkmSuite::Osen => write!(f, "OSEN"),
+            AkmSuite::Dpp => write!(f, "DPP"),
+            AkmSuite::Reserved(suite_type) => write!(f, "Reserved ({})", suite_type),
+            AkmSuite::VendorSpecific { oui, suite_type } => write!(
+                f,
+                "Vendor Specific ({:02X}-{:02X}-{:02X}:{})",
+                oui[0], oui[1], oui[2], suite_type
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RsnCapabilities {
+    bits: BitArray<LocalBits, [u8; 2]>,
+}
+
+impl RsnCapabilities {
+    pub fn new(rsn_capabilities: [u8; 2]) -> RsnCapabilities {
+        RsnCapabilities {
+            bits: BitArray::new(rsn_capabilities),
+        }
+    }
+
+    pub fn preauthentication(&self) -> bool {
+        self.bits[0]
+    }
+
+    pub fn no_pairwise(&self) -> bool {
+        self.bits[1]
+    }
+
+    pub fn ptksa_replay_counter(&self) -> u8 {
+        Self::replay_counter(self.bits[2..=3].load())
+    }
+
+    pub fn gtksa_replay_counter(&self) -> u8 {
+        Self::replay_counter(self.bits[4..=5].load())
+    }
+
+    pub fn mfpr(&self) -> bool {
+        self.bits[6]
+    }
+
+    pub fn mfpc(&self) -> bool {
+        self.bits[7]
+    }
+
+    pub fn joint_multiband_rsna(&self) -> bool {
+        self.bits[8]
+    }
+
+    pub fn peerkey_enabled(&self) -> bool {
+        self.bits[9]
+    }
+
+    pub fn spp_amsdu_capable(&self) -> bool {
+        self.bits[10]
+    }
+
+    pub fn spp_amsdu_required(&self) -> bool {
+        self.bits[11]
+    }
+
+    pub fn pbac(&self) -> bool {
+        self.bits[12]
+    }
+
+    pub fn extended_key_id(&self) -> bool {
+        self.bits[13]
+    }
+
+    pub fn ocvc(&self) -> bool {
+        self.bits[14]
+    }
+
+    pub fn fields(&self) -> Vec<Field> {
+        vec![
+            Field::new("Preauthentication", self.preauthentication()),
+            Field::new("No Pairwise", self.no_pairwise()),
+            Field::new("PTKSA Replay Counter", self.ptksa_replay_counter()),
+            Field::new("GTKSA Replay Counter", self.gtksa_replay_counter()),
+            Field::new("MFPR", self.mfpr()),
+            Field::new("MFPC", self.mfpc()),
+            Field::new("Joint Multi-Band RSNA", self.joint_multiband_rsna()),
+            Field::new("PeerKey Enabled", self.peerkey_enabled()),
+            Field::new("SPP A-MSDU Capable", self.spp_amsdu_capable()),
+            Field::new("SPP A-MSDU Required", self.spp_amsdu_required()),
+            Field::new("PBAC", self.pbac()),
+            Field::new(
+                "Extended Key ID for Individually Addressed Frames",
+                self.extended_key_id(),
+            ),
+            Field::new("OCVC", self.ocvc()),
+        ]
+    }
+
+    fn replay_counter(value: u8) -> u8 {
+        match value {
+            0 => 1,
+            1 => 2,
+            2 => 4,
+            _ => 16,
+        }
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Rsn {
     bytes: Vec<u8>,
+    version: u16,
+    group_data_cipher_suite: Option<CipherSuite>,
+    pairwise_cipher_suites: Option<Vec<CipherSuite>>,
+    akm_suites: Option<Vec<AkmSuite>>,
+    rsn_capabilities: Option<RsnCapabilities>,
+    pmkids: Option<Vec<[u8; 16]>>,
+    group_management_cipher_suite: Option<CipherSuite>,
+    truncated: bool,
 }
 
 impl Rsn {
+    pub const MIN_LENGTH: usize = 2;
+
+    pub fn new(bytes: Vec<u8>) -> Result<Rsn, IeError> {
+        if bytes.len() < Self::MIN_LENGTH {
+            return Err(IeError::InvalidLength {
+                ie_name: Self::NAME,
+                expected_length: Self::MIN_LENGTH,
+                actual_length: bytes.len(),
+            });
+        }
+
+        let mut rsn = Rsn {
+            bytes,
+            version: 0,
+            group_data_cipher_suite: None,
+            pairwise_cipher_suites: None,
+            akm_suites: None,
+            rsn_capabilities: None,
+            pmkids: None,
+            group_management_cipher_suite: None,
+            truncated: false,
+        };
 
-    pub fn new(bytes: Vec<u8>) -> Rsn {
-        Rsn { bytes }
+        // Every field after the version is optional, but if a field is missing then all of the
+        // fields after it must be missing too, so stop parsing at the first field we can't read.
+        // A field we can't read that still has bytes left means the element was cut short
+        let mut cursor = Cursor::new(rsn.bytes.as_slice());
+        let has_bytes_left =
+            |cursor: &Cursor<&[u8]>| cursor.position() < cursor.get_ref().len() as u64;
+        rsn.version = cursor.read_u16::<LittleEndian>().unwrap_or_default();
+        rsn.truncated = has_bytes_left(&cursor);
+        rsn.group_data_cipher_suite = read_selector(&mut cursor).map(CipherSuite::from_selector);
+        if rsn.group_data_cipher_suite.is_none() {
+            return Ok(rsn);
+        }
+        rsn.truncated = has_bytes_left(&cursor);
+        rsn.pairwise_cipher_suites = read_selector_list(&mut cursor).map(|selectors| {
+            selectors
+                .into_iter()
+                .map(CipherSuite::from_selector)
+                .collect()
+        });
+        if rsn.pairwise_cipher_suites.is_none() {
+            return Ok(rsn);
+        }
+        rsn.truncated = has_bytes_left(&cursor);
+        rsn.akm_suites = read_selector_list(&mut cursor)
+            .map(|selectors| selectors.into_iter().map(AkmSuite::from_selector).collect());
+        if rsn.akm_suites.is_none() {
+            return Ok(rsn);
+        }
+        rsn.truncated = has_bytes_left(&cursor);
+        rsn.rsn_capabilities = {
+            let mut capabilities = [0; 2];
+            cursor
+                .read_exact(&mut capabilities)
+                .ok()
+                .map(|_| RsnCapabilities::new(capabilities))
+        };
+        if rsn.rsn_capabilities.is_none() {
+            return Ok(rsn);
+        }
+        rsn.truncated = has_bytes_left(&cursor);
+        rsn.pmkids = cursor.read_u16::<LittleEndian>().ok().and_then(|count| {
+            (0..count)
+                .map(|_| {
+                    let mut pmkid = [0; 16];
+                    cursor.read_exact(&mut pmkid).ok().map(|_| pmkid)
+                })
+                .collect()
+        });
+        if rsn.pmkids.is_none() {
+            return Ok(rsn);
+        }
+        rsn.truncated = has_bytes_left(&cursor);
+        rsn.group_management_cipher_suite =
+            read_selector(&mut cursor).map(CipherSuite::from_selector);
+        if rsn.group_management_cipher_suite.is_some() {
+            rsn.truncated = false;
+        }
+
+        Ok(rsn)
     }
-}
 
-impl InformationElement for Rsn {
-    fn name(&self) -> &'static str {
-        Rsn::NAME
+    pub fn version(&self) -> u16 {
+        self.version
+    }
+
+    /// Whether the element ends partway through a field. The fields from there on are unknown
+    /// rather than absent, so their accessors return None instead of the defaults.
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    // The field, the default if the field is absent, or None if the element was cut short before
+    // the field
+    fn field_or_default<T>(&self, field: &Option<T>, default: impl FnOnce() -> T) -> Option<T>
+    where
+        T: Clone,
+    {
+        match field {
+            Some(field) => Some(field.clone()),
+            None if self.truncated => None,
+            None => Some(default()),
+        }
+    }
+
+    /// The group data cipher suite, which is CCMP-128 if the field is absent.
+    pub fn group_data_cipher_suite(&self) -> Option<CipherSuite> {
+        self.field_or_default(&self.group_data_cipher_suite, || CipherSuite::Ccmp128)
+    }
+
+    /// The pairwise cipher suites, which is a list containing CCMP-128 if the field is absent.
+    pub fn pairwise_cipher_suites(&self) -> Option<Vec<CipherSuite>> {
+        self.field_or_default(&self.pairwise_cipher_suites, || vec![CipherSuite::Ccmp128])
+    }
+
+    /// The AKM suites, which is a list containing IEEE 802.1X if the field is absent.
+    pub fn akm_suites(&self) -> Option<Vec<AkmSuite>> {
+        self.field_or_default(&self.akm_suites, || vec![AkmSuite::Ieee8021X])
+    }
+
+    /// The RSN capabilities, which has every subfield set to 0 if the field is absent.
+    pub fn rsn_capabilities(&self) -> Option<RsnCapabilities> {
+        self.field_or_default(&self.rsn_capabilities, || RsnCapabilities::new([0; 2]))
+    }
+
+    pub fn pmkids(&self) -> Option<Vec<[u8; 16]>> {
+        self.field_or_default(&self.pmkids, Vec::new)
+    }
+
+    /// The group management cipher suite, which is BIP-CMAC-128 if the field is absent and
+    /// management frame protection is enabled.
+    pub fn group_management_cipher_suite(&self) -> Option<CipherSuite> {
+        self.group_management_cipher_suite.or_else(|| {
+            if !self.truncated
+                && self
+                    .rsn_capabilities()
+                    .is_some_and(|rsn_capabilities| rsn_capabilities.mfpc())
+            {
+                Some(CipherSuite::BipCmac128)
+            } else {
+                None
+            }
+        })
     }
 
-    fn id(&self) -> u8 {
-        Rsn::ID
+    // The value of a field for information_fields(), marking defaults and unknown values
+    fn field_value(&self, value: impl Display, is_absent: bool) -> String {
+        if !is_absent {
+            value.to_string()
+        } else if self.truncated {
+            String::from("Unknown (Truncated)")
+        } else {
+            format!("{} (Default)", value)
+        }
     }
 }
 
@@ -31,10 +488,208 @@ impl InformationElement for Rsn {
     }
 
     fn information_fields(&self) -> Vec<Field> {
-        Vec::new()
+        let suite_list_field =
+            |title: &str, subtitle: &str, suites: Option<Vec<String>>, is_absent| {
+                let suites = suites.unwrap_or_default();
+                Field::with_subfields(
+                    title,
+                    self.field_value(suites.len(), is_absent),
+                    suites
+                        .iter()
+                        .map(|suite| Field::new(subtitle, self.field_value(suite, is_absent)))
+                        .collect(),
+                )
+            };
+
+        let mut information_fields = vec![
+            Field::new("Version", self.version()),
+            Field::new(
+                "Group Data Cipher Suite",
+                self.field_value(
+                    self.group_data_cipher_suite()
+                        .map(|suite| suite.to_string())
+                        .unwrap_or_default(),
+                    self.group_data_cipher_suite.is_none(),
+                ),
+            ),
+            suite_list_field(
+                "Pairwise Cipher Suites",
+                "Pairwise Cipher Suite",
+                self.pairwise_cipher_suites()
+                    .map(|suites| suites.iter().map(|suite| suite.to_string()).collect()),
+                self.pairwise_cipher_suites.is_none(),
+            ),
+            suite_list_field(
+                "AKM Suites",
+                "AKM Suite",
+                self.akm_suites()
+                    .map(|suites| suites.iter().map(|suite| suite.to_string()).collect()),
+                self.akm_suites.is_none(),
+            ),
+        ];
+
+        if let Some(rsn_capabilities) = self.rsn_capabilities() {
+            information_fields.push(Field::with_subfields(
+                "RSN Capabilities",
+                self.field_value(
+                    format!("{:02?}", rsn_capabilities.bits.as_raw_slice()),
+                    self.rsn_capabilities.is_none(),
+                ),
+                rsn_capabilities.fields(),
+            ));
+        } else {
+            information_fields.push(Field::new("RSN Capabilities", self.field_value("", true)));
+        }
+
+        if let Some(pmkids) = &self.pmkids {
+            information_fields.push(Field::with_subfields(
+                "PMKIDs",
+                pmkids.len(),
+                pmkids
+                    .iter()
+                    .map(|pmkid| Field::new("PMKID", format!("{:02X?}", pmkid)))
+                    .collect(),
+            ));
+        }
+
+        if let Some(group_management_cipher_suite) = self.group_management_cipher_suite() {
+            information_fields.push(Field::new(
+                "Group Management Cipher Suite",
+                self.field_value(
+                    group_management_cipher_suite,
+                    self.group_management_cipher_suite.is_none(),
+                ),
+            ));
+        }
+
+        information_fields
     }
 }
 
 impl_display_for_ie!(Rsn);
+
+pub(super) fn read_selector(cursor: &mut Cursor<&[u8]>) -> Option<[u8; 4]> {
+    let mut selector = [0; 4];
+    cursor.read_exact(&mut selector).ok()?;
+    Some(selector)
+}
+
+pub(super) fn read_selector_list(cursor: &mut Cursor<&[u8]>) -> Option<Vec<[u8; 4]>> {
+    let count = cursor.read_u16::<LittleEndian>().ok()?;
+    (0..count).map(|_| read_selector(cursor)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wpa2_wpa3_transition() {
+        let rsn = Rsn::new(vec![
+            0x01, 0x00, 0x00, 0x0F, 0xAC, 0x04, 0x01, 0x00, 0x00, 0x0F, 0xAC, 0x04, 0x02, 0x00,
+            0x00, 0x0F, 0xAC, 0x02, 0x00, 0x0F, 0xAC, 0x08, 0x80, 0x00,
+        ])
+        .unwrap();
+
+        assert_eq!(rsn.version(), 1);
+        assert_eq!(rsn.group_data_cipher_suite(), Some(CipherSuite::Ccmp128));
+        assert_eq!(
+            rsn.pairwise_cipher_suites(),
+            Some(vec![CipherSuite::Ccmp128])
+        );
+        assert_eq!(rsn.akm_suites(), Some(vec![AkmSuite::Psk, AkmSuite::Sae]));
+        assert!(rsn.rsn_capabilities().unwrap().mfpc());
+        assert!(!rsn.rsn_capabilities().unwrap().mfpr());
+        assert_eq!(rsn.pmkids(), Some(vec![]));
+        assert!(!rsn.is_truncated());
+        assert_eq!(
+            rsn.group_management_cipher_suite(),
+            Some(CipherSuite::BipCmac128)
+        );
+    }
+
+    #[test]
+    fn truncated_after_version() {
+        let rsn = Rsn::new(vec![0x01, 0x00]).unwrap();
+
+        // The fields are absent rather than truncated, so they take their default values
+        assert!(!rsn.is_truncated());
+        assert_eq!(rsn.group_data_cipher_suite(), Some(CipherSuite::Ccmp128));
+        assert_eq!(
+            rsn.pairwise_cipher_suites(),
+            Some(vec![CipherSuite::Ccmp128])
+        );
+        assert_eq!(rsn.akm_suites(), Some(vec![AkmSuite::Ieee8021X]));
+        assert_eq!(rsn.group_management_cipher_suite(), None);
+        assert!(rsn
+            .information_fields()
+            .iter()
+            .any(|field| field.value().ends_with("(Default)")));
+    }
+
+    #[test]
+    fn shorter_than_version() {
+        // A one-byte RSN element is kept as an unknown element instead of failing the whole frame
+        let ies = crate::ies::from_bytes(&[48, 1, 0x01, 0, 0]).unwrap();
+
+        assert_eq!(ies.len(), 2);
+        assert!(matches!(&ies[0], crate::Ie::Unknown(unknown) if unknown.id() == Rsn::ID));
+    }
+
+    #[test]
+    fn truncated_suite_list() {
+        // The pairwise cipher suite count says there are two suites but only one is present
+        let rsn = Rsn::new(vec![
+            0x01, 0x00, 0x00, 0x0F, 0xAC, 0x02, 0x02, 0x00, 0x00, 0x0F, 0xAC, 0x04,
+        ])
+        .unwrap();
+
+        // The fields from the pairwise cipher suites on are unknown, not absent
+        assert!(rsn.is_truncated());
+        assert_eq!(rsn.group_data_cipher_suite(), Some(CipherSuite::Tkip));
+        assert_eq!(rsn.pairwise_cipher_suites(), None);
+        assert_eq!(rsn.akm_suites(), None);
+        assert_eq!(rsn.rsn_capabilities(), None);
+        assert_eq!(rsn.group_management_cipher_suite(), None);
+    }
+
+    #[test]
+    fn wpa3_enterprise_192_bit() {
+        let rsn = Rsn::new(vec![
+            0x01, 0x00, 0x00, 0x0F, 0xAC, 0x09, 0x01, 0x00, 0x00, 0x0F, 0xAC, 0x09, 0x01, 0x00,
+            0x00, 0x0F, 0xAC, 0x0C, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x0F, 0xAC, 0x0C,
+        ])
+        .unwrap();
+
+        assert_eq!(
+            rsn.pairwise_cipher_suites(),
+            Some(vec![CipherSuite::Gcmp256])
+        );
+        assert_eq!(rsn.akm_suites(), Some(vec![AkmSuite::Ieee8021XSuiteB192]));
+        assert!(rsn.rsn_capabilities().unwrap().mfpr());
+        assert!(!rsn.is_truncated());
+        assert_eq!(
+            rsn.group_management_cipher_suite(),
+            Some(CipherSuite::BipGmac256)
+        );
+    }
+
+    #[test]
+    fn unknown_suites() {
+        assert_eq!(
+            CipherSuite::from_selector([0x00, 0x0F, 0xAC, 0x63]),
+            CipherSuite::Reserved(0x63)
+        );
+        assert_eq!(
+            AkmSuite::from_selector([0x00, 0x40, 0x96, 0x00]),
+            AkmSuite::VendorSpecific {
+                oui: [0x00, 0x40, 0x96],
+                suite_type: 0
+            }
+        );
+        assert_eq!(
+            AkmSuite::from_selector([0x50, 0x6F, 0x9A, 0x02]),
+            AkmSuite::Dpp
+        );
     }
 }
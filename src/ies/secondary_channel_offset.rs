@@ -0,0 +1,60 @@
+use super::{Field, IeError, InformationElement, SecondaryChannelOffset};
+use std::convert::{TryFrom, TryInto};
+
+/// The Secondary Channel Offset element, which a BSS switching to a 40 MHz channel includes with
+/// its Channel Switch Announcement element. It's named for the element to tell it apart from the
+/// [`SecondaryChannelOffset`] field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SecondaryChannelOffsetElement {
+    bytes: [u8; Self::LENGTH],
+}
+
+impl SecondaryChannelOffsetElement {
+    pub const LENGTH: usize = 1;
+
+    pub fn new(bytes: Vec<u8>) -> Result<SecondaryChannelOffsetElement, IeError> {
+        let bytes: [u8; Self::LENGTH] =
+            bytes
+                .try_into()
+                .map_err(|ie_data: Vec<u8>| IeError::InvalidLength {
+                    ie_name: Self::NAME,
+                    expected_length: Self::LENGTH,
+                    actual_length: ie_data.len(),
+                })?;
+        Ok(SecondaryChannelOffsetElement::from(bytes))
+    }
+
+    /// The position of the secondary channel, or None if the value is reserved.
+    pub fn secondary_channel_offset(&self) -> Option<SecondaryChannelOffset> {
+        SecondaryChannelOffset::try_from(self.bytes[0]).ok()
+    }
+}
+
+impl InformationElement for SecondaryChannelOffsetElement {
+    const NAME: &'static str = "Secondary Channel Offset";
+    const ID: u8 = 62;
+
+    fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    fn information_fields(&self) -> Vec<Field> {
+        vec![match self.secondary_channel_offset() {
+            Some(secondary_channel_offset) => {
+                Field::new("Secondary Channel Offset", secondary_channel_offset)
+            }
+            None => Field::new(
+                "Secondary Channel Offset",
+                format!("Reserved ({})", self.bytes[0]),
+            ),
+        }]
+    }
+}
+
+impl From<[u8; Self::LENGTH]> for SecondaryChannelOffsetElement {
+    fn from(bytes: [u8; Self::LENGTH]) -> Self {
+        SecondaryChannelOffsetElement { bytes }
+    }
+}
+
+impl_display_for_ie!(SecondaryChannelOffsetElement);
@@ -1,4 +1,5 @@
 use super::{Field, InformationElement};
+use std::fmt::{Display, Write};
 use std::str;
 use std::str::Utf8Error;
 
@@ -15,6 +16,36 @@ impl Ssid {
     pub fn as_str(&self) -> Result<&str, Utf8Error> {
         str::from_utf8(&self.bytes)
     }
+
+    /// Decodes the SSID as UTF-8, or with the first of the fallback encodings that can decode it.
+    /// `utf8_ssid` is the UTF-8 SSID bit of the Extended Capabilities element; an SSID the BSS
+    /// declares as UTF-8 isn't decoded with the fallback encodings.
+    pub fn decode(&self, utf8_ssid: bool, fallback_encodings: &[SsidEncoding]) -> DecodedSsid {
+        let kind = if self.bytes.is_empty() {
+            SsidKind::Empty
+        } else if self.bytes.iter().all(|byte| *byte == 0) {
+            SsidKind::Hidden
+        } else if let Ok(text) = self.as_str() {
+            SsidKind::Utf8(text.to_string())
+        } else if utf8_ssid {
+            SsidKind::Undecodable
+        } else {
+            fallback_encodings
+                .iter()
+                .find_map(|encoding| {
+                    encoding
+                        .decode(&self.bytes)
+                        .map(|text| SsidKind::Legacy(text, *encoding))
+                })
+                .unwrap_or(SsidKind::Undecodable)
+        };
+
+        DecodedSsid {
+            bytes: self.bytes.clone(),
+            kind,
+            utf8_declared: utf8_ssid,
+        }
+    }
 }
 
 impl InformationElement for Ssid {
@@ -26,8 +57,232 @@ impl InformationElement for Ssid {
     }
 
     fn information_fields(&self) -> Vec<Field> {
-        vec![Field::new("SSID", self.as_str().unwrap_or_default())]
+        vec![Field::new("SSID", self.decode(false, &[]).escaped())]
     }
 }
 
 impl_display_for_ie!(Ssid);
+
+/// A legacy encoding to decode SSIDs that aren't UTF-8 with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SsidEncoding {
+    /// Simplified Chinese, a superset of GB 2312.
+    Gbk,
+    /// Japanese.
+    ShiftJis,
+    /// ISO 8859-1, which decodes any bytes.
+    Latin1,
+}
+
+impl SsidEncoding {
+    /// The fallback encodings for Simplified Chinese SSIDs. GBK and Shift_JIS can decode many of
+    /// the same byte sequences to different text, so a locale only tries one of them before
+    /// ISO 8859-1, which never fails.
+    pub const SIMPLIFIED_CHINESE: [SsidEncoding; 2] = [SsidEncoding::Gbk, SsidEncoding::Latin1];
+
+    /// The fallback encodings for Japanese SSIDs.
+    pub const JAPANESE: [SsidEncoding; 2] = [SsidEncoding::ShiftJis, SsidEncoding::Latin1];
+
+    /// Decodes the bytes, or returns None if they aren't valid in the encoding.
+    pub fn decode(&self, bytes: &[u8]) -> Option<String> {
+        let encoding = match self {
+            SsidEncoding::Gbk => encoding_rs::GBK,
+            SsidEncoding::ShiftJis => encoding_rs::SHIFT_JIS,
+            SsidEncoding::Latin1 => {
+                return Some(bytes.iter().map(|byte| char::from(*byte)).collect())
+            }
+        };
+
+        encoding
+            .decode_without_bom_handling_and_without_replacement(bytes)
+            .map(String::from)
+    }
+}
+
+impl Display for SsidEncoding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SsidEncoding::Gbk => write!(f, "GBK"),
+            SsidEncoding::ShiftJis => write!(f, "Shift_JIS"),
+            SsidEncoding::Latin1 => write!(f, "ISO 8859-1"),
+        }
+    }
+}
+
+/// What an SSID turned out to be when it was decoded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SsidKind {
+    /// A zero-length SSID, which hidden BSSs and wildcard probe requests use.
+    Empty,
+    /// NUL bytes in place of the SSID, which hidden BSSs use to give away only its length.
+    Hidden,
+    Utf8(String),
+    /// Text decoded with one of the fallback encodings.
+    Legacy(String, SsidEncoding),
+    /// Bytes that none of the encodings could decode.
+    Undecodable,
+}
+
+/// An SSID and what it decoded to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedSsid {
+    bytes: Vec<u8>,
+    kind: SsidKind,
+    utf8_declared: bool,
+}
+
+impl DecodedSsid {
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    pub fn kind(&self) -> &SsidKind {
+        &self.kind
+    }
+
+    /// Whether the BSS declares its SSID as UTF-8 in its Extended Capabilities element.
+    pub fn utf8_declared(&self) -> bool {
+        self.utf8_declared
+    }
+
+    /// Whether the BSS hides its SSID, either with a zero-length SSID or with NUL bytes.
+    pub fn is_hidden(&self) -> bool {
+        matches!(self.kind, SsidKind::Empty | SsidKind::Hidden)
+    }
+
+    /// The decoded text, which isn't escaped.
+    pub fn text(&self) -> Option<&str> {
+        match &self.kind {
+            SsidKind::Utf8(text) | SsidKind::Legacy(text, _) => Some(text),
+            _ => None,
+        }
+    }
+
+    /// The encoding the text was decoded with.
+    pub fn encoding(&self) -> Option<SsidEncoding> {
+        match self.kind {
+            SsidKind::Legacy(_, encoding) => Some(encoding),
+            _ => None,
+        }
+    }
+
+    /// The SSID made safe to print to a terminal. Control characters, bidirectional formatting
+    /// characters, zero-width characters and backslashes are escaped, as are the bytes of hidden
+    /// and undecodable SSIDs that aren't printable ASCII.
+    pub fn escaped(&self) -> String {
+        let mut escaped = String::new();
+
+        match self.text() {
+            Some(text) => {
+                for c in text.chars() {
+                    if c == '\\' {
+                        escaped.push_str("\\\\");
+                    } else if needs_escaping(c) {
+                        let _ = write!(escaped, "\\u{{{:04x}}}", u32::from(c));
+                    } else {
+                        escaped.push(c);
+                    }
+                }
+            }
+            None => {
+                for byte in self.bytes.iter() {
+                    match byte {
+                        b'\\' => escaped.push_str("\\\\"),
+                        0x20..=0x7e => escaped.push(char::from(*byte)),
+                        _ => {
+                            let _ = write!(escaped, "\\x{:02x}", byte);
+                        }
+                    }
+                }
+            }
+        }
+
+        escaped
+    }
+
+    /// The escaped SSID as a CSV field, quoted if it has to be and with a leading apostrophe if a
+    /// spreadsheet could take it for a formula.
+    pub fn csv_field(&self) -> String {
+        let mut field = self.escaped();
+
+        if field.starts_with(['=', '+', '-', '@']) {
+            field.insert(0, '\'');
+        }
+        if field.contains([',', '"']) || field.starts_with(' ') || field.ends_with(' ') {
+            field = format!("\"{}\"", field.replace('"', "\"\""));
+        }
+
+        field
+    }
+}
+
+impl Display for DecodedSsid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.escaped())
+    }
+}
+
+// Characters that could hide or disguise text in a terminal or a spreadsheet: control characters,
+// bidirectional formatting characters and zero-width characters
+fn needs_escaping(c: char) -> bool {
+    c.is_control()
+        || matches!(
+            c,
+            '\u{061c}'
+                | '\u{200b}'..='\u{200f}'
+                | '\u{202a}'..='\u{202e}'
+                | '\u{2060}'..='\u{2064}'
+                | '\u{2066}'..='\u{2069}'
+                | '\u{feff}'
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_ssids() {
+        assert_eq!(
+            *Ssid::new(vec![]).decode(false, &[]).kind(),
+            SsidKind::Empty
+        );
+        assert_eq!(
+            *Ssid::new(vec![0; 8]).decode(false, &[]).kind(),
+            SsidKind::Hidden
+        );
+
+        let ssid = Ssid::new("Café\u{202e}\tx".as_bytes().to_vec()).decode(true, &[]);
+        assert_eq!(ssid.text(), Some("Café\u{202e}\tx"));
+        assert_eq!(ssid.escaped(), "Café\\u{202e}\\u{0009}x");
+
+        // "中文" in GBK
+        let gbk_bytes = vec![0xd6, 0xd0, 0xce, 0xc4];
+        let ssid = Ssid::new(gbk_bytes.clone()).decode(false, &SsidEncoding::SIMPLIFIED_CHINESE);
+        assert_eq!(
+            *ssid.kind(),
+            SsidKind::Legacy(String::from("中文"), SsidEncoding::Gbk)
+        );
+        let ssid = Ssid::new(gbk_bytes).decode(true, &SsidEncoding::SIMPLIFIED_CHINESE);
+        assert_eq!(*ssid.kind(), SsidKind::Undecodable);
+        assert_eq!(ssid.escaped(), "\\xd6\\xd0\\xce\\xc4");
+
+        // "日本語" in Shift_JIS, which GBK would also decode
+        let shift_jis_bytes = vec![0x93, 0xfa, 0x96, 0x7b, 0x8c, 0xea];
+        let ssid = Ssid::new(shift_jis_bytes.clone()).decode(false, &SsidEncoding::JAPANESE);
+        assert_eq!(
+            *ssid.kind(),
+            SsidKind::Legacy(String::from("日本語"), SsidEncoding::ShiftJis)
+        );
+        assert_eq!(
+            SsidEncoding::ShiftJis.decode(&shift_jis_bytes),
+            Some(String::from("日本語"))
+        );
+
+        let ssid = Ssid::new(vec![b'C', b'a', b'f', 0xe9]).decode(false, &[SsidEncoding::Latin1]);
+        assert_eq!(ssid.text(), Some("Café"));
+
+        let ssid = Ssid::new(b"=1+1, \"hi\"".to_vec()).decode(false, &[]);
+        assert_eq!(ssid.csv_field(), "\"'=1+1, \"\"hi\"\"\"");
+    }
+}
@@ -0,0 +1,146 @@
+use super::{operating_class_field, Field, IeError, InformationElement};
+
+const ONE_HUNDRED_AND_THIRTY_DELIMITER: u8 = 130;
+const ZERO_DELIMITER: u8 = 0;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SupportedOperatingClasses {
+    bytes: Vec<u8>,
+}
+
+impl SupportedOperatingClasses {
+    pub const MIN_LENGTH: usize = 2;
+
+    pub fn new(bytes: Vec<u8>) -> Result<SupportedOperatingClasses, IeError> {
+        if bytes.len() >= Self::MIN_LENGTH {
+            Ok(SupportedOperatingClasses { bytes })
+        } else {
+            Err(IeError::InvalidLength {
+                ie_name: Self::NAME,
+                expected_length: Self::MIN_LENGTH,
+                actual_length: bytes.len(),
+            })
+        }
+    }
+
+    pub fn current_operating_class(&self) -> u8 {
+        self.bytes[0]
+    }
+
+    // The Operating Classes field ends at the first delimiter
+    fn operating_classes_end(&self) -> usize {
+        self.bytes[1..]
+            .iter()
+            .position(|byte| *byte == ONE_HUNDRED_AND_THIRTY_DELIMITER || *byte == ZERO_DELIMITER)
+            .map_or(self.bytes.len(), |position| position + 1)
+    }
+
+    fn zero_delimiter_position(&self) -> Option<usize> {
+        let start = self.operating_classes_end();
+        self.bytes[start..]
+            .iter()
+            .position(|byte| *byte == ZERO_DELIMITER)
+            .map(|position| start + position)
+    }
+
+    pub fn operating_classes(&self) -> &[u8] {
+        &self.bytes[1..self.operating_classes_end()]
+    }
+
+    /// The Current Operating Class Extension sequence, which follows the 130 delimiter.
+    pub fn current_operating_class_extension(&self) -> Option<&[u8]> {
+        let start = self.operating_classes_end();
+        if self.bytes.get(start) != Some(&ONE_HUNDRED_AND_THIRTY_DELIMITER) {
+            return None;
+        }
+
+        let end = self.zero_delimiter_position().unwrap_or(self.bytes.len());
+        Some(&self.bytes[start + 1..end])
+    }
+
+    /// The operating class duples of the Operating Class Duple sequence, which follows the zero
+    /// delimiter.
+    pub fn operating_class_duples(&self) -> Vec<(u8, u8)> {
+        match self.zero_delimiter_position() {
+            Some(position) => self.bytes[position + 1..]
+                .chunks_exact(2)
+                .map(|duple| (duple[0], duple[1]))
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+}
+
+impl InformationElement for SupportedOperatingClasses {
+    const NAME: &'static str = "Supported Operating Classes";
+    const ID: u8 = 59;
+
+    fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    fn information_fields(&self) -> Vec<Field> {
+        let mut information_fields = vec![
+            operating_class_field("Current Operating Class", self.current_operating_class()),
+            Field::with_subfields(
+                "Operating Classes",
+                format!("{:?}", self.operating_classes()),
+                self.operating_classes()
+                    .iter()
+                    .map(|class| operating_class_field("Operating Class", *class))
+                    .collect(),
+            ),
+        ];
+
+        if let Some(extension) = self.current_operating_class_extension() {
+            information_fields.push(Field::new(
+                "Current Operating Class Extension",
+                format!("{:?}", extension),
+            ));
+        }
+
+        let duples = self.operating_class_duples();
+        if !duples.is_empty() {
+            information_fields.push(Field::new(
+                "Operating Class Duples",
+                format!("{:?}", duples),
+            ));
+        }
+
+        information_fields
+    }
+}
+
+impl_display_for_ie!(SupportedOperatingClasses);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ChannelBand, ChannelWidth, OperatingClass};
+
+    #[test]
+    fn supported_operating_classes_sequences() {
+        let supported_operating_classes = SupportedOperatingClasses::new(vec![
+            0x80, 0x51, 0x73, 0x74, 0x80, 0x82, 0x01, 0x00, 0x51, 0x01,
+        ])
+        .unwrap();
+
+        assert_eq!(supported_operating_classes.current_operating_class(), 128);
+        assert_eq!(
+            supported_operating_classes.operating_classes(),
+            &[81, 115, 116, 128]
+        );
+        assert_eq!(
+            supported_operating_classes.current_operating_class_extension(),
+            Some(&[0x01][..])
+        );
+        assert_eq!(
+            supported_operating_classes.operating_class_duples(),
+            vec![(81, 1)]
+        );
+
+        let operating_class = OperatingClass::global(128).unwrap();
+        assert_eq!(operating_class.band(), ChannelBand::FiveGhz);
+        assert_eq!(operating_class.channel_spacing(), ChannelWidth::EightyMhz);
+    }
+}
@@ -1,4 +1,4 @@
-use super::{Field, InformationElement};
+use super::{Field, IeError, InformationElement};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Tim {
@@ -6,8 +6,63 @@ pub struct Tim {
 }
 
 impl Tim {
-    pub fn new(bytes: Vec<u8>) -> Tim {
-        Tim { bytes }
+    pub const MIN_LENGTH: usize = 4;
+
+    pub fn new(bytes: Vec<u8>) -> Result<Tim, IeError> {
+        if bytes.len() >= Self::MIN_LENGTH {
+            Ok(Tim { bytes })
+        } else {
+            Err(IeError::InvalidLength {
+                ie_name: Self::NAME,
+                expected_length: Self::MIN_LENGTH,
+                actual_length: bytes.len(),
+            })
+        }
+    }
+
+    /// The number of beacons, including the current one, before the next DTIM. A count of 0 means
+    /// the current beacon is a DTIM.
+    pub fn dtim_count(&self) -> u8 {
+        self.bytes[0]
+    }
+
+    /// The number of beacon intervals between DTIMs.
+    pub fn dtim_period(&self) -> u8 {
+        self.bytes[1]
+    }
+
+    // Bitmap Control
+
+    /// Whether group addressed frames are buffered at the AP, which is only set in DTIM beacons.
+    pub fn group_addressed_traffic_buffered(&self) -> bool {
+        self.bytes[2] & 0x01 != 0
+    }
+
+    /// The index of the first octet of the traffic indication virtual bitmap that's in the
+    /// partial virtual bitmap.
+    pub fn bitmap_offset(&self) -> u8 {
+        self.bytes[2] & 0xFE
+    }
+
+    pub fn partial_virtual_bitmap(&self) -> &[u8] {
+        &self.bytes[3..]
+    }
+
+    /// The association IDs of the stations that have individually addressed frames buffered.
+    pub fn aids_with_buffered_traffic(&self) -> Vec<u16> {
+        let first_aid = u16::from(self.bitmap_offset()) * 8;
+
+        self.partial_virtual_bitmap()
+            .iter()
+            .enumerate()
+            .flat_map(|(index, octet)| {
+                (0..8)
+                    .filter(move |bit| octet & (1 << bit) != 0)
+                    .map(move |bit| first_aid + index as u16 * 8 + bit)
+            })
+            // AID 0 is the group addressed traffic indicator, not a station
+            .filter(|aid| *aid != 0)
+            .collect()
     }
 }
 
@@ -20,8 +75,51 @@ impl InformationElement for Tim {
     }
 
     fn information_fields(&self) -> Vec<Field> {
-        Vec::new()
+        let aids = self.aids_with_buffered_traffic();
+
+        vec![
+            Field::new("DTIM Count", self.dtim_count()),
+            Field::new("DTIM Period", self.dtim_period()),
+            Field::with_subfields(
+                "Bitmap Control",
+                format!("{:02?}", self.bytes[2]),
+                vec![
+                    Field::new(
+                        "Group Addressed Traffic Buffered",
+                        self.group_addressed_traffic_buffered(),
+                    ),
+                    Field::new("Bitmap Offset", self.bitmap_offset()),
+                ],
+            ),
+            Field::with_subfields(
+                "Partial Virtual Bitmap",
+                format!("{:02?}", self.partial_virtual_bitmap()),
+                vec![Field::new(
+                    "AIDs with Buffered Traffic",
+                    aids.iter()
+                        .map(|aid| aid.to_string())
+                        .collect::<Vec<String>>()
+                        .join(", "),
+                )],
+            ),
+        ]
     }
 }
 
 impl_display_for_ie!(Tim);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aids_with_buffered_traffic() {
+        let tim = Tim::new(vec![0x00, 0x03, 0x05, 0x01, 0x00, 0x82]).unwrap();
+
+        assert_eq!(tim.dtim_count(), 0);
+        assert_eq!(tim.dtim_period(), 3);
+        assert!(tim.group_addressed_traffic_buffered());
+        assert_eq!(tim.bitmap_offset(), 4);
+        assert_eq!(tim.aids_with_buffered_traffic(), vec![32, 49, 55]);
+    }
+}
@@ -3,6 +3,7 @@ use bitvec::prelude::*;
 use std::fmt::Display;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(clippy::upper_case_acronyms)]
 pub enum UnitInterpretation {
     EIRP,
     Unknown(u8),
@@ -1,5 +1,80 @@
-use super::{Field, IeError, InformationElement};
+use super::{ht_capabilities::McsFeedback, Field, IeError, InformationElement};
+use crate::{ChannelWidth, ChannelWidths};
 use bitvec::prelude::*;
+use num_enum::TryFromPrimitive;
+use std::{convert::TryFrom, fmt::Display};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
+#[repr(u8)]
+pub enum VhtMcsSupport {
+    ZeroToSeven,
+    ZeroToEight,
+    ZeroToNine,
+    NotSupported,
+}
+
+impl VhtMcsSupport {
+    pub fn max_mcs(&self) -> Option<u8> {
+        match self {
+            VhtMcsSupport::ZeroToSeven => Some(7),
+            VhtMcsSupport::ZeroToEight => Some(8),
+            VhtMcsSupport::ZeroToNine => Some(9),
+            VhtMcsSupport::NotSupported => None,
+        }
+    }
+}
+
+impl Display for VhtMcsSupport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VhtMcsSupport::ZeroToSeven => write!(f, "VHT-MCS 0-7"),
+            VhtMcsSupport::ZeroToEight => write!(f, "VHT-MCS 0-8"),
+            VhtMcsSupport::ZeroToNine => write!(f, "VHT-MCS 0-9"),
+            VhtMcsSupport::NotSupported => write!(f, "Not Supported"),
+        }
+    }
+}
+
+/// A 16-bit VHT-MCS map with the max VHT-MCS for 1 to 8 spatial streams.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VhtMcsMap {
+    map: u16,
+}
+
+impl VhtMcsMap {
+    pub fn new(map: [u8; 2]) -> VhtMcsMap {
+        VhtMcsMap {
+            map: u16::from_le_bytes(map),
+        }
+    }
+
+    pub fn mcs_support(&self, nss: u8) -> VhtMcsSupport {
+        match nss {
+            1..=8 => VhtMcsSupport::try_from(((self.map >> ((nss - 1) * 2)) & 0b11) as u8)
+                .unwrap_or(VhtMcsSupport::NotSupported),
+            _ => VhtMcsSupport::NotSupported,
+        }
+    }
+
+    pub fn max_nss(&self) -> u8 {
+        (1..=8)
+            .rev()
+            .find(|nss| self.mcs_support(*nss) != VhtMcsSupport::NotSupported)
+            .unwrap_or(0)
+    }
+
+    pub fn fields(&self) -> Vec<Field> {
+        (1..=8)
+            .map(|nss| Field::new(format!("{} SS", nss), self.mcs_support(nss)))
+            .collect()
+    }
+}
+
+impl Display for VhtMcsMap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:#06x}", self.map)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct VhtCapabilities {
@@ -22,17 +97,148 @@ impl VhtCapabilities {
             })
         }
     }
-}
 
-impl InformationElement for VhtCapabilities {
-    fn name(&self) -> &'static str {
-        VhtCapabilities::NAME
+    // VHT Capabilities Information
+
+    pub fn max_mpdu_length(&self) -> usize {
+        match self.bits[0..=1].load::<u8>() {
+            0 => 3895,
+            1 => 7991,
+            _ => 11454,
+        }
+    }
+
+    pub fn supported_channel_width_set(&self) -> ChannelWidths {
+        let channel_widths =
+            ChannelWidth::TwentyMhz | ChannelWidth::FortyMhz | ChannelWidth::EightyMhz;
+
+        match self.bits[2..=3].load::<u8>() {
+            0 => channel_widths.into(),
+            1 => (channel_widths | ChannelWidth::OneSixtyMhz).into(),
+            _ => (channel_widths | ChannelWidth::OneSixtyMhz | ChannelWidth::EightyPlusEightyMhz)
+                .into(),
+        }
+    }
+
+    pub fn rx_ldpc(&self) -> bool {
+        self.bits[4]
+    }
+
+    pub fn short_gi_eighty_mhz(&self) -> bool {
+        self.bits[5]
+    }
+
+    pub fn short_gi_one_sixty_and_eighty_plus_eighty_mhz(&self) -> bool {
+        self.bits[6]
+    }
+
+    pub fn tx_stbc(&self) -> bool {
+        self.bits[7]
+    }
+
+    /// The number of spatial streams the STA can receive STBC PPDUs with, where 0 means it can't.
+    pub fn rx_stbc(&self) -> u8 {
+        match self.bits[8..=10].load::<u8>() {
+            num @ 0..=4 => num,
+            _ => 0,
+        }
     }
 
-    fn id(&self) -> u8 {
-        VhtCapabilities::ID
+    pub fn su_beamformer_capable(&self) -> bool {
+        self.bits[11]
     }
 
+    pub fn su_beamformee_capable(&self) -> bool {
+        self.bits[12]
+    }
+
+    /// The max number of space-time streams the STA can receive in a VHT NDP as a beamformee.
+    pub fn beamformee_sts_capability(&self) -> u8 {
+        self.bits[13..=15].load::<u8>() + 1
+    }
+
+    /// The number of antennas the STA uses to transmit a VHT NDP as a beamformer.
+    pub fn number_of_sounding_dimensions(&self) -> u8 {
+        self.bits[16..=18].load::<u8>() + 1
+    }
+
+    pub fn mu_beamformer_capable(&self) -> bool {
+        self.bits[19]
+    }
+
+    pub fn mu_beamformee_capable(&self) -> bool {
+        self.bits[20]
+    }
+
+    pub fn txop_ps(&self) -> bool {
+        self.bits[21]
+    }
+
+    pub fn htc_vht_capable(&self) -> bool {
+        self.bits[22]
+    }
+
+    pub fn max_ampdu_length_exponent(&self) -> u8 {
+        self.bits[23..=25].load::<u8>()
+    }
+
+    pub fn max_ampdu_length(&self) -> u32 {
+        2u32.pow(13 + u32::from(self.max_ampdu_length_exponent())) - 1
+    }
+
+    pub fn vht_link_adaptation_capable(&self) -> McsFeedback {
+        McsFeedback::try_from(self.bits[26..=27].load::<u8>()).unwrap_or(McsFeedback::NoMfb)
+    }
+
+    pub fn rx_antenna_pattern_consistency(&self) -> bool {
+        self.bits[28]
+    }
+
+    pub fn tx_antenna_pattern_consistency(&self) -> bool {
+        self.bits[29]
+    }
+
+    /// The Extended NSS BW Support subfield, which together with the Supported Channel Width Set
+    /// subfield tells how many spatial streams the STA supports at 160 and 80+80 MHz.
+    pub fn extended_nss_bw_support(&self) -> u8 {
+        self.bits[30..=31].load::<u8>()
+    }
+
+    // Supported VHT-MCS and NSS Set
+
+    pub fn rx_vht_mcs_map(&self) -> VhtMcsMap {
+        let bytes = self.bits.as_raw_slice();
+        VhtMcsMap::new([bytes[4], bytes[5]])
+    }
+
+    /// The highest long GI data rate the STA can receive in Mb/s, where 0 means it isn't stated and
+    /// the Rx VHT-MCS map sets the limit.
+    pub fn rx_highest_supported_long_gi_data_rate_mbps(&self) -> u16 {
+        self.bits[48..=60].load_le::<u16>()
+    }
+
+    /// The max number of space-time streams the STA can receive in an MU PPDU, where 0 means it
+    /// isn't stated.
+    pub fn max_nsts_total(&self) -> u8 {
+        self.bits[61..=63].load::<u8>()
+    }
+
+    pub fn tx_vht_mcs_map(&self) -> VhtMcsMap {
+        let bytes = self.bits.as_raw_slice();
+        VhtMcsMap::new([bytes[8], bytes[9]])
+    }
+
+    /// The highest long GI data rate the STA can transmit in Mb/s, where 0 means it isn't stated
+    /// and the Tx VHT-MCS map sets the limit.
+    pub fn tx_highest_supported_long_gi_data_rate_mbps(&self) -> u16 {
+        self.bits[80..=92].load_le::<u16>()
+    }
+
+    pub fn vht_extended_nss_bw_capable(&self) -> bool {
+        self.bits[93]
+    }
+}
+
 impl InformationElement for VhtCapabilities {
     const NAME: &'static str = "VHT Capabilities";
     const ID: u8 = 191;
@@ -42,10 +248,146 @@ impl InformationElement for VhtCapabilities {
     }
 
     fn information_fields(&self) -> Vec<Field> {
-        Vec::new()
+        let rx_vht_mcs_map = self.rx_vht_mcs_map();
+        let tx_vht_mcs_map = self.tx_vht_mcs_map();
+
+        vec![
+            Field::with_subfields(
+                "VHT Capabilities Information",
+                format!("{:02?}", &self.bits.as_raw_slice()[0..=3]),
+                vec![
+                    Field::new("Maximum MPDU Length", self.max_mpdu_length()),
+                    Field::new(
+                        "Supported Channel Width Set",
+                        self.supported_channel_width_set(),
+                    ),
+                    Field::new("Rx LDPC", self.rx_ldpc()),
+                    Field::new("Short GI for 80 MHz", self.short_gi_eighty_mhz()),
+                    Field::new(
+                        "Short GI for 160 and 80+80 MHz",
+                        self.short_gi_one_sixty_and_eighty_plus_eighty_mhz(),
+                    ),
+                    Field::new("Tx STBC", self.tx_stbc()),
+                    Field::new("Rx STBC", self.rx_stbc()),
+                    Field::new("SU Beamformer Capable", self.su_beamformer_capable()),
+                    Field::new("SU Beamformee Capable", self.su_beamformee_capable()),
+                    Field::new(
+                        "Beamformee STS Capability",
+                        self.beamformee_sts_capability(),
+                    ),
+                    Field::new(
+                        "Number of Sounding Dimensions",
+                        self.number_of_sounding_dimensions(),
+                    ),
+                    Field::new("MU Beamformer Capable", self.mu_beamformer_capable()),
+                    Field::new("MU Beamformee Capable", self.mu_beamformee_capable()),
+                    Field::new("TXOP PS", self.txop_ps()),
+                    Field::new("+HTC-VHT Capable", self.htc_vht_capable()),
+                    Field::new(
+                        "Maximum A-MPDU Length Exponent",
+                        format!(
+                            "{} ({} octets)",
+                            self.max_ampdu_length_exponent(),
+                            self.max_ampdu_length()
+                        ),
+                    ),
+                    Field::new(
+                        "VHT Link Adaptation Capable",
+                        self.vht_link_adaptation_capable(),
+                    ),
+                    Field::new(
+                        "Rx Antenna Pattern Consistency",
+                        self.rx_antenna_pattern_consistency(),
+                    ),
+                    Field::new(
+                        "Tx Antenna Pattern Consistency",
+                        self.tx_antenna_pattern_consistency(),
+                    ),
+                    Field::new("Extended NSS BW Support", self.extended_nss_bw_support()),
+                ],
+            ),
+            Field::with_subfields(
+                "Supported VHT-MCS and NSS Set",
+                format!("{:02?}", &self.bits.as_raw_slice()[4..=11]),
+                vec![
+                    Field::with_subfields(
+                        "Rx VHT-MCS Map",
+                        rx_vht_mcs_map,
+                        rx_vht_mcs_map.fields(),
+                    ),
+                    Field::new(
+                        "Rx Highest Supported Long GI Data Rate",
+                        format!(
+                            "{} Mb/s",
+                            self.rx_highest_supported_long_gi_data_rate_mbps()
+                        ),
+                    ),
+                    Field::new("Maximum NSTS Total", self.max_nsts_total()),
+                    Field::with_subfields(
+                        "Tx VHT-MCS Map",
+                        tx_vht_mcs_map,
+                        tx_vht_mcs_map.fields(),
+                    ),
+                    Field::new(
+                        "Tx Highest Supported Long GI Data Rate",
+                        format!(
+                            "{} Mb/s",
+                            self.tx_highest_supported_long_gi_data_rate_mbps()
+                        ),
+                    ),
+                    Field::new(
+                        "VHT Extended NSS BW Capable",
+                        self.vht_extended_nss_bw_capable(),
+                    ),
+                ],
+            ),
+        ]
     }
 }
 
 impl_display_for_ie!(VhtCapabilities);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vht_capabilities_mcs_maps_and_beamforming() {
+        // A 2x2 160 MHz AP with MU beamforming
+        let vht_capabilities = VhtCapabilities::new(vec![
+            0xB6, 0x79, 0xC9, 0x33, 0xFA, 0xFF, 0x0C, 0x03, 0xFA, 0xFF, 0x0C, 0x23,
+        ])
+        .unwrap();
+
+        assert_eq!(vht_capabilities.max_mpdu_length(), 11454);
+        assert!(vht_capabilities
+            .supported_channel_width_set()
+            .contains(ChannelWidth::OneSixtyMhz));
+        assert!(!vht_capabilities
+            .supported_channel_width_set()
+            .contains(ChannelWidth::EightyPlusEightyMhz));
+        assert!(vht_capabilities.rx_ldpc());
+        assert!(vht_capabilities.short_gi_eighty_mhz());
+        assert_eq!(vht_capabilities.rx_stbc(), 1);
+        assert!(vht_capabilities.su_beamformer_capable());
+        assert!(vht_capabilities.su_beamformee_capable());
+        assert_eq!(vht_capabilities.beamformee_sts_capability(), 4);
+        assert_eq!(vht_capabilities.number_of_sounding_dimensions(), 2);
+        assert!(vht_capabilities.mu_beamformer_capable());
+        assert_eq!(vht_capabilities.max_ampdu_length_exponent(), 7);
+        assert_eq!(vht_capabilities.rx_vht_mcs_map().max_nss(), 2);
+        assert_eq!(
+            vht_capabilities.tx_vht_mcs_map().mcs_support(2),
+            VhtMcsSupport::ZeroToNine
+        );
+        assert_eq!(
+            vht_capabilities.rx_highest_supported_long_gi_data_rate_mbps(),
+            780
+        );
+        assert_eq!(
+            vht_capabilities.tx_highest_supported_long_gi_data_rate_mbps(),
+            780
+        );
+        assert!(vht_capabilities.vht_extended_nss_bw_capable());
     }
 }
@@ -1,4 +1,4 @@
-use super::{Field, IeError, InformationElement};
+use super::{Field, IeError, InformationElement, VhtMcsMap};
 use crate::{ChannelWidth, ChannelWidths};
 use bitvec::prelude::*;
 
@@ -24,10 +24,49 @@ impl VhtOperation {
         }
     }
 
+    // VHT Operation Information
+
+    /// The widths the Channel Width subfield allows, which the HT Operation element and the
+    /// channel center frequency segments narrow down.
+    pub fn channel_width(&self) -> ChannelWidths {
+        vht_channel_widths(self.channel_width_value())
+    }
+
+    pub fn channel_width_value(&self) -> u8 {
+        self.bits.as_raw_slice()[0]
+    }
+
+    /// The BSS channel width, or None if it's 20 or 40 MHz, which the HT Operation element tells
+    /// apart.
+    pub fn bss_channel_width(&self) -> Option<ChannelWidth> {
+        vht_channel_width(
+            self.channel_width_value(),
+            self.channel_center_freq_segment_zero(),
+            self.channel_center_freq_segment_one(),
+        )
+    }
+
+    /// Whether the Channel Width subfield uses the deprecated 160 MHz or 80+80 MHz encodings, where
+    /// segment 0 is the center of the whole 160 MHz channel or the first 80 MHz segment.
+    pub fn uses_deprecated_channel_width(&self) -> bool {
+        matches!(self.channel_width_value(), 2 | 3)
+    }
+
+    pub fn channel_center_freq_segment_zero(&self) -> u8 {
+        self.bits.as_raw_slice()[1]
     }
 
+    pub fn channel_center_freq_segment_one(&self) -> u8 {
+        self.bits.as_raw_slice()[2]
     }
 
+    // Basic VHT-MCS and NSS Set
+
+    pub fn basic_vht_mcs_and_nss_set(&self) -> VhtMcsMap {
+        let bytes = self.bits.as_raw_slice();
+        VhtMcsMap::new([bytes[3], bytes[4]])
+    }
+}
 
 impl InformationElement for VhtOperation {
     const NAME: &'static str = "VHT Operation";
@@ -38,10 +77,96 @@ impl InformationElement for VhtOperation {
     }
 
     fn information_fields(&self) -> Vec<Field> {
-        Vec::new()
+        let basic_vht_mcs_and_nss_set = self.basic_vht_mcs_and_nss_set();
+
+        vec![
+            Field::with_subfields(
+                "VHT Operation Information",
+                format!("{:02?}", &self.bits.as_raw_slice()[0..=2]),
+                vec![
+                    Field::new(
+                        "Channel Width",
+                        match self.bss_channel_width() {
+                            Some(channel_width) if self.uses_deprecated_channel_width() => {
+                                format!("{} (deprecated)", channel_width)
+                            }
+                            Some(channel_width) => channel_width.to_string(),
+                            None => "20 or 40 MHz".to_string(),
+                        },
+                    ),
+                    Field::new(
+                        "Channel Center Frequency Segment 0",
+                        self.channel_center_freq_segment_zero(),
+                    ),
+                    Field::new(
+                        "Channel Center Frequency Segment 1",
+                        self.channel_center_freq_segment_one(),
+                    ),
+                ],
+            ),
+            Field::with_subfields(
+                "Basic VHT-MCS and NSS Set",
+                basic_vht_mcs_and_nss_set,
+                basic_vht_mcs_and_nss_set.fields(),
+            ),
+        ]
     }
 }
 
 impl_display_for_ie!(VhtOperation);
+
+// The VHT Operation Information's Channel Width subfield, which is also carried by the HE Operation
+// element
+pub(super) fn vht_channel_widths(channel_width: u8) -> ChannelWidths {
+    match channel_width {
+        0 => (ChannelWidth::TwentyMhz | ChannelWidth::FortyMhz).into(),
+        1 => (ChannelWidth::EightyMhz
+            | ChannelWidth::EightyPlusEightyMhz
+            | ChannelWidth::OneSixtyMhz)
+            .into(),
+        2 => ChannelWidth::OneSixtyMhz.into(),
+        3 => ChannelWidth::EightyPlusEightyMhz.into(),
+        _ => ChannelWidth::TwentyMhz.into(),
+    }
+}
+
+// Resolves the Channel Width subfield with the channel center frequency segments, also used by the
+// Wide Bandwidth Channel Switch element
+pub(super) fn vht_channel_width(
+    channel_width: u8,
+    channel_center_freq_segment_zero: u8,
+    channel_center_freq_segment_one: u8,
+) -> Option<ChannelWidth> {
+    match channel_width {
+        1 if channel_center_freq_segment_one == 0 => Some(ChannelWidth::EightyMhz),
+        1 if channel_center_freq_segment_zero.abs_diff(channel_center_freq_segment_one) == 8 => {
+            Some(ChannelWidth::OneSixtyMhz)
+        }
+        1 => Some(ChannelWidth::EightyPlusEightyMhz),
+        2 => Some(ChannelWidth::OneSixtyMhz),
+        3 => Some(ChannelWidth::EightyPlusEightyMhz),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ies::VhtMcsSupport;
+
+    #[test]
+    fn vht_operation_160_mhz() {
+        let vht_operation = VhtOperation::new(vec![0x01, 0x2A, 0x32, 0xFA, 0xFF]).unwrap();
+
+        assert_eq!(
+            vht_operation.bss_channel_width(),
+            Some(ChannelWidth::OneSixtyMhz)
+        );
+        assert!(!vht_operation.uses_deprecated_channel_width());
+        assert_eq!(vht_operation.basic_vht_mcs_and_nss_set().max_nss(), 2);
+        assert_eq!(
+            vht_operation.basic_vht_mcs_and_nss_set().mcs_support(1),
+            VhtMcsSupport::ZeroToNine
+        );
     }
 }
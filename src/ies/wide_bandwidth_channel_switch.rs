@@ -0,0 +1,78 @@
+use super::{vht_operation::vht_channel_width, Field, IeError, InformationElement};
+use crate::ChannelWidth;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WideBandwidthChannelSwitch {
+    bytes: Vec<u8>,
+}
+
+impl WideBandwidthChannelSwitch {
+    pub const LENGTH: usize = 3;
+
+    pub fn new(bytes: Vec<u8>) -> Result<WideBandwidthChannelSwitch, IeError> {
+        if bytes.len() == Self::LENGTH {
+            Ok(WideBandwidthChannelSwitch { bytes })
+        } else {
+            Err(IeError::InvalidLength {
+                ie_name: Self::NAME,
+                expected_length: Self::LENGTH,
+                actual_length: bytes.len(),
+            })
+        }
+    }
+
+    /// The raw New Channel Width field, which is encoded like the VHT Operation element's Channel
+    /// Width field.
+    pub fn new_channel_width_value(&self) -> u8 {
+        self.bytes[0]
+    }
+
+    /// The new channel width, or None if it's 20 or 40 MHz, which the Secondary Channel Offset
+    /// element tells apart.
+    pub fn new_channel_width(&self) -> Option<ChannelWidth> {
+        vht_channel_width(
+            self.new_channel_width_value(),
+            self.new_channel_center_freq_segment_zero(),
+            self.new_channel_center_freq_segment_one(),
+        )
+    }
+
+    pub fn new_channel_center_freq_segment_zero(&self) -> u8 {
+        self.bytes[1]
+    }
+
+    pub fn new_channel_center_freq_segment_one(&self) -> u8 {
+        self.bytes[2]
+    }
+}
+
+impl InformationElement for WideBandwidthChannelSwitch {
+    const NAME: &'static str = "Wide Bandwidth Channel Switch";
+    const ID: u8 = 194;
+
+    fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    fn information_fields(&self) -> Vec<Field> {
+        vec![
+            Field::new(
+                "New Channel Width",
+                match self.new_channel_width() {
+                    Some(channel_width) => channel_width.to_string(),
+                    None => "20 or 40 MHz".to_string(),
+                },
+            ),
+            Field::new(
+                "New Channel Center Frequency Segment 0",
+                self.new_channel_center_freq_segment_zero(),
+            ),
+            Field::new(
+                "New Channel Center Frequency Segment 1",
+                self.new_channel_center_freq_segment_one(),
+            ),
+        ]
+    }
+}
+
+impl_display_for_ie!(WideBandwidthChannelSwitch);
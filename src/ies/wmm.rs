@@ -0,0 +1,292 @@
+use super::{Field, IeError, InformationElement};
+use std::fmt::Display;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WmmSubtype {
+    Information,
+    Parameter,
+    Other(u8),
+}
+
+impl From<u8> for WmmSubtype {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => WmmSubtype::Information,
+            1 => WmmSubtype::Parameter,
+            value => WmmSubtype::Other(value),
+        }
+    }
+}
+
+impl Display for WmmSubtype {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WmmSubtype::Information => write!(f, "Information Element"),
+            WmmSubtype::Parameter => write!(f, "Parameter Element"),
+            WmmSubtype::Other(value) => write!(f, "Other ({})", value),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccessCategory {
+    BestEffort,
+    Background,
+    Video,
+    Voice,
+}
+
+impl Display for AccessCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AccessCategory::BestEffort => write!(f, "Best Effort"),
+            AccessCategory::Background => write!(f, "Background"),
+            AccessCategory::Video => write!(f, "Video"),
+            AccessCategory::Voice => write!(f, "Voice"),
+        }
+    }
+}
+
+/// The EDCA parameters for one access category.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AcParameters {
+    bytes: [u8; 4],
+}
+
+impl AcParameters {
+    pub fn new(ac_parameters: [u8; 4]) -> AcParameters {
+        AcParameters {
+            bytes: ac_parameters,
+        }
+    }
+
+    pub fn aifsn(&self) -> u8 {
+        self.bytes[0] & 0x0F
+    }
+
+    /// Whether stations need admission control before using the access category.
+    pub fn acm(&self) -> bool {
+        self.bytes[0] & 0x10 != 0
+    }
+
+    pub fn access_category(&self) -> AccessCategory {
+        match (self.bytes[0] >> 5) & 0b11 {
+            0 => AccessCategory::BestEffort,
+            1 => AccessCategory::Background,
+            2 => AccessCategory::Video,
+            _ => AccessCategory::Voice,
+        }
+    }
+
+    pub fn ecw_min(&self) -> u8 {
+        self.bytes[1] & 0x0F
+    }
+
+    pub fn ecw_max(&self) -> u8 {
+        self.bytes[1] >> 4
+    }
+
+    pub fn cw_min(&self) -> u16 {
+        (1 << self.ecw_min()) - 1
+    }
+
+    pub fn cw_max(&self) -> u16 {
+        (1 << self.ecw_max()) - 1
+    }
+
+    /// The TXOP limit in μs, where 0 means a station can send one MSDU per TXOP.
+    pub fn txop_limit_us(&self) -> u32 {
+        u32::from(u16::from_le_bytes([self.bytes[2], self.bytes[3]])) * 32
+    }
+
+    pub fn fields(&self) -> Vec<Field> {
+        vec![
+            Field::new("AIFSN", self.aifsn()),
+            Field::new("ACM", self.acm()),
+            Field::new("ECWmin", self.ecw_min()),
+            Field::new("ECWmax", self.ecw_max()),
+            Field::new("CWmin", self.cw_min()),
+            Field::new("CWmax", self.cw_max()),
+            Field::new("TXOP Limit", format!("{} μs", self.txop_limit_us())),
+        ]
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Wmm {
+    bytes: Vec<u8>,
+}
+
+impl Wmm {
+    pub const OUI: [u8; 4] = [0x00, 0x50, 0xF2, 0x02];
+    pub const MIN_LENGTH: usize = 7;
+
+    const AC_PARAMETERS_OFFSET: usize = 8;
+
+    pub fn new(bytes: Vec<u8>) -> Result<Wmm, IeError> {
+        if bytes.len() >= Self::MIN_LENGTH {
+            Ok(Wmm { bytes })
+        } else {
+            Err(IeError::InvalidLength {
+                ie_name: Self::NAME,
+                expected_length: Self::MIN_LENGTH,
+                actual_length: bytes.len(),
+            })
+        }
+    }
+
+    pub fn subtype(&self) -> WmmSubtype {
+        WmmSubtype::from(self.bytes[4])
+    }
+
+    pub fn version(&self) -> u8 {
+        self.bytes[5]
+    }
+
+    // QoS Info
+
+    // Only the information and parameter elements have the QoS Info field, which the other
+    // subtypes, like TSPEC, use for something else
+    fn qos_info(&self) -> Option<u8> {
+        match self.subtype() {
+            WmmSubtype::Information | WmmSubtype::Parameter => Some(self.bytes[6]),
+            WmmSubtype::Other(_) => None,
+        }
+    }
+
+    /// The count the AP increments whenever its EDCA parameters change.
+    pub fn parameter_set_count(&self) -> Option<u8> {
+        self.qos_info().map(|qos_info| qos_info & 0x0F)
+    }
+
+    pub fn uapsd(&self) -> Option<bool> {
+        self.qos_info().map(|qos_info| qos_info & 0x80 != 0)
+    }
+
+    // AC Parameters
+
+    /// The EDCA parameters of each access category, which only the parameter element has.
+    pub fn ac_parameters(&self) -> Vec<AcParameters> {
+        if self.subtype() != WmmSubtype::Parameter {
+            return Vec::new();
+        }
+
+        self.bytes
+            .get(Self::AC_PARAMETERS_OFFSET..)
+            .unwrap_or_default()
+            .chunks_exact(4)
+            .map(|ac_parameters| {
+                AcParameters::new([
+                    ac_parameters[0],
+                    ac_parameters[1],
+                    ac_parameters[2],
+                    ac_parameters[3],
+                ])
+            })
+            .collect()
+    }
+
+    pub fn access_category_parameters(
+        &self,
+        access_category: AccessCategory,
+    ) -> Option<AcParameters> {
+        self.ac_parameters()
+            .into_iter()
+            .find(|ac_parameters| ac_parameters.access_category() == access_category)
+    }
+}
+
+impl InformationElement for Wmm {
+    const NAME: &'static str = "WMM";
+    const ID: u8 = 221;
+
+    fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    fn information_fields(&self) -> Vec<Field> {
+        let mut information_fields = vec![
+            Field::new("Subtype", self.subtype()),
+            Field::new("Version", self.version()),
+        ];
+
+        if let Some(qos_info) = self.qos_info() {
+            information_fields.push(Field::with_subfields(
+                "QoS Info",
+                format!("{:02?}", qos_info),
+                vec![
+                    Field::new("Parameter Set Count", qos_info & 0x0F),
+                    Field::new("U-APSD", qos_info & 0x80 != 0),
+                ],
+            ));
+        }
+
+        for ac_parameters in self.ac_parameters() {
+            information_fields.push(Field::with_subfields(
+                format!("{} Parameters", ac_parameters.access_category()),
+                format!("{:02?}", ac_parameters.bytes),
+                ac_parameters.fields(),
+            ));
+        }
+
+        information_fields
+    }
+}
+
+impl_display_for_ie!(Wmm);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ies::{Ie, VendorSpecific};
+
+    #[test]
+    fn wmm_parameter_element() {
+        let wmm = Wmm::new(vec![
+            0x00, 0x50, 0xF2, 0x02, 0x01, 0x01, 0x83, 0x00, 0x03, 0xA4, 0x00, 0x00, 0x27, 0xA4,
+            0x00, 0x00, 0x42, 0x43, 0x5E, 0x00, 0x72, 0x32, 0x2F, 0x00,
+        ])
+        .unwrap();
+
+        assert_eq!(wmm.subtype(), WmmSubtype::Parameter);
+        assert_eq!(wmm.parameter_set_count(), Some(3));
+        assert_eq!(wmm.uapsd(), Some(true));
+        assert_eq!(wmm.ac_parameters().len(), 4);
+
+        let voice = wmm
+            .access_category_parameters(AccessCategory::Voice)
+            .unwrap();
+        assert_eq!(voice.aifsn(), 2);
+        assert!(voice.acm());
+        assert_eq!(voice.cw_min(), 3);
+        assert_eq!(voice.cw_max(), 7);
+        assert_eq!(voice.txop_limit_us(), 1504);
+    }
+
+    #[test]
+    fn wmm_tspec_element() {
+        // A TSPEC element starts with its TS Info field where the other subtypes have QoS Info
+        let wmm = Wmm::new(vec![0x00, 0x50, 0xF2, 0x02, 0x02, 0x01, 0xE0, 0x34, 0x00]).unwrap();
+
+        assert_eq!(wmm.subtype(), WmmSubtype::Other(2));
+        assert_eq!(wmm.parameter_set_count(), None);
+        assert_eq!(wmm.uapsd(), None);
+        assert!(wmm
+            .information_fields()
+            .iter()
+            .all(|field| field.title() != "QoS Info"));
+    }
+
+    #[test]
+    fn malformed_wmm_is_kept_as_vendor_specific() {
+        let ie = Ie::new(
+            vec![0x00, 0x50, 0xF2, 0x02, 0x00, 0x01],
+            VendorSpecific::ID,
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert!(matches!(ie, Ie::VendorSpecific(_)));
+    }
+}
@@ -1,15 +1,109 @@
-use super::{Field, InformationElement};
+use super::{
+    rsn::{read_selector, read_selector_list},
+    AkmSuite, CipherSuite, Field, IeError, InformationElement,
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::Cursor;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Wpa {
     bytes: Vec<u8>,
+    version: u16,
+    multicast_cipher_suite: Option<CipherSuite>,
+    unicast_cipher_suites: Option<Vec<CipherSuite>>,
+    akm_suites: Option<Vec<AkmSuite>>,
+    truncated: bool,
 }
 
 impl Wpa {
     pub const OUI: [u8; 4] = [0x00, 0x50, 0xF2, 0x01];
+    pub const MIN_LENGTH: usize = 6;
 
-    pub fn new(bytes: Vec<u8>) -> Wpa {
-        Wpa { bytes }
+    pub fn new(bytes: Vec<u8>) -> Result<Wpa, IeError> {
+        if bytes.len() < Self::MIN_LENGTH {
+            return Err(IeError::InvalidLength {
+                ie_name: Self::NAME,
+                expected_length: Self::MIN_LENGTH,
+                actual_length: bytes.len(),
+            });
+        }
+
+        let mut wpa = Wpa {
+            bytes,
+            version: 0,
+            multicast_cipher_suite: None,
+            unicast_cipher_suites: None,
+            akm_suites: None,
+            truncated: false,
+        };
+
+        // Like the RSN element, a missing field means every field after it is missing too, and a
+        // field we can't read that still has bytes left means the element was cut short
+        let mut cursor = Cursor::new(&wpa.bytes[Self::OUI.len()..]);
+        let has_bytes_left =
+            |cursor: &Cursor<&[u8]>| cursor.position() < cursor.get_ref().len() as u64;
+        wpa.version = cursor.read_u16::<LittleEndian>().unwrap_or_default();
+        wpa.truncated = has_bytes_left(&cursor);
+        wpa.multicast_cipher_suite = read_selector(&mut cursor).map(CipherSuite::from_selector);
+        if wpa.multicast_cipher_suite.is_none() {
+            return Ok(wpa);
+        }
+        wpa.truncated = has_bytes_left(&cursor);
+        wpa.unicast_cipher_suites = read_selector_list(&mut cursor).map(|selectors| {
+            selectors
+                .into_iter()
+                .map(CipherSuite::from_selector)
+                .collect()
+        });
+        if wpa.unicast_cipher_suites.is_none() {
+            return Ok(wpa);
+        }
+        wpa.truncated = has_bytes_left(&cursor);
+        wpa.akm_suites = read_selector_list(&mut cursor)
+            .map(|selectors| selectors.into_iter().map(AkmSuite::from_selector).collect());
+        if wpa.akm_suites.is_some() {
+            wpa.truncated = false;
+        }
+
+        Ok(wpa)
+    }
+
+    pub fn version(&self) -> u16 {
+        self.version
+    }
+
+    /// Whether the element ends partway through a field. The fields from there on are unknown
+    /// rather than absent, so their accessors return None instead of the defaults.
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    // The field, the default if the field is absent, or None if the element was cut short before
+    // the field
+    fn field_or_default<T>(&self, field: &Option<T>, default: impl FnOnce() -> T) -> Option<T>
+    where
+        T: Clone,
+    {
+        match field {
+            Some(field) => Some(field.clone()),
+            None if self.truncated => None,
+            None => Some(default()),
+        }
+    }
+
+    /// The multicast cipher suite, which is TKIP if the field is absent.
+    pub fn multicast_cipher_suite(&self) -> Option<CipherSuite> {
+        self.field_or_default(&self.multicast_cipher_suite, || CipherSuite::Tkip)
+    }
+
+    /// The unicast cipher suites, which is a list containing TKIP if the field is absent.
+    pub fn unicast_cipher_suites(&self) -> Option<Vec<CipherSuite>> {
+        self.field_or_default(&self.unicast_cipher_suites, || vec![CipherSuite::Tkip])
+    }
+
+    /// The AKM suites, which is a list containing IEEE 802.1X if the field is absent.
+    pub fn akm_suites(&self) -> Option<Vec<AkmSuite>> {
+        self.field_or_default(&self.akm_suites, || vec![AkmSuite::Ieee8021X])
     }
 }
 
@@ -22,8 +116,99 @@ impl InformationElement for Wpa {
     }
 
     fn information_fields(&self) -> Vec<Field> {
-        Vec::new()
+        let suite_list_field =
+            |title: &str, subtitle: &str, suites: Option<Vec<String>>| match suites {
+                Some(suites) => Field::with_subfields(
+                    title,
+                    suites.len(),
+                    suites
+                        .iter()
+                        .map(|suite| Field::new(subtitle, suite))
+                        .collect(),
+                ),
+                None => Field::new(title, "Unknown (Truncated)"),
+            };
+
+        vec![
+            Field::new("Version", self.version()),
+            Field::new(
+                "Multicast Cipher Suite",
+                self.multicast_cipher_suite()
+                    .map(|suite| suite.to_string())
+                    .unwrap_or_else(|| String::from("Unknown (Truncated)")),
+            ),
+            suite_list_field(
+                "Unicast Cipher Suites",
+                "Unicast Cipher Suite",
+                self.unicast_cipher_suites()
+                    .map(|suites| suites.iter().map(|suite| suite.to_string()).collect()),
+            ),
+            suite_list_field(
+                "AKM Suites",
+                "AKM Suite",
+                self.akm_suites()
+                    .map(|suites| suites.iter().map(|suite| suite.to_string()).collect()),
+            ),
+        ]
     }
 }
 
 impl_display_for_ie!(Wpa);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ies::{Ie, VendorSpecific};
+
+    #[test]
+    fn wpa_tkip_psk() {
+        let wpa = Wpa::new(vec![
+            0x00, 0x50, 0xF2, 0x01, 0x01, 0x00, 0x00, 0x50, 0xF2, 0x02, 0x02, 0x00, 0x00, 0x50,
+            0xF2, 0x02, 0x00, 0x50, 0xF2, 0x04, 0x01, 0x00, 0x00, 0x50, 0xF2, 0x02,
+        ])
+        .unwrap();
+
+        assert_eq!(wpa.version(), 1);
+        assert!(!wpa.is_truncated());
+        assert_eq!(wpa.multicast_cipher_suite(), Some(CipherSuite::Tkip));
+        assert_eq!(
+            wpa.unicast_cipher_suites(),
+            Some(vec![CipherSuite::Tkip, CipherSuite::Ccmp128])
+        );
+        assert_eq!(wpa.akm_suites(), Some(vec![AkmSuite::Psk]));
+    }
+
+    #[test]
+    fn truncated_akm_suite_list() {
+        // The AKM suite count says there's one suite but the element ends partway through it
+        let wpa = Wpa::new(vec![
+            0x00, 0x50, 0xF2, 0x01, 0x01, 0x00, 0x00, 0x50, 0xF2, 0x02, 0x01, 0x00, 0x00, 0x50,
+            0xF2, 0x02, 0x01, 0x00, 0x00, 0x50,
+        ])
+        .unwrap();
+
+        assert!(wpa.is_truncated());
+        assert_eq!(wpa.unicast_cipher_suites(), Some(vec![CipherSuite::Tkip]));
+        assert_eq!(wpa.akm_suites(), None);
+
+        // The fields after the version are absent rather than truncated, so they take their
+        // default values
+        let wpa = Wpa::new(vec![0x00, 0x50, 0xF2, 0x01, 0x01, 0x00]).unwrap();
+        assert!(!wpa.is_truncated());
+        assert_eq!(wpa.akm_suites(), Some(vec![AkmSuite::Ieee8021X]));
+    }
+
+    #[test]
+    fn malformed_wpa_is_kept_as_vendor_specific() {
+        // The element ends before the version field
+        let ie = Ie::new(
+            vec![0x00, 0x50, 0xF2, 0x01, 0x01],
+            VendorSpecific::ID,
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert!(matches!(ie, Ie::VendorSpecific(_)));
+    }
+}
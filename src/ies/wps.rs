@@ -0,0 +1,592 @@
+use super::{Field, IeError, InformationElement};
+use byteorder::{BigEndian, ReadBytesExt};
+use enumflags2::{bitflags, BitFlags};
+use std::{
+    fmt::Display,
+    io::{Cursor, Read},
+};
+
+// WPS attribute types
+const VERSION: u16 = 0x104A;
+const WPS_STATE: u16 = 0x1044;
+const AP_SETUP_LOCKED: u16 = 0x1057;
+const SELECTED_REGISTRAR: u16 = 0x1041;
+const DEVICE_PASSWORD_ID: u16 = 0x1012;
+const SELECTED_REGISTRAR_CONFIG_METHODS: u16 = 0x1053;
+const RESPONSE_TYPE: u16 = 0x103B;
+const UUID_E: u16 = 0x1047;
+const MANUFACTURER: u16 = 0x1021;
+const MODEL_NAME: u16 = 0x1023;
+const MODEL_NUMBER: u16 = 0x1024;
+const SERIAL_NUMBER: u16 = 0x1042;
+const PRIMARY_DEVICE_TYPE: u16 = 0x1054;
+const DEVICE_NAME: u16 = 0x1011;
+const CONFIG_METHODS: u16 = 0x1008;
+const RF_BANDS: u16 = 0x103C;
+const VENDOR_EXTENSION: u16 = 0x1049;
+
+// The Wi-Fi Alliance's vendor ID in a Vendor Extension attribute and the ID of its Version2
+// subelement
+const WFA_VENDOR_ID: [u8; 3] = [0x00, 0x37, 0x2A];
+const VERSION2_ID: u8 = 0x00;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WpsState {
+    NotConfigured,
+    Configured,
+    Reserved(u8),
+}
+
+impl From<u8> for WpsState {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => WpsState::NotConfigured,
+            2 => WpsState::Configured,
+            value => WpsState::Reserved(value),
+        }
+    }
+}
+
+impl Display for WpsState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WpsState::NotConfigured => write!(f, "Not Configured"),
+            WpsState::Configured => write!(f, "Configured"),
+            WpsState::Reserved(value) => write!(f, "Reserved ({})", value),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DevicePasswordId {
+    Pin,
+    UserSpecified,
+    MachineSpecified,
+    Rekey,
+    PushButton,
+    RegistrarSpecified,
+    NfcConnectionHandover,
+    Reserved(u16),
+}
+
+impl From<u16> for DevicePasswordId {
+    fn from(value: u16) -> Self {
+        match value {
+            0 => DevicePasswordId::Pin,
+            1 => DevicePasswordId::UserSpecified,
+            2 => DevicePasswordId::MachineSpecified,
+            3 => DevicePasswordId::Rekey,
+            4 => DevicePasswordId::PushButton,
+            5 => DevicePasswordId::RegistrarSpecified,
+            7 => DevicePasswordId::NfcConnectionHandover,
+            value => DevicePasswordId::Reserved(value),
+        }
+    }
+}
+
+impl Display for DevicePasswordId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DevicePasswordId::Pin => write!(f, "Default (PIN)"),
+            DevicePasswordId::UserSpecified => write!(f, "User-Specified"),
+            DevicePasswordId::MachineSpecified => write!(f, "Machine-Specified"),
+            DevicePasswordId::Rekey => write!(f, "Rekey"),
+            DevicePasswordId::PushButton => write!(f, "Push Button"),
+            DevicePasswordId::RegistrarSpecified => write!(f, "Registrar-Specified"),
+            DevicePasswordId::NfcConnectionHandover => write!(f, "NFC Connection Handover"),
+            DevicePasswordId::Reserved(value) => write!(f, "Reserved ({:#06x})", value),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WpsResponseType {
+    EnrolleeInfoOnly,
+    Enrollee,
+    Registrar,
+    AccessPoint,
+    Reserved(u8),
+}
+
+impl From<u8> for WpsResponseType {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => WpsResponseType::EnrolleeInfoOnly,
+            1 => WpsResponseType::Enrollee,
+            2 => WpsResponseType::Registrar,
+            3 => WpsResponseType::AccessPoint,
+            value => WpsResponseType::Reserved(value),
+        }
+    }
+}
+
+impl Display for WpsResponseType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WpsResponseType::EnrolleeInfoOnly => write!(f, "Enrollee, Info Only"),
+            WpsResponseType::Enrollee => write!(f, "Enrollee, Open 802.1X"),
+            WpsResponseType::Registrar => write!(f, "Registrar"),
+            WpsResponseType::AccessPoint => write!(f, "AP"),
+            WpsResponseType::Reserved(value) => write!(f, "Reserved ({})", value),
+        }
+    }
+}
+
+#[bitflags]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u16)]
+pub enum ConfigMethod {
+    Usba = 0x0001,
+    Ethernet = 0x0002,
+    Label = 0x0004,
+    Display = 0x0008,
+    ExternalNfcToken = 0x0010,
+    IntegratedNfcToken = 0x0020,
+    NfcInterface = 0x0040,
+    PushButton = 0x0080,
+    Keypad = 0x0100,
+    VirtualPushButton = 0x0200,
+    PhysicalPushButton = 0x0400,
+    VirtualDisplayPin = 0x2000,
+    PhysicalDisplayPin = 0x4000,
+}
+
+impl Display for ConfigMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigMethod::Usba => write!(f, "USBA"),
+            ConfigMethod::Ethernet => write!(f, "Ethernet"),
+            ConfigMethod::Label => write!(f, "Label"),
+            ConfigMethod::Display => write!(f, "Display"),
+            ConfigMethod::ExternalNfcToken => write!(f, "External NFC Token"),
+            ConfigMethod::IntegratedNfcToken => write!(f, "Integrated NFC Token"),
+            ConfigMethod::NfcInterface => write!(f, "NFC Interface"),
+            ConfigMethod::PushButton => write!(f, "Push Button"),
+            ConfigMethod::Keypad => write!(f, "Keypad"),
+            ConfigMethod::VirtualPushButton => write!(f, "Virtual Push Button"),
+            ConfigMethod::PhysicalPushButton => write!(f, "Physical Push Button"),
+            ConfigMethod::VirtualDisplayPin => write!(f, "Virtual Display PIN"),
+            ConfigMethod::PhysicalDisplayPin => write!(f, "Physical Display PIN"),
+        }
+    }
+}
+
+pub(super) fn config_methods_to_string(config_methods: BitFlags<ConfigMethod>) -> String {
+    config_methods
+        .iter()
+        .map(|config_method| config_method.to_string())
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeviceType {
+    category: u16,
+    oui: [u8; 4],
+    subcategory: u16,
+}
+
+impl DeviceType {
+    pub fn new(device_type: [u8; 8]) -> DeviceType {
+        DeviceType {
+            category: u16::from_be_bytes([device_type[0], device_type[1]]),
+            oui: [
+                device_type[2],
+                device_type[3],
+                device_type[4],
+                device_type[5],
+            ],
+            subcategory: u16::from_be_bytes([device_type[6], device_type[7]]),
+        }
+    }
+
+    pub fn category(&self) -> u16 {
+        self.category
+    }
+
+    pub fn oui(&self) -> [u8; 4] {
+        self.oui
+    }
+
+    pub fn subcategory(&self) -> u16 {
+        self.subcategory
+    }
+
+    pub fn category_name(&self) -> Option<&'static str> {
+        // Categories are only defined for the Wi-Fi Alliance's OUI
+        if self.oui != [0x00, 0x50, 0xF2, 0x04] {
+            return None;
+        }
+
+        match self.category {
+            1 => Some("Computer"),
+            2 => Some("Input Device"),
+            3 => Some("Printer, Scanner, Fax or Copier"),
+            4 => Some("Camera"),
+            5 => Some("Storage"),
+            6 => Some("Network Infrastructure"),
+            7 => Some("Display"),
+            8 => Some("Multimedia Device"),
+            9 => Some("Gaming Device"),
+            10 => Some("Telephone"),
+            11 => Some("Audio Device"),
+            12 => Some("Docking Device"),
+            _ => None,
+        }
+    }
+}
+
+impl Display for DeviceType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.category_name() {
+            Some(category_name) => write!(
+                f,
+                "{} (Category {}, Subcategory {})",
+                category_name, self.category, self.subcategory
+            ),
+            None => write!(
+                f,
+                "Category {}, OUI {:02X?}, Subcategory {}",
+                self.category, self.oui, self.subcategory
+            ),
+        }
+    }
+}
+
+// Reads WPS attributes, which use big-endian 2-octet types and lengths
+fn read_attributes(bytes: &[u8]) -> Vec<(u16, Vec<u8>)> {
+    let mut cursor = Cursor::new(bytes);
+    let mut attributes = Vec::new();
+
+    while let (Ok(attribute_type), Ok(attribute_length)) = (
+        cursor.read_u16::<BigEndian>(),
+        cursor.read_u16::<BigEndian>(),
+    ) {
+        let mut data = vec![0; attribute_length as usize];
+        if cursor.read_exact(&mut data).is_err() {
+            break;
+        }
+        attributes.push((attribute_type, data));
+    }
+
+    attributes
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Wps {
+    bytes: Vec<u8>,
+    attributes: Vec<(u16, Vec<u8>)>,
+}
+
+impl Wps {
+    pub const OUI: [u8; 4] = [0x00, 0x50, 0xF2, 0x04];
+    pub const MIN_LENGTH: usize = 4;
+
+    pub fn new(bytes: Vec<u8>) -> Result<Wps, IeError> {
+        if bytes.len() < Self::MIN_LENGTH {
+            return Err(IeError::InvalidLength {
+                ie_name: Self::NAME,
+                expected_length: Self::MIN_LENGTH,
+                actual_length: bytes.len(),
+            });
+        }
+
+        let attributes = read_attributes(&bytes[Self::OUI.len()..]);
+        Ok(Wps { bytes, attributes })
+    }
+
+    /// Joins the WPS elements of a frame into one. An attribute can be split across elements, so
+    /// their data is joined before the attributes are read.
+    pub fn join<'a>(elements: impl IntoIterator<Item = &'a Wps>) -> Option<Wps> {
+        let mut elements = elements.into_iter();
+        let mut bytes = elements.next()?.bytes.clone();
+        for element in elements {
+            bytes.extend_from_slice(&element.bytes[Self::OUI.len()..]);
+        }
+
+        Wps::new(bytes).ok()
+    }
+
+    fn attribute(&self, attribute_type: u16) -> Option<&[u8]> {
+        self.attributes
+            .iter()
+            .find(|(this_type, _)| *this_type == attribute_type)
+            .map(|(_, data)| data.as_slice())
+    }
+
+    fn u8_attribute(&self, attribute_type: u16) -> Option<u8> {
+        self.attribute(attribute_type)
+            .and_then(|data| data.first())
+            .copied()
+    }
+
+    fn u16_attribute(&self, attribute_type: u16) -> Option<u16> {
+        self.attribute(attribute_type)
+            .filter(|data| data.len() >= 2)
+            .map(|data| u16::from_be_bytes([data[0], data[1]]))
+    }
+
+    fn string_attribute(&self, attribute_type: u16) -> Option<String> {
+        self.attribute(attribute_type).map(|data| {
+            String::from_utf8_lossy(data)
+                .trim_end_matches(char::from(0))
+                .to_string()
+        })
+    }
+
+    /// The version, which is always 0x10 and superseded by the Version2 subelement.
+    pub fn version(&self) -> Option<u8> {
+        self.u8_attribute(VERSION)
+    }
+
+    /// The version from the Wi-Fi Alliance vendor extension, which is 0x20 for WPS 2.0.
+    pub fn version2(&self) -> Option<u8> {
+        self.attributes
+            .iter()
+            .filter(|(attribute_type, data)| {
+                *attribute_type == VENDOR_EXTENSION && data.starts_with(&WFA_VENDOR_ID)
+            })
+            .find_map(|(_, data)| {
+                let subelements = &data[WFA_VENDOR_ID.len()..];
+                let mut offset = 0;
+                while let (Some(id), Some(length)) =
+                    (subelements.get(offset), subelements.get(offset + 1))
+                {
+                    if *id == VERSION2_ID && *length >= 1 {
+                        return subelements.get(offset + 2).copied();
+                    }
+                    offset += 2 + *length as usize;
+                }
+                None
+            })
+    }
+
+    pub fn wps_state(&self) -> Option<WpsState> {
+        self.u8_attribute(WPS_STATE).map(WpsState::from)
+    }
+
+    pub fn ap_setup_locked(&self) -> Option<bool> {
+        self.u8_attribute(AP_SETUP_LOCKED).map(|value| value != 0)
+    }
+
+    pub fn selected_registrar(&self) -> Option<bool> {
+        self.u8_attribute(SELECTED_REGISTRAR)
+            .map(|value| value != 0)
+    }
+
+    pub fn device_password_id(&self) -> Option<DevicePasswordId> {
+        self.u16_attribute(DEVICE_PASSWORD_ID)
+            .map(DevicePasswordId::from)
+    }
+
+    pub fn selected_registrar_config_methods(&self) -> Option<BitFlags<ConfigMethod>> {
+        self.u16_attribute(SELECTED_REGISTRAR_CONFIG_METHODS)
+            .map(BitFlags::from_bits_truncate)
+    }
+
+    pub fn response_type(&self) -> Option<WpsResponseType> {
+        self.u8_attribute(RESPONSE_TYPE).map(WpsResponseType::from)
+    }
+
+    pub fn uuid_e(&self) -> Option<[u8; 16]> {
+        self.attribute(UUID_E)
+            .filter(|data| data.len() == 16)
+            .map(|data| {
+                let mut uuid_e = [0; 16];
+                uuid_e.copy_from_slice(data);
+                uuid_e
+            })
+    }
+
+    pub fn manufacturer(&self) -> Option<String> {
+        self.string_attribute(MANUFACTURER)
+    }
+
+    pub fn model_name(&self) -> Option<String> {
+        self.string_attribute(MODEL_NAME)
+    }
+
+    pub fn model_number(&self) -> Option<String> {
+        self.string_attribute(MODEL_NUMBER)
+    }
+
+    pub fn serial_number(&self) -> Option<String> {
+        self.string_attribute(SERIAL_NUMBER)
+    }
+
+    pub fn primary_device_type(&self) -> Option<DeviceType> {
+        self.attribute(PRIMARY_DEVICE_TYPE)
+            .filter(|data| data.len() == 8)
+            .map(|data| {
+                DeviceType::new([
+                    data[0], data[1], data[2], data[3], data[4], data[5], data[6], data[7],
+                ])
+            })
+    }
+
+    pub fn device_name(&self) -> Option<String> {
+        self.string_attribute(DEVICE_NAME)
+    }
+
+    pub fn config_methods(&self) -> Option<BitFlags<ConfigMethod>> {
+        self.u16_attribute(CONFIG_METHODS)
+            .map(BitFlags::from_bits_truncate)
+    }
+
+    /// The RF bands bitmap, where bit 0 is 2.4 GHz, bit 1 is 5 GHz and bit 2 is 60 GHz.
+    pub fn rf_bands(&self) -> Option<u8> {
+        self.u8_attribute(RF_BANDS)
+    }
+}
+
+impl InformationElement for Wps {
+    const NAME: &'static str = "WPS";
+    const ID: u8 = 221;
+
+    fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    fn information_fields(&self) -> Vec<Field> {
+        let mut information_fields = Vec::new();
+
+        if let Some(version) = self.version() {
+            information_fields.push(Field::new("Version", format!("{:#04x}", version)));
+        }
+        if let Some(version2) = self.version2() {
+            information_fields.push(Field::new("Version2", format!("{:#04x}", version2)));
+        }
+        if let Some(wps_state) = self.wps_state() {
+            information_fields.push(Field::new("Wi-Fi Protected Setup State", wps_state));
+        }
+        if let Some(ap_setup_locked) = self.ap_setup_locked() {
+            information_fields.push(Field::new("AP Setup Locked", ap_setup_locked));
+        }
+        if let Some(selected_registrar) = self.selected_registrar() {
+            information_fields.push(Field::new("Selected Registrar", selected_registrar));
+        }
+        if let Some(device_password_id) = self.device_password_id() {
+            information_fields.push(Field::new("Device Password ID", device_password_id));
+        }
+        if let Some(config_methods) = self.selected_registrar_config_methods() {
+            information_fields.push(Field::new(
+                "Selected Registrar Config Methods",
+                config_methods_to_string(config_methods),
+            ));
+        }
+        if let Some(response_type) = self.response_type() {
+            information_fields.push(Field::new("Response Type", response_type));
+        }
+        if let Some(uuid_e) = self.uuid_e() {
+            information_fields.push(Field::new(
+                "UUID-E",
+                uuid_e
+                    .iter()
+                    .map(|byte| format!("{:02x}", byte))
+                    .collect::<String>(),
+            ));
+        }
+        if let Some(manufacturer) = self.manufacturer() {
+            information_fields.push(Field::new("Manufacturer", manufacturer));
+        }
+        if let Some(model_name) = self.model_name() {
+            information_fields.push(Field::new("Model Name", model_name));
+        }
+        if let Some(model_number) = self.model_number() {
+            information_fields.push(Field::new("Model Number", model_number));
+        }
+        if let Some(serial_number) = self.serial_number() {
+            information_fields.push(Field::new("Serial Number", serial_number));
+        }
+        if let Some(primary_device_type) = self.primary_device_type() {
+            information_fields.push(Field::new("Primary Device Type", primary_device_type));
+        }
+        if let Some(device_name) = self.device_name() {
+            information_fields.push(Field::new("Device Name", device_name));
+        }
+        if let Some(config_methods) = self.config_methods() {
+            information_fields.push(Field::new(
+                "Config Methods",
+                config_methods_to_string(config_methods),
+            ));
+        }
+        if let Some(rf_bands) = self.rf_bands() {
+            let bands: Vec<&str> = [(0x01, "2.4 GHz"), (0x02, "5 GHz"), (0x04, "60 GHz")]
+                .iter()
+                .filter(|(bit, _)| rf_bands & bit != 0)
+                .map(|(_, band)| *band)
+                .collect();
+            information_fields.push(Field::new("RF Bands", bands.join(", ")));
+        }
+
+        information_fields
+    }
+}
+
+impl_display_for_ie!(Wps);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wps_attributes() {
+        let mut bytes = Wps::OUI.to_vec();
+        bytes.extend([0x10, 0x4A, 0x00, 0x01, 0x10]);
+        bytes.extend([0x10, 0x44, 0x00, 0x01, 0x02]);
+        bytes.extend([0x10, 0x57, 0x00, 0x01, 0x01]);
+        bytes.extend([0x10, 0x21, 0x00, 0x07]);
+        bytes.extend(b"Netgear");
+        bytes.extend([0x10, 0x23, 0x00, 0x06]);
+        bytes.extend(b"RAX120");
+        bytes.extend([0x10, 0x11, 0x00, 0x08]);
+        bytes.extend(b"Router\0\0");
+        bytes.extend([0x10, 0x08, 0x00, 0x02, 0x22, 0x88]);
+        bytes.extend([
+            0x10, 0x54, 0x00, 0x08, 0x00, 0x06, 0x00, 0x50, 0xF2, 0x04, 0x00, 0x01,
+        ]);
+        bytes.extend([
+            0x10, 0x49, 0x00, 0x09, 0x00, 0x37, 0x2A, 0x01, 0x01, 0xFF, 0x00, 0x01,
+        ]);
+        bytes.push(0x20);
+
+        let wps = Wps::new(bytes).unwrap();
+        assert_eq!(wps.version(), Some(0x10));
+        assert_eq!(wps.version2(), Some(0x20));
+        assert_eq!(wps.wps_state(), Some(WpsState::Configured));
+        assert_eq!(wps.ap_setup_locked(), Some(true));
+        assert_eq!(wps.manufacturer().as_deref(), Some("Netgear"));
+        assert_eq!(wps.model_name().as_deref(), Some("RAX120"));
+        assert_eq!(wps.device_name().as_deref(), Some("Router"));
+        assert_eq!(
+            wps.config_methods(),
+            Some(
+                ConfigMethod::Display
+                    | ConfigMethod::PushButton
+                    | ConfigMethod::VirtualPushButton
+                    | ConfigMethod::VirtualDisplayPin
+            )
+        );
+        assert_eq!(
+            wps.primary_device_type().unwrap().category_name(),
+            Some("Network Infrastructure")
+        );
+    }
+
+    #[test]
+    fn wps_attribute_split_across_elements() {
+        let mut first = Wps::OUI.to_vec();
+        first.extend([0x10, 0x4A, 0x00, 0x01, 0x10]);
+        first.extend([0x10, 0x21, 0x00, 0x07]);
+        first.extend(b"Net");
+        let mut second = Wps::OUI.to_vec();
+        second.extend(b"gear");
+        let elements = [Wps::new(first).unwrap(), Wps::new(second).unwrap()];
+
+        assert_eq!(elements[0].manufacturer(), None);
+
+        let wps = Wps::join(&elements).unwrap();
+        assert_eq!(wps.version(), Some(0x10));
+        assert_eq!(wps.manufacturer().as_deref(), Some("Netgear"));
+    }
+}
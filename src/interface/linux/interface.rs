@@ -1,5 +1,5 @@
 use super::{InterfaceType, Nl80211Attr, Nl80211Cmd};
-use crate::{bss::Bss, interface::ScanError};
+use crate::{bss::Bss, interface::ScanError, ChannelSurvey};
 use macaddr::MacAddr6;
 use neli::{
     attr::Attribute,
@@ -43,6 +43,10 @@ impl Interface {
         self.wiphy
     }
 
+    pub fn wdev(&self) -> u64 {
+        self.wdev
+    }
+
     pub fn mac_address(&self) -> MacAddr6 {
         self.mac_address
     }
@@ -130,6 +134,46 @@ impl Interface {
             })
             .collect::<HashSet<_>>())
     }
+
+    /// Gets the channel survey results the driver has collected, which usually cover the channels
+    /// visited by the latest scan.
+    pub fn survey(&self) -> Result<Vec<ChannelSurvey>, ScanError> {
+        // Create a generic netlink message header containing the GetSurvey command
+        let genl_msghdr = {
+            let attr = Nlattr::new(None, false, true, Nl80211Attr::Ifindex, self.index);
+            Genlmsghdr::new(Nl80211Cmd::GetSurvey, 1, attr.into_iter().collect())
+        };
+
+        let mut socket = NlSocketHandle::connect(NlFamily::Generic, None, &[])?;
+
+        // Create a netlink message header with the generic netlink message header as its payload
+        let nl_msghdr = {
+            let id = socket.resolve_genl_family(super::NL80211_FAMILY_NAME)?;
+            let flags = NlmFFlags::new(&[NlmF::Request, NlmF::Dump]);
+            let payload = NlPayload::Payload(genl_msghdr);
+            Nlmsghdr::new(None, id, flags, None, None, payload)
+        };
+
+        socket.send(nl_msghdr)?;
+
+        // Receive all responses and attempt to convert each one of them to a ChannelSurvey
+        Ok(socket
+            .recv_all::<Nlmsg, Genlmsghdr<Nl80211Cmd, Nl80211Attr>>()?
+            .iter()
+            .filter_map(|nl_msghdr| nl_msghdr.get_payload().ok())
+            .filter(|payload| payload.cmd == Nl80211Cmd::NewSurveyResults)
+            .map(|payload| payload.get_attr_handle())
+            .filter_map(|mut attr_handle| {
+                if let Ok(survey_attr_handle) =
+                    attr_handle.get_nested_attributes(Nl80211Attr::SurveyInfo)
+                {
+                    ChannelSurvey::try_from(survey_attr_handle.get_attrs()).ok()
+                } else {
+                    None
+                }
+            })
+            .collect())
+    }
 }
 
 impl Hash for Interface {
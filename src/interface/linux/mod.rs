@@ -2,12 +2,14 @@ mod interface;
 mod interface_type;
 mod nl80211_attr;
 mod nl80211_cmd;
+mod nl80211_survey_info;
 mod scan_error;
 
 pub use interface::Interface;
 pub use interface_type::InterfaceType;
 use nl80211_attr::Nl80211Attr;
 use nl80211_cmd::Nl80211Cmd;
+pub(crate) use nl80211_survey_info::Nl80211SurveyInfo;
 pub use scan_error::ScanError;
 
 use neli::{
@@ -54,7 +56,7 @@ pub fn interfaces() -> HashSet<Interface> {
 
     // Send the netlink message header using the socket
     // If sending the message header fails, return an empty HashSet
-    if let Err(_) = socket.send(nl_msghdr) {
+    if socket.send(nl_msghdr).is_err() {
         return HashSet::new();
     }
 
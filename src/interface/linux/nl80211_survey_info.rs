@@ -0,0 +1,20 @@
+neli::impl_var!(
+    /// Netlink attributes for a channel survey.
+    /// Based on nl80211_survey_info from linux/include/uapi/linux/nl80211.h
+    pub(crate) Nl80211SurveyInfo, u16,
+    Invalid         => 0,
+    Frequency       => 1,
+    Noise           => 2,
+    InUse           => 3,
+    Time            => 4,
+    TimeBusy        => 5,
+    TimeExtBusy     => 6,
+    TimeRx          => 7,
+    TimeTx          => 8,
+    TimeScan        => 9,
+    Pad             => 10,
+    TimeBssRx       => 11,
+    FrequencyOffset => 12
+);
+
+impl neli::consts::genl::NlAttrType for Nl80211SurveyInfo {}
@@ -2,6 +2,7 @@ cfg_if::cfg_if! {
     if #[cfg(target_os = "linux")] {
         mod linux;
         pub use linux::{default_interface, interfaces, Interface, ScanError};
+        pub(crate) use linux::Nl80211SurveyInfo;
     } else if #[cfg(target_os = "macos")] {
         mod macos;
         pub use macos::{default_interface, interfaces, Interface};
@@ -1,13 +1,21 @@
 mod bss;
 mod channel;
 mod field;
-mod ies;
+pub mod ies;
 mod interface;
 mod security_protocol;
 mod wifi_protocol;
 
-pub use bss::{Bss, CapabilityInfo};
-pub use channel::{Channel, ChannelBand, ChannelNumber, ChannelWidth, ChannelWidths};
+pub use bss::{
+    ApMld, Bss, CapabilityInfo, ChannelPlanPolicy, ChannelPlanner, ChannelRecommendation,
+    ChannelSwitch, CoexistenceConflict, CoexistenceTrigger, GuardInterval, MobilityDomainGroup,
+    PhyRate, ScoreFactor, ScoreFactorKind, SixGhzNeighbor, TwentyFortyCoexistence,
+};
+pub use channel::{
+    BehaviorLimit, BehaviorLimits, Channel, ChannelBand, ChannelNumber, ChannelOccupancy,
+    ChannelOverlap, ChannelRelation, ChannelSegment, ChannelSurvey, ChannelWidth, ChannelWidths,
+    OperatingClass, Region, SecondaryChannelPosition,
+};
 pub use field::Field;
 pub use ies::{Ie, InformationElement};
 pub use interface::{default_interface, interfaces, Interface};
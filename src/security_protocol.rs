@@ -1,18 +1,69 @@
-use crate::Ie;
+use crate::{
+    ies::{AkmSuite, InformationElement},
+    CapabilityInfo, Ie,
+};
 use derive_more::{
     BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Deref, DerefMut, From, Not,
 };
 use enumflags2::{bitflags, BitFlags};
-use std::convert::From;
+use std::fmt::Display;
+
+// The OUI and type of the Wi-Fi Alliance's OWE Transition Mode element, which an open BSS uses to
+// point stations at the OWE BSS it's paired with
+const OWE_TRANSITION_MODE_OUI: [u8; 4] = [0x50, 0x6F, 0x9A, 0x1C];
 
 #[bitflags]
 #[derive(Copy, Clone, Debug, PartialOrd, Ord, PartialEq, Eq)]
-#[repr(u8)]
+#[repr(u16)]
 pub enum SecurityProtocol {
-    WEP = 1 << 0,
-    WPA = 1 << 1,
-    WPA2 = 1 << 2,
-    WPA3 = 1 << 3,
+    Open = 1 << 0,
+    WEP = 1 << 1,
+    WPAPersonal = 1 << 2,
+    WPAEnterprise = 1 << 3,
+    WPA2Personal = 1 << 4,
+    WPA2Enterprise = 1 << 5,
+    WPA3Personal = 1 << 6,
+    WPA3Enterprise = 1 << 7,
+    WPA3Enterprise192 = 1 << 8,
+    OWE = 1 << 9,
+}
+
+impl SecurityProtocol {
+    pub fn is_personal(&self) -> bool {
+        matches!(
+            self,
+            SecurityProtocol::WPAPersonal
+                | SecurityProtocol::WPA2Personal
+                | SecurityProtocol::WPA3Personal
+        )
+    }
+
+    pub fn is_enterprise(&self) -> bool {
+        matches!(
+            self,
+            SecurityProtocol::WPAEnterprise
+                | SecurityProtocol::WPA2Enterprise
+                | SecurityProtocol::WPA3Enterprise
+                | SecurityProtocol::WPA3Enterprise192
+        )
+    }
+}
+
+impl Display for SecurityProtocol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SecurityProtocol::Open => write!(f, "Open"),
+            SecurityProtocol::WEP => write!(f, "WEP"),
+            SecurityProtocol::WPAPersonal => write!(f, "WPA-Personal"),
+            SecurityProtocol::WPAEnterprise => write!(f, "WPA-Enterprise"),
+            SecurityProtocol::WPA2Personal => write!(f, "WPA2-Personal"),
+            SecurityProtocol::WPA2Enterprise => write!(f, "WPA2-Enterprise"),
+            SecurityProtocol::WPA3Personal => write!(f, "WPA3-Personal"),
+            SecurityProtocol::WPA3Enterprise => write!(f, "WPA3-Enterprise"),
+            SecurityProtocol::WPA3Enterprise192 => write!(f, "WPA3-Enterprise 192-bit"),
+            SecurityProtocol::OWE => write!(f, "OWE"),
+        }
+    }
 }
 
 // Use the Newtype pattern to create a type alias (SecurityProtocols) and implement the From trait
@@ -35,14 +86,253 @@ pub enum SecurityProtocol {
 )]
 pub struct SecurityProtocols(BitFlags<SecurityProtocol>);
 
-impl PartialEq<BitFlags<SecurityProtocol, u8>> for SecurityProtocols {
-    fn eq(&self, other: &BitFlags<SecurityProtocol, u8>) -> bool {
+impl SecurityProtocols {
+    /// Classifies a BSS using the Privacy bit of its capability info and the RSN, WPA and OWE
+    /// Transition Mode elements it advertises.
+    pub fn new(capability_info: &CapabilityInfo, ies: &[Ie]) -> SecurityProtocols {
+        let mut protocols = SecurityProtocols(BitFlags::empty());
+
+        let mut has_rsn_or_wpa = false;
+        let mut has_owe_transition_mode = false;
+
+        for ie in ies {
+            match ie {
+                Ie::Rsn(rsn) => {
+                    has_rsn_or_wpa = true;
+
+                    // A truncated element's unknown fields don't count towards any protocol
+                    let rsn_capabilities = rsn.rsn_capabilities();
+                    let mfpr = rsn_capabilities.as_ref().is_some_and(|caps| caps.mfpr());
+                    let mfpc = rsn_capabilities.as_ref().is_some_and(|caps| caps.mfpc());
+                    for akm_suite in rsn.akm_suites().unwrap_or_default() {
+                        match akm_suite {
+                            AkmSuite::Psk
+                            | AkmSuite::FtPsk
+                            | AkmSuite::PskSha256
+                            | AkmSuite::PskSha384
+                            | AkmSuite::FtPskSha384 => {
+                                protocols.insert(SecurityProtocol::WPA2Personal)
+                            }
+                            AkmSuite::Sae
+                            | AkmSuite::FtSae
+                            | AkmSuite::SaeExtKey
+                            | AkmSuite::FtSaeExtKey => {
+                                protocols.insert(SecurityProtocol::WPA3Personal)
+                            }
+                            // WPA3-Enterprise only mode is IEEE 802.1X with PMF required, and
+                            // WPA3-Enterprise transition mode adds the SHA-256 AKM with PMF capable
+                            AkmSuite::Ieee8021X | AkmSuite::FtIeee8021X => {
+                                if mfpr {
+                                    protocols.insert(SecurityProtocol::WPA3Enterprise)
+                                } else {
+                                    protocols.insert(SecurityProtocol::WPA2Enterprise)
+                                }
+                            }
+                            AkmSuite::Ieee8021XSha256 => {
+                                if mfpc {
+                                    protocols.insert(SecurityProtocol::WPA3Enterprise)
+                                } else {
+                                    protocols.insert(SecurityProtocol::WPA2Enterprise)
+                                }
+                            }
+                            AkmSuite::Ieee8021XSuiteB
+                            | AkmSuite::Ieee8021XSha384
+                            | AkmSuite::FtIeee8021XSha384 => {
+                                protocols.insert(SecurityProtocol::WPA3Enterprise)
+                            }
+                            AkmSuite::Ieee8021XSuiteB192 => {
+                                protocols.insert(SecurityProtocol::WPA3Enterprise192)
+                            }
+                            AkmSuite::FilsSha256
+                            | AkmSuite::FilsSha384
+                            | AkmSuite::FtFilsSha256
+                            | AkmSuite::FtFilsSha384
+                            | AkmSuite::Osen => protocols.insert(SecurityProtocol::WPA2Enterprise),
+                            AkmSuite::Owe => protocols.insert(SecurityProtocol::OWE),
+                            _ => continue,
+                        }
+                    }
+                }
+                Ie::Wpa(wpa) => {
+                    has_rsn_or_wpa = true;
+
+                    // Like RSN, a truncated AKM suite list doesn't fall back to IEEE 802.1X
+                    for akm_suite in wpa.akm_suites().unwrap_or_default() {
+                        match akm_suite {
+                            AkmSuite::Psk => protocols.insert(SecurityProtocol::WPAPersonal),
+                            AkmSuite::Ieee8021X => {
+                                protocols.insert(SecurityProtocol::WPAEnterprise)
+                            }
+                            _ => continue,
+                        }
+                    }
+                }
+                Ie::VendorSpecific(vendor_specific) => {
+                    if vendor_specific
+                        .bytes()
+                        .starts_with(&OWE_TRANSITION_MODE_OUI)
+                    {
+                        has_owe_transition_mode = true;
+                    }
+                }
+                _ => continue,
+            }
+        }
+
+        if !has_rsn_or_wpa {
+            if capability_info.privacy() {
+                protocols.insert(SecurityProtocol::WEP);
+            } else {
+                protocols.insert(SecurityProtocol::Open);
+
+                // The open half of an OWE transition mode pair
+                if has_owe_transition_mode {
+                    protocols.insert(SecurityProtocol::OWE);
+                }
+            }
+        }
+
+        protocols
+    }
+
+    pub fn is_personal(&self) -> bool {
+        self.iter().any(|protocol| protocol.is_personal())
+    }
+
+    pub fn is_enterprise(&self) -> bool {
+        self.iter().any(|protocol| protocol.is_enterprise())
+    }
+
+    /// Whether the BSS lets older stations connect with a weaker protocol alongside a newer one,
+    /// e.g. WPA3-Personal transition mode (WPA2-Personal and WPA3-Personal) or OWE transition mode
+    /// (Open and OWE).
+    pub fn is_transition_mode(&self) -> bool {
+        self.contains(SecurityProtocol::WPAPersonal | SecurityProtocol::WPA2Personal)
+            || self.contains(SecurityProtocol::WPAEnterprise | SecurityProtocol::WPA2Enterprise)
+            || self.contains(SecurityProtocol::WPA2Personal | SecurityProtocol::WPA3Personal)
+            || self.contains(SecurityProtocol::WPA2Enterprise | SecurityProtocol::WPA3Enterprise)
+            || self.contains(SecurityProtocol::Open | SecurityProtocol::OWE)
+    }
+}
+
+impl PartialEq<BitFlags<SecurityProtocol, u16>> for SecurityProtocols {
+    fn eq(&self, other: &BitFlags<SecurityProtocol, u16>) -> bool {
         self.0.eq(other)
     }
 }
 
-impl From<&[Ie]> for SecurityProtocols {
-    fn from(_: &[Ie]) -> Self {
-        SecurityProtocols(BitFlags::empty())
+impl Display for SecurityProtocols {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            self.iter()
+                .map(|protocol| protocol.to_string())
+                .collect::<Vec<String>>()
+                .join("/")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ies;
+
+    fn security_protocols(privacy: bool, ie_bytes: &[u8]) -> SecurityProtocols {
+        let capability_info = CapabilityInfo::new([if privacy { 0x11 } else { 0x01 }, 0x00]);
+        SecurityProtocols::new(&capability_info, &ies::from_bytes(ie_bytes).unwrap())
+    }
+
+    #[test]
+    fn open_and_wep() {
+        assert_eq!(
+            security_protocols(false, &[]),
+            BitFlags::from(SecurityProtocol::Open)
+        );
+        assert_eq!(
+            security_protocols(true, &[]),
+            BitFlags::from(SecurityProtocol::WEP)
+        );
+    }
+
+    #[test]
+    fn wpa3_personal_transition() {
+        let protocols = security_protocols(
+            true,
+            &[
+                0x30, 0x18, 0x01, 0x00, 0x00, 0x0F, 0xAC, 0x04, 0x01, 0x00, 0x00, 0x0F, 0xAC, 0x04,
+                0x02, 0x00, 0x00, 0x0F, 0xAC, 0x02, 0x00, 0x0F, 0xAC, 0x08, 0x80, 0x00,
+            ],
+        );
+
+        assert_eq!(
+            protocols,
+            SecurityProtocol::WPA2Personal | SecurityProtocol::WPA3Personal
+        );
+        assert!(protocols.is_personal());
+        assert!(!protocols.is_enterprise());
+        assert!(protocols.is_transition_mode());
+    }
+
+    #[test]
+    fn wpa3_enterprise_only() {
+        let protocols = security_protocols(
+            true,
+            &[
+                0x30, 0x14, 0x01, 0x00, 0x00, 0x0F, 0xAC, 0x04, 0x01, 0x00, 0x00, 0x0F, 0xAC, 0x04,
+                0x01, 0x00, 0x00, 0x0F, 0xAC, 0x05, 0xC0, 0x00,
+            ],
+        );
+
+        assert_eq!(protocols, BitFlags::from(SecurityProtocol::WPA3Enterprise));
+        assert!(protocols.is_enterprise());
+        assert!(!protocols.is_transition_mode());
+    }
+
+    #[test]
+    fn truncated_wpa() {
+        // A WPA element cut short partway through its PSK AKM suite
+        let protocols = security_protocols(
+            true,
+            &[
+                0xDD, 0x14, 0x00, 0x50, 0xF2, 0x01, 0x01, 0x00, 0x00, 0x50, 0xF2, 0x02, 0x01, 0x00,
+                0x00, 0x50, 0xF2, 0x02, 0x01, 0x00, 0x00, 0x50,
+            ],
+        );
+
+        assert!(!protocols.contains(SecurityProtocol::WPAEnterprise));
+    }
+
+    #[test]
+    fn wpa_wpa2_mixed() {
+        let protocols = security_protocols(
+            true,
+            &[
+                0x30, 0x14, 0x01, 0x00, 0x00, 0x0F, 0xAC, 0x02, 0x01, 0x00, 0x00, 0x0F, 0xAC, 0x04,
+                0x01, 0x00, 0x00, 0x0F, 0xAC, 0x02, 0x00, 0x00, 0xDD, 0x16, 0x00, 0x50, 0xF2, 0x01,
+                0x01, 0x00, 0x00, 0x50, 0xF2, 0x02, 0x01, 0x00, 0x00, 0x50, 0xF2, 0x02, 0x01, 0x00,
+                0x00, 0x50, 0xF2, 0x02,
+            ],
+        );
+
+        assert_eq!(
+            protocols,
+            SecurityProtocol::WPAPersonal | SecurityProtocol::WPA2Personal
+        );
+    }
+
+    #[test]
+    fn owe_transition_open_bss() {
+        let protocols = security_protocols(
+            false,
+            &[
+                0xDD, 0x0F, 0x50, 0x6F, 0x9A, 0x1C, 0x02, 0x11, 0x22, 0x33, 0x44, 0x55, 0x04, 0x6F,
+                0x77, 0x65, 0x21,
+            ],
+        );
+
+        assert_eq!(protocols, SecurityProtocol::Open | SecurityProtocol::OWE);
+        assert!(protocols.is_transition_mode());
     }
 }
@@ -18,6 +18,7 @@ pub enum WifiProtocol {
     N = 1 << 3,
     AC = 1 << 4,
     AX = 1 << 5,
+    BE = 1 << 6,
 }
 
 impl Display for WifiProtocol {
@@ -29,6 +30,7 @@ impl Display for WifiProtocol {
             WifiProtocol::N => write!(f, "n"),
             WifiProtocol::AC => write!(f, "ac"),
             WifiProtocol::AX => write!(f, "ax"),
+            WifiProtocol::BE => write!(f, "be"),
         }
     }
 }
@@ -108,6 +110,7 @@ impl From<&[Ie]> for WifiProtocols {
                 Ie::HtCapabilities(_) => protocols.insert(WifiProtocol::N),
                 Ie::VhtCapabilities(_) => protocols.insert(WifiProtocol::AC),
                 Ie::HeCapabilities(_) => protocols.insert(WifiProtocol::AX),
+                Ie::EhtCapabilities(_) => protocols.insert(WifiProtocol::BE),
                 _ => continue,
             }
         }